@@ -7,6 +7,7 @@ More detailed description, with
 
 */
 
+use crate::eval::{Continuation, Expression};
 use crate::read::datum::Label;
 use crate::types::{Identifier, SchemeRepr};
 use std::error::Error as StdError;
@@ -20,6 +21,7 @@ use std::fmt::{Display, Formatter};
 pub struct Error {
     kind: ErrorKind,
     source: Option<Box<dyn StdError>>,
+    raised: bool,
 }
 
 #[derive(Debug)]
@@ -51,6 +53,7 @@ pub enum ErrorKind {
         actual: Option<String>,
     },
     // Values -------------------------------------------------------------------------------------
+    DivisionByZero,
     ImproperList,
     UnexpectedValue {
         type_name: String,
@@ -61,21 +64,53 @@ pub enum ErrorKind {
     UnboundVariable {
         name: Identifier,
     },
+    UninitializedVariable {
+        name: Identifier,
+    },
     ProcedureArgumentCardinality {
         name: Identifier,
         min: usize,
         max: Option<usize>,
         given: usize,
     },
+    ValuesCardinality {
+        name: Identifier,
+        min: usize,
+        max: Option<usize>,
+        given: usize,
+    },
     BadFormSyntax {
         name: Identifier,
         value: String,
     },
+    SyntaxError {
+        message: String,
+        irritants: Vec<String>,
+    },
     ImmutableEnvironment,
     ImmutableValue {
         name: Identifier,
         type_name: String,
     },
+    ClosedPort {
+        name: String,
+    },
+    /// Not an error as such, this unwinds the evaluator back to the `call/cc` that captured
+    /// `continuation` which then returns `values`.
+    ContinuationInvoked {
+        continuation: Continuation,
+        values: Vec<Expression>,
+    },
+    ContinuationNotActive {
+        continuation: String,
+    },
+    /// An object raised by `raise`, or `raise-continuable`, that no handler escaped from.
+    Raised {
+        value: Box<Expression>,
+    },
+    ExceptionHandlerReturned {
+        value: Box<Expression>,
+    },
     // Library ------------------------------------------------------------------------------------
     BadLibraryName {
         name: String,
@@ -117,6 +152,7 @@ impl From<std::io::Error> for Error {
         Self {
             kind: ErrorKind::File,
             source: Some(Box::new(e)),
+            raised: false,
         }
     }
 }
@@ -126,13 +162,18 @@ impl From<std::time::SystemTimeError> for Error {
         Self {
             kind: ErrorKind::File,
             source: Some(Box::new(e)),
+            raised: false,
         }
     }
 }
 
 impl From<ErrorKind> for Error {
     fn from(kind: ErrorKind) -> Self {
-        Self { kind, source: None }
+        Self {
+            kind,
+            source: None,
+            raised: false,
+        }
     }
 }
 
@@ -153,12 +194,36 @@ impl Error {
         Self {
             kind,
             source: Some(source),
+            raised: false,
         }
     }
 
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
     pub fn is_file_error(&self) -> bool {
         matches!(self.kind, ErrorKind::File)
     }
+
+    ///
+    /// Returns `true` if this error has already been raised to the current exception handlers,
+    /// either as it was raised by `raise` or because it was raised where it occurred.
+    ///
+    pub fn is_raised(&self) -> bool {
+        self.raised || matches!(self.kind, ErrorKind::Raised { .. })
+    }
+
+    ///
+    /// Mark this error as raised, so that it is passed through, and not raised again, as it
+    /// unwinds the evaluator.
+    ///
+    pub(crate) fn into_raised(self) -> Self {
+        Self {
+            raised: true,
+            ..self
+        }
+    }
 }
 
 impl Display for ErrorKind {
@@ -205,6 +270,15 @@ impl Display for ErrorKind {
                 ErrorKind::UnboundVariable { name } => {
                     format!("Unbound variable: '{}'.", name.to_repr_string())
                 }
+                ErrorKind::UninitializedVariable { name } => {
+                    format!(
+                        "Variable '{}' referenced before it was initialized.",
+                        name.to_repr_string()
+                    )
+                }
+                ErrorKind::DivisionByZero => {
+                    String::from("Division by zero.")
+                }
                 ErrorKind::ImproperList => {
                     String::from("Value was not a proper list.")
                 }
@@ -221,9 +295,29 @@ impl Display for ErrorKind {
                         min_max_to_text(min, max)
                     )
                 }
+                ErrorKind::ValuesCardinality {
+                    name,
+                    min,
+                    max,
+                    given,
+                } => {
+                    format!(
+                        "The form '{}' was given {}, it expects {}.",
+                        name.to_repr_string(),
+                        given_values_to_text(given),
+                        min_max_to_text(min, max)
+                    )
+                }
                 ErrorKind::BadFormSyntax { name, value } => {
                     format!("Bad syntax in form '{}': {}.", name.to_repr_string(), value)
                 }
+                ErrorKind::SyntaxError { message, irritants } => {
+                    if irritants.is_empty() {
+                        format!("Syntax error: {}", message)
+                    } else {
+                        format!("Syntax error: {} {}", message, irritants.join(" "))
+                    }
+                }
                 ErrorKind::UnexpectedValue {
                     type_name: name,
                     expected,
@@ -244,6 +338,37 @@ impl Display for ErrorKind {
                         name.to_repr_string()
                     )
                 }
+                ErrorKind::ClosedPort { name } => {
+                    format!("The port '{}' has been closed.", name)
+                }
+                ErrorKind::ContinuationInvoked { continuation, .. } => {
+                    format!(
+                        "The continuation {} was invoked outside of its call/cc.",
+                        continuation.to_repr_string()
+                    )
+                }
+                ErrorKind::ContinuationNotActive { continuation } => {
+                    format!(
+                        "The continuation {} may not be re-entered once its call/cc has returned.",
+                        continuation
+                    )
+                }
+                ErrorKind::Raised { value } => {
+                    if let Expression::ErrorObject(error) = value.as_ref() {
+                        error.to_message_string()
+                    } else {
+                        format!(
+                            "An object was raised and not handled: {}.",
+                            value.to_repr_string()
+                        )
+                    }
+                }
+                ErrorKind::ExceptionHandlerReturned { value } => {
+                    format!(
+                        "An exception handler returned from the non-continuable raise of {}.",
+                        value.to_repr_string()
+                    )
+                }
                 ErrorKind::BadLibraryName { name } => {
                     format!("Bad syntax for library name: {}.", name,)
                 }
@@ -273,6 +398,14 @@ fn given_to_text(given: &usize) -> String {
     }
 }
 
+fn given_values_to_text(given: &usize) -> String {
+    match *given {
+        0 => "zero values".to_string(),
+        1 => "one value".to_string(),
+        v => format!("{} values", v),
+    }
+}
+
 fn min_max_to_text(min: &usize, max: &Option<usize>) -> String {
     let max = max.map(|v| v.to_string()).unwrap_or(String::from("many"));
     if *min == 0 && max == "0" {
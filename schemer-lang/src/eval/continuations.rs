@@ -0,0 +1,539 @@
+/*!
+First-class continuations, as captured by `call-with-current-continuation`, and `dynamic-wind`.
+
+The evaluator runs on the Rust stack and so, to allow a continuation to be re-entered, the
+receiver of a `call/cc` is evaluated on a stack of its own. Invoking a continuation suspends the
+stacks between the point of the call and the `call/cc` that captured it, rather than unwinding
+them, and that `call/cc` then returns the values passed to the continuation. A continuation
+captured on one of the suspended stacks may later be re-entered by resuming it. In either case
+the `after` thunks of the `dynamic-wind` extents being left, and the `before` thunks of those
+being entered, are called and the exception handlers and current ports of the continuation are
+restored.
+
+Continuations are one-shot; once the `call/cc` that captured a continuation has returned, either
+normally or because the continuation was invoked, re-entering it fails with an error, reported as
+"the continuation may not be re-entered once its call/cc has returned". So an escape, a generator
+or a coroutine may be written with continuations, but a continuation may not be used to return
+from the same `call/cc` twice. Each receiver is given a stack of its own, and if that stack cannot
+be allocated the `call/cc` fails with an error rather than calling the receiver. A
+`call/cc` in tail position is returned to the evaluator as a tail call, and shares the stack of
+the call it is in tail position to.
+
+# Example
+
+A generator, where each call to `next` re-enters the `for-each` that the previous call left.
+
+```scheme
+(define (make-generator items)
+  (define return #f)
+  (define (resume)
+    (for-each (lambda (item)
+                (call/cc (lambda (next)
+                  (set! resume (lambda () (next #f)))
+                  (return item))))
+              items)
+    (return 'done))
+  (lambda () (call/cc (lambda (k) (set! return k) (resume)))))
+
+(define next (make-generator '(a b)))
+(list (next) (next) (next))
+;; => (a b done)
+```
+
+*/
+
+use crate::error::{Error, ErrorKind};
+use crate::eval::exceptions::{current_handlers, restore_handlers, Handlers};
+use crate::eval::expression::trampoline_in;
+use crate::eval::ports::{current_ports, restore_ports, CurrentPorts};
+use crate::eval::{Environment, Expression, TailCall};
+use crate::read::syntax_str::{
+    PSEUDO_SYNTAX_COLON_CHAR, PSEUDO_SYNTAX_LEFT_PROCEDURE, PSEUDO_SYNTAX_RIGHT_PROCEDURE,
+};
+use crate::types::{MutableRef, Ref, SchemeRepr, SchemeValue};
+use corosensei::stack::DefaultStack;
+use corosensei::{Coroutine, CoroutineResult, Yielder};
+use std::cell::{Cell, RefCell};
+use std::fmt::{Debug, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug)]
+pub struct Continuation(Ref<Frame>);
+
+pub const TYPE_NAME_CONTINUATION: &str = "continuation";
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The `before` and `after` thunks of a `dynamic-wind` call whose `thunk` is being evaluated.
+///
+#[derive(Clone, Debug)]
+pub(crate) struct Wind {
+    id: usize,
+    before: Expression,
+    after: Expression,
+}
+
+///
+/// The call that a continuation returns to. A frame without a capture only escapes, by unwinding
+/// the Rust stack as an error, and is used by `guard`.
+///
+struct Frame {
+    id: usize,
+    returned: Cell<bool>,
+    capture: Option<Capture>,
+}
+
+///
+/// A frame captured by `call/cc`, its receiver is evaluated on `stack`.
+///
+struct Capture {
+    /// The frame whose stack the `call/cc` was called on, `None` for the thread's own stack.
+    parent: Option<Ref<Frame>>,
+    stack: RefCell<Option<Stack>>,
+    yielder: Cell<*const Yielder<Option<Jump>, Jump>>,
+    /// Whether `stack` was suspended by a frame that may be re-entered.
+    resumable: Cell<bool>,
+    winds: Vec<Wind>,
+    handlers: Handlers,
+    ports: CurrentPorts,
+}
+
+///
+/// The values passed to the continuation of `frame`.
+///
+struct Jump {
+    frame: Ref<Frame>,
+    values: Vec<Expression>,
+}
+
+type Stack = Coroutine<Option<Jump>, Jump, Result<Expression, Error>>;
+
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+thread_local! {
+    static NEXT_CONTINUATION: Cell<usize> = const { Cell::new(1) };
+    static NEXT_WIND: Cell<usize> = const { Cell::new(1) };
+    static WINDS: RefCell<Vec<Wind>> = const { RefCell::new(Vec::new()) };
+    // The frames whose stacks are running, innermost last.
+    static RUNNING: RefCell<Vec<Ref<Frame>>> = const { RefCell::new(Vec::new()) };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns the call of `receiver` with the current continuation as its only argument, this is a
+/// tail call for the evaluator to continue with. The continuation is one-shot, invoking it once
+/// this `call/cc` has returned results in an `ErrorKind::ContinuationNotActive` error.
+///
+pub fn call_with_current_continuation(receiver: &Expression) -> Result<Expression, Error> {
+    Ok(Expression::TailCall(TailCall::CallWithContinuation(
+        Box::new(receiver.clone()),
+    )))
+}
+
+///
+/// Call `receiver`, on a new stack, with a new continuation that returns from this call.
+///
+pub(crate) fn call_with_new_continuation(
+    receiver: Expression,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let stack = DefaultStack::new(STACK_SIZE)
+        .map_err(|e| Error::chain(Box::new(e), ErrorKind::OperatingSystem))?;
+    let frame = Ref::new(Frame {
+        id: next_continuation_id(),
+        returned: Cell::new(false),
+        capture: Some(Capture {
+            parent: running_frame(),
+            stack: RefCell::new(None),
+            yielder: Cell::new(std::ptr::null()),
+            resumable: Cell::new(false),
+            winds: WINDS.with(|winds| winds.borrow().clone()),
+            handlers: current_handlers(),
+            ports: current_ports(),
+        }),
+    });
+    let weak = Ref::downgrade(&frame);
+    let mut stack_environment = environment.clone();
+    let stack = Coroutine::with_stack(stack, move |yielder: &Yielder<Option<Jump>, Jump>, _| {
+        // the frame is held by `resume` while the stack is running.
+        let frame = weak.upgrade().unwrap();
+        frame.capture().yielder.set(yielder);
+        trampoline_in(
+            Some(&Continuation(frame)),
+            Expression::TailCall(TailCall::CallWithContinuation(Box::new(receiver))),
+            &mut stack_environment,
+        )
+    });
+    let _ = frame.capture().stack.replace(Some(stack));
+    resume(&frame, None, environment)
+}
+
+///
+/// Call `thunk` with no arguments, calling `before` first and `after` once `thunk` has returned
+/// either normally or because an error has unwound past it. If a continuation, or a `guard`, has
+/// already left the dynamic extent of `thunk`, and called `after`, it is not called again.
+///
+pub fn dynamic_wind(
+    before: &Expression,
+    thunk: &Expression,
+    after: &Expression,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let _ = before.call(Vec::default(), environment)?;
+    let wind = Wind {
+        id: NEXT_WIND.with(|next| next.replace(next.get() + 1)),
+        before: before.clone(),
+        after: after.clone(),
+    };
+    let id = wind.id;
+    let depth = WINDS.with(|winds| {
+        let mut winds = winds.borrow_mut();
+        winds.push(wind);
+        winds.len()
+    });
+    let result = thunk.call(Vec::default(), environment);
+    let wound = WINDS.with(|winds| {
+        let mut winds = winds.borrow_mut();
+        if winds.get(depth - 1).map(|wind| wind.id) == Some(id) {
+            winds.truncate(depth - 1);
+            true
+        } else {
+            false
+        }
+    });
+    if wound {
+        let _ = after.call(Vec::default(), environment)?;
+    }
+    result
+}
+
+///
+/// The number of `dynamic-wind` calls whose `thunk` is being evaluated.
+///
+pub(crate) fn wind_depth() -> usize {
+    WINDS.with(|winds| winds.borrow().len())
+}
+
+///
+/// Leave the dynamic extent of each `dynamic-wind` entered after the first `depth`, innermost
+/// first, calling its `after` thunk. The winds left are returned so that they may be re-entered
+/// by `rewind`.
+///
+pub(crate) fn unwind_to(
+    depth: usize,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Vec<Wind>, Error> {
+    let mut unwound = Vec::default();
+    while let Some(wind) = WINDS.with(|winds| {
+        let mut winds = winds.borrow_mut();
+        if winds.len() > depth {
+            winds.pop()
+        } else {
+            None
+        }
+    }) {
+        let _ = wind.after.call(Vec::default(), environment)?;
+        unwound.push(wind);
+    }
+    Ok(unwound)
+}
+
+///
+/// Re-enter the dynamic extent of each of the winds left by `unwind_to`, outermost first,
+/// calling its `before` thunk.
+///
+pub(crate) fn rewind(
+    unwound: Vec<Wind>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<(), Error> {
+    for wind in unwound.into_iter().rev() {
+        let _ = wind.before.call(Vec::default(), environment)?;
+        WINDS.with(|winds| winds.borrow_mut().push(wind));
+    }
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl PartialEq for Continuation {
+    fn eq(&self, other: &Self) -> bool {
+        Ref::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl SchemeRepr for Continuation {
+    fn to_repr_string(&self) -> String {
+        format!(
+            "{}{}{}{}{}",
+            PSEUDO_SYNTAX_LEFT_PROCEDURE,
+            TYPE_NAME_CONTINUATION,
+            PSEUDO_SYNTAX_COLON_CHAR,
+            self.id(),
+            PSEUDO_SYNTAX_RIGHT_PROCEDURE
+        )
+    }
+}
+
+impl SchemeValue for Continuation {
+    fn type_name(&self) -> &'static str {
+        TYPE_NAME_CONTINUATION
+    }
+}
+
+impl Continuation {
+    ///
+    /// A continuation that may only be invoked within the dynamic extent of the call that
+    /// created it, and on the same stack; it unwinds the evaluator to that call as an error.
+    ///
+    pub(crate) fn new_escape() -> Self {
+        Self(Ref::new(Frame {
+            id: next_continuation_id(),
+            returned: Cell::new(false),
+            capture: None,
+        }))
+    }
+
+    pub fn id(&self) -> usize {
+        self.0.id
+    }
+
+    ///
+    /// Returns `true` while the call that captured this continuation has not yet returned, only
+    /// an active continuation may be invoked.
+    ///
+    pub fn is_active(&self) -> bool {
+        !self.0.returned.get()
+    }
+
+    ///
+    /// Mark this continuation as no longer active, once the call that created it has returned.
+    ///
+    pub(crate) fn deactivate(&self) {
+        self.0.returned.set(true)
+    }
+
+    ///
+    /// Invoke this continuation, the values are returned from the call that captured it and so
+    /// this only returns if that call is not active, or for an escape continuation, as an error.
+    ///
+    pub fn call(&self, arguments: Vec<Expression>) -> Result<Expression, Error> {
+        if !self.is_active() {
+            Err(not_active(&self.0))
+        } else if self.0.capture.is_none() {
+            Err(Error::from(ErrorKind::ContinuationInvoked {
+                continuation: self.clone(),
+                values: arguments,
+            }))
+        } else {
+            jump(Jump {
+                frame: self.0.clone(),
+                values: arguments,
+            })
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Debug for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Frame")
+            .field("id", &self.id)
+            .field("returned", &self.returned.get())
+            .finish()
+    }
+}
+
+impl Frame {
+    fn capture(&self) -> &Capture {
+        self.capture
+            .as_ref()
+            .expect("an escape continuation has no stack")
+    }
+
+    ///
+    /// Returns `true` if the `call/cc` of this frame is on a stack that is running, rather than
+    /// one that has been suspended.
+    ///
+    fn is_running(&self) -> bool {
+        match &self.capture().parent {
+            None => true,
+            Some(parent) => RUNNING.with(|running| {
+                running
+                    .borrow()
+                    .iter()
+                    .any(|frame| Ref::ptr_eq(frame, parent))
+            }),
+        }
+    }
+
+    ///
+    /// Keep `stack`, suspended, if it may be resumed to re-enter a continuation, otherwise it is
+    /// dropped which unwinds it.
+    ///
+    fn park(&self, stack: Stack) {
+        let capture = self.capture();
+        if capture.resumable.get() {
+            let _ = capture.stack.replace(Some(stack));
+        } else {
+            drop(stack);
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn next_continuation_id() -> usize {
+    NEXT_CONTINUATION.with(|next| next.replace(next.get() + 1))
+}
+
+fn running_frame() -> Option<Ref<Frame>> {
+    RUNNING.with(|running| running.borrow().last().cloned())
+}
+
+fn not_active(frame: &Ref<Frame>) -> Error {
+    Error::from(ErrorKind::ContinuationNotActive {
+        continuation: Continuation(frame.clone()).to_repr_string(),
+    })
+}
+
+///
+/// Resume the stack of `frame` with `input` until a value is returned to its `call/cc`, either
+/// as the receiver returns or as the continuation is invoked.
+///
+fn resume(
+    frame: &Ref<Frame>,
+    mut input: Option<Jump>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let capture = frame.capture();
+    loop {
+        let mut stack = match capture.stack.take() {
+            Some(stack) => stack,
+            None => return Err(not_active(frame)),
+        };
+        RUNNING.with(|running| running.borrow_mut().push(frame.clone()));
+        let result = stack.resume(input);
+        let _ = RUNNING.with(|running| running.borrow_mut().pop());
+        match result {
+            CoroutineResult::Return(result) => {
+                frame.returned.set(true);
+                return result;
+            }
+            CoroutineResult::Yield(mut jump) => {
+                frame.park(stack);
+                if !Ref::ptr_eq(&jump.frame, frame) {
+                    // the continuation is outside this frame, which is suspended until resumed
+                    // to re-enter a continuation within it.
+                    jump = suspend(jump, true)?;
+                }
+                if Ref::ptr_eq(&jump.frame, frame) {
+                    frame.returned.set(true);
+                    wind_to(&capture.winds, environment)?;
+                    restore_handlers(capture.handlers.clone());
+                    restore_ports(capture.ports.clone());
+                    return Ok(Expression::from_values(jump.values));
+                }
+                input = Some(jump);
+            }
+        }
+    }
+}
+
+///
+/// Suspend the running stack, passing `jump` to the frame that resumed it, and return the jump
+/// it is resumed with.
+///
+fn suspend(jump: Jump, resumable: bool) -> Result<Jump, Error> {
+    match running_frame() {
+        None => Err(not_active(&jump.frame)),
+        Some(running) => {
+            let capture = running.capture();
+            capture.resumable.set(resumable);
+            // SAFETY: the yielder is held by the stack of `running`, and this is that stack.
+            let yielder = unsafe { &*capture.yielder.get() };
+            yielder.suspend(jump).ok_or_else(|| not_active(&running))
+        }
+    }
+}
+
+///
+/// Pass the values of `jump` to its frame. If the frame is on a running stack the stacks in
+/// between are suspended until it is reached, otherwise the suspended stack it is on is resumed.
+///
+fn jump(mut jump: Jump) -> Result<Expression, Error> {
+    loop {
+        let frame = jump.frame.clone();
+        if frame.returned.get() {
+            return Err(not_active(&frame));
+        }
+        if frame.is_running() {
+            jump = suspend(jump, false)?;
+        } else {
+            let root = suspended_root(&frame)?;
+            let mut stack = match root.capture().stack.take() {
+                Some(stack) => stack,
+                None => return Err(not_active(&frame)),
+            };
+            RUNNING.with(|running| running.borrow_mut().push(root.clone()));
+            let result = stack.resume(Some(jump));
+            let _ = RUNNING.with(|running| running.borrow_mut().pop());
+            match result {
+                CoroutineResult::Yield(next) => {
+                    root.park(stack);
+                    jump = next;
+                }
+                // the receiver returned to a call/cc that has already returned.
+                CoroutineResult::Return(Ok(_)) => return Err(not_active(&root)),
+                CoroutineResult::Return(Err(error)) => return Err(error),
+            }
+        }
+    }
+}
+
+///
+/// The frame, whose `call/cc` has returned, that holds the suspended stack `frame` is on.
+///
+fn suspended_root(frame: &Ref<Frame>) -> Result<Ref<Frame>, Error> {
+    let mut root = frame.clone();
+    loop {
+        match root.capture().parent.clone() {
+            None => return Err(not_active(frame)),
+            Some(parent) if parent.returned.get() => return Ok(parent),
+            Some(parent) => root = parent,
+        }
+    }
+}
+
+///
+/// Leave and enter `dynamic-wind` extents until those entered are `winds`.
+///
+fn wind_to(winds: &[Wind], environment: &mut MutableRef<Environment>) -> Result<(), Error> {
+    let common = WINDS.with(|current| {
+        current
+            .borrow()
+            .iter()
+            .zip(winds)
+            .take_while(|(current, wind)| current.id == wind.id)
+            .count()
+    });
+    let _ = unwind_to(common, environment)?;
+    rewind(winds[common..].iter().rev().cloned().collect(), environment)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
@@ -171,7 +171,7 @@ impl Environment {
             Err(Error::from(ErrorKind::ImmutableEnvironment))
         } else {
             if let Some(old_value) = self.bindings.get(&name) {
-                if old_value.is_form() || old_value.is_builtin_procedure() {
+                if old_value.is_form() || old_value.is_macro() || old_value.is_builtin_procedure() {
                     Err(Error::from(ErrorKind::ImmutableValue {
                         name,
                         type_name: old_value.type_name().to_string(),
@@ -215,6 +215,10 @@ impl Environment {
         }
     }
 
+    pub fn is_bound_locally(&self, name: &Identifier) -> bool {
+        self.bindings.contains_key(name)
+    }
+
     pub fn has_parent(&self) -> bool {
         self.parent.is_some()
     }
@@ -0,0 +1,398 @@
+/*!
+The exception system from R7RS §6.11; `raise`, `raise-continuable`, `with-exception-handler`,
+error objects, and the support for the `guard` form.
+
+Exception handlers are kept on a stack, one entry for each `with-exception-handler` or `guard`
+whose body is being evaluated. A raised object is passed to the current handler at the point it
+was raised, before the stack is unwound, and the handler is called with the outer handlers
+installed. If the handler returns from a call to `raise` a secondary exception is raised in the
+same dynamic environment as the handler. Errors produced by the interpreter itself, such as an
+unbound variable, are raised as error objects where they occur, although if no handler is
+installed at all they are returned as-is.
+
+A `guard` is also called at the point an object was raised; it leaves the dynamic extent of any
+`dynamic-wind` within its body, calling the `after` thunks, and evaluates its clauses. If a clause
+applies the guard's continuation is invoked with its value, otherwise the `before` thunks are
+called to re-enter the dynamic environment of the raise and the object is re-raised by
+`raise-continuable` to the guard's own handler.
+
+# Example
+
+```scheme
+(guard (e ((error-object? e) (error-object-message e))
+          ((symbol? e) e))
+  (raise 'oops))
+;; => oops
+```
+
+*/
+
+use crate::error::{Error, ErrorKind};
+use crate::eval::continuations::{rewind, unwind_to, wind_depth};
+use crate::eval::procedures::TYPE_NAME_PROCEDURE;
+use crate::eval::{Continuation, Environment, Expression};
+use crate::read::syntax_str::{
+    PSEUDO_SYNTAX_COLON_CHAR, PSEUDO_SYNTAX_LEFT_PROCEDURE, PSEUDO_SYNTAX_RIGHT_PROCEDURE,
+};
+use crate::types::{MutableRef, Ref, SchemeRepr, SchemeString, SchemeValue};
+use std::cell::{Cell, RefCell};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The object raised by the `error` procedure, and by the interpreter for its own errors.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorObject {
+    kind: ErrorObjectKind,
+    message: SchemeString,
+    irritants: Vec<Expression>,
+}
+
+///
+/// Distinguishes the error objects that satisfy `read-error?` and `file-error?`.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorObjectKind {
+    Error,
+    Read,
+    File,
+}
+
+///
+/// Evaluates the clauses of a `guard` with the raised object, returning `None` if none apply.
+///
+pub type GuardClauses = Ref<dyn Fn(Expression) -> Result<Option<Expression>, Error>>;
+
+pub const TYPE_NAME_ERROR_OBJECT: &str = "error-object";
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone)]
+enum Handler {
+    Procedure(Expression),
+    Guard {
+        clauses: GuardClauses,
+        continuation: Continuation,
+        winds: usize,
+    },
+}
+
+///
+/// The exception handlers installed, as saved by a continuation.
+///
+#[derive(Clone)]
+pub(crate) struct Handlers {
+    handlers: Vec<Handler>,
+    suspended: usize,
+}
+
+thread_local! {
+    static HANDLERS: RefCell<Vec<Handler>> = const { RefCell::new(Vec::new()) };
+    // The number of outer handlers removed from `HANDLERS` while a handler is called.
+    static SUSPENDED: Cell<usize> = const { Cell::new(0) };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Raise `value` as a non-continuable exception, this only returns an error; either one from the
+/// handler, or a secondary exception if the handler returns.
+///
+pub fn raise(value: Expression, environment: &mut MutableRef<Environment>) -> Error {
+    match call_handler(value.clone(), environment) {
+        None => Error::from(ErrorKind::Raised {
+            value: Box::new(value),
+        }),
+        Some(Ok(_)) => handler_returned(value, environment),
+        Some(Err(error)) => error.into_raised(),
+    }
+}
+
+///
+/// Raise `value` as a continuable exception, the current handler is called with `value` and
+/// the value it returns becomes the result of this call.
+///
+pub fn raise_continuable(
+    value: Expression,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    match call_handler(value.clone(), environment) {
+        None => Err(Error::from(ErrorKind::Raised {
+            value: Box::new(value),
+        })),
+        Some(result) => result.map_err(Error::into_raised),
+    }
+}
+
+///
+/// Raise an error returned by the interpreter, where it occurred, as an error object. The error
+/// is returned as-is if it has already been raised, or if no handler is installed.
+///
+pub(crate) fn raise_error(error: Error, environment: &mut MutableRef<Environment>) -> Error {
+    if error.is_raised()
+        || matches!(error.kind(), ErrorKind::ContinuationInvoked { .. })
+        || handler_depth() + SUSPENDED.with(Cell::get) == 0
+    {
+        return error;
+    }
+    let condition = Expression::ErrorObject(ErrorObject::from(&error));
+    match call_handler(condition.clone(), environment) {
+        None => error.into_raised(),
+        Some(Ok(_)) => handler_returned(condition, environment),
+        // the condition was not handled, and so the original error is kept.
+        Some(Err(raised)) => match raised.kind() {
+            ErrorKind::Raised { value } if **value == condition => error.into_raised(),
+            _ => raised.into_raised(),
+        },
+    }
+}
+
+///
+/// Call `thunk` with `handler` installed as the current exception handler.
+///
+pub fn with_exception_handler(
+    handler: &Expression,
+    thunk: &Expression,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    if !handler.is_callable() {
+        return Err(Error::from(ErrorKind::UnexpectedType {
+            expected: TYPE_NAME_PROCEDURE.to_string(),
+            actual: Some(handler.type_name().to_string()),
+        }));
+    }
+    let depth = push_handler(Handler::Procedure(handler.clone()));
+    let result = thunk.call(Vec::default(), environment);
+    pop_handler(depth);
+    result
+}
+
+///
+/// Evaluate `body` as the body of a `guard` form, if it raises an exception `clauses` are
+/// evaluated with the raised object. The value of the clause that applies becomes the result of
+/// this call.
+///
+pub fn guard<F>(body: F, clauses: GuardClauses) -> Result<Expression, Error>
+where
+    F: FnOnce() -> Result<Expression, Error>,
+{
+    let continuation = Continuation::new_escape();
+    let depth = push_handler(Handler::Guard {
+        clauses,
+        continuation: continuation.clone(),
+        winds: wind_depth(),
+    });
+    let result = body();
+    pop_handler(depth);
+    continuation.deactivate();
+    match result {
+        Err(error) => match error.kind() {
+            ErrorKind::ContinuationInvoked {
+                continuation: target,
+                values,
+            } if *target == continuation => Ok(Expression::from_values(values.clone())),
+            _ => Err(error),
+        },
+        result => result,
+    }
+}
+
+///
+/// The exception handlers currently installed.
+///
+pub(crate) fn current_handlers() -> Handlers {
+    Handlers {
+        handlers: HANDLERS.with(|handlers| handlers.borrow().clone()),
+        suspended: SUSPENDED.with(Cell::get),
+    }
+}
+
+///
+/// Install `handlers`, as saved by `current_handlers`, in place of the current handlers.
+///
+pub(crate) fn restore_handlers(handlers: Handlers) {
+    let Handlers {
+        handlers,
+        suspended,
+    } = handlers;
+    let _ = HANDLERS.with(|current| current.replace(handlers));
+    SUSPENDED.with(|current| current.set(suspended));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl SchemeRepr for ErrorObject {
+    fn to_repr_string(&self) -> String {
+        format!(
+            "{}{}{}{}{}",
+            PSEUDO_SYNTAX_LEFT_PROCEDURE,
+            TYPE_NAME_ERROR_OBJECT,
+            PSEUDO_SYNTAX_COLON_CHAR,
+            self.to_message_string(),
+            PSEUDO_SYNTAX_RIGHT_PROCEDURE
+        )
+    }
+}
+
+impl SchemeValue for ErrorObject {
+    fn type_name(&self) -> &'static str {
+        TYPE_NAME_ERROR_OBJECT
+    }
+}
+
+impl From<&Error> for ErrorObject {
+    fn from(error: &Error) -> Self {
+        Self {
+            kind: match error.kind() {
+                ErrorKind::Parser
+                | ErrorKind::ParserState { .. }
+                | ErrorKind::ParseValue { .. }
+                | ErrorKind::UnknownReference { .. }
+                | ErrorKind::Read => ErrorObjectKind::Read,
+                ErrorKind::File => ErrorObjectKind::File,
+                _ => ErrorObjectKind::Error,
+            },
+            message: SchemeString::from(error.to_string()),
+            irritants: Default::default(),
+        }
+    }
+}
+
+impl ErrorObject {
+    pub fn new(message: SchemeString, irritants: Vec<Expression>) -> Self {
+        Self {
+            kind: ErrorObjectKind::Error,
+            message,
+            irritants,
+        }
+    }
+
+    pub fn kind(&self) -> ErrorObjectKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &SchemeString {
+        &self.message
+    }
+
+    pub fn irritants(&self) -> &Vec<Expression> {
+        &self.irritants
+    }
+
+    pub fn is_read_error(&self) -> bool {
+        self.kind == ErrorObjectKind::Read
+    }
+
+    pub fn is_file_error(&self) -> bool {
+        self.kind == ErrorObjectKind::File
+    }
+
+    ///
+    /// The message followed by the representation of each irritant, as reported when the object
+    /// is raised and not handled.
+    ///
+    pub fn to_message_string(&self) -> String {
+        let mut message = self.message.to_string();
+        for irritant in &self.irritants {
+            message.push(' ');
+            message.push_str(&irritant.to_repr_string());
+        }
+        message
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[inline]
+fn handler_depth() -> usize {
+    HANDLERS.with(|handlers| handlers.borrow().len())
+}
+
+///
+/// Push a handler returning its depth, that is the depth of the stack once it is installed.
+///
+fn push_handler(handler: Handler) -> usize {
+    HANDLERS.with(|handlers| {
+        let mut handlers = handlers.borrow_mut();
+        handlers.push(handler);
+        handlers.len()
+    })
+}
+
+fn pop_handler(depth: usize) {
+    HANDLERS.with(|handlers| handlers.borrow_mut().truncate(depth - 1))
+}
+
+///
+/// Call `f` with the current handler, and any installed after it, removed.
+///
+fn with_outer_handlers<T, F>(f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let depth = handler_depth();
+    let saved = HANDLERS.with(|handlers| handlers.borrow_mut().split_off(depth - 1));
+    SUSPENDED.with(|suspended| suspended.set(suspended.get() + saved.len()));
+    let result = f();
+    SUSPENDED.with(|suspended| suspended.set(suspended.get() - saved.len()));
+    HANDLERS.with(|handlers| handlers.borrow_mut().extend(saved));
+    result
+}
+
+///
+/// Call the current handler with `value`, in the dynamic environment of the raise except that
+/// the outer handlers are installed, or return `None` if there is no handler.
+///
+fn call_handler(
+    value: Expression,
+    environment: &mut MutableRef<Environment>,
+) -> Option<Result<Expression, Error>> {
+    let handler = HANDLERS.with(|handlers| handlers.borrow().last().cloned())?;
+    Some(with_outer_handlers(|| match handler {
+        Handler::Procedure(handler) => handler.call(vec![value], environment),
+        Handler::Guard {
+            clauses,
+            continuation,
+            winds,
+        } => {
+            let unwound = unwind_to(winds, environment)?;
+            match clauses(value.clone())? {
+                Some(result) => continuation.call(vec![result]),
+                None => {
+                    rewind(unwound, environment)?;
+                    raise_continuable(value, environment)
+                }
+            }
+        }
+    }))
+}
+
+///
+/// The secondary exception raised when a handler returns from a non-continuable raise of
+/// `value`, it is raised in the handler's dynamic environment.
+///
+fn handler_returned(value: Expression, environment: &mut MutableRef<Environment>) -> Error {
+    with_outer_handlers(|| {
+        raise_error(
+            Error::from(ErrorKind::ExceptionHandlerReturned {
+                value: Box::new(value),
+            }),
+            environment,
+        )
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
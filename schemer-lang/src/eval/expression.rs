@@ -9,15 +9,17 @@ More detailed description, with
 
 use crate::error::{Error, ErrorKind};
 use crate::eval::callable::Callable;
+use crate::eval::continuations::call_with_new_continuation;
 use crate::eval::environment::Environment;
-use crate::eval::procedures::Procedure;
-use crate::eval::{forms, Form};
+use crate::eval::exceptions::raise_error;
+use crate::eval::procedures::{Procedure, TYPE_NAME_PROCEDURE};
+use crate::eval::{forms, Continuation, ErrorObject, Form, Macro, Port};
 use crate::read::datum::{datum_to_vec, Abbreviation, Datum};
 use crate::read::syntax_str::{
-    SYNTAX_ABBR_QUOTE, SYNTAX_LEFT_PARENTHESIS_CHAR, SYNTAX_RIGHT_PARENTHESIS_CHAR, VALUE_NULL_LIST,
+    PSEUDO_SYNTAX_LEFT_PROCEDURE, PSEUDO_SYNTAX_RIGHT_PROCEDURE, SYNTAX_ABBR_QUOTE,
+    SYNTAX_LEFT_PARENTHESIS_CHAR, SYNTAX_RIGHT_PARENTHESIS_CHAR, VALUE_NULL_LIST,
 };
-use crate::types::lists::TYPE_NAME_LIST;
-use crate::types::symbols::TYPE_NAME_SYMBOL;
+use crate::types::lists::{vec_to_improper_list, TYPE_NAME_LIST};
 use crate::types::{
     Boolean, ByteVector, Char, Identifier, MutableRef, Number, Pair, Ref, SchemeRepr, SchemeString,
     SchemeValue, Vector,
@@ -44,15 +46,50 @@ pub enum Expression {
     ByteVector(ByteVector),
     Quotation(Ref<Datum>),
     Form(Form),
+    Macro(Macro),
     Procedure(Procedure),
+    Continuation(Continuation),
+    ErrorObject(ErrorObject),
+    Port(Port),
     List(Vec<Expression>),
     Environment(MutableRef<Environment>),
+    /// The result of `values` when called with other than one value, an expression that
+    /// produces exactly one value always does so directly.
+    Values(Vec<Expression>),
     Null,
     Unspecified,
+    /// The end-of-file object, returned by the input procedures at the end of input.
+    EofObject,
+    /// The value of a `letrec` variable before its initializer has been evaluated, it is an
+    /// error to reference a variable with this value.
+    Uninitialized,
+    /// Returned by forms and procedure bodies for the expression in tail position, this is
+    /// resolved by the evaluator's trampoline and is never visible to Scheme code.
+    TailCall(TailCall),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TailCall {
+    /// Evaluate the datum in the given environment.
+    Evaluate(Ref<Datum>, MutableRef<Environment>),
+    /// Call the procedure with already evaluated arguments.
+    Apply(Procedure, Vec<Expression>),
+    /// Call the receiver with the current continuation, as `call/cc` does.
+    CallWithContinuation(Box<Expression>),
 }
 
 pub const VALUE_NAME_UNSPECIFIED: &str = "#!unspecified";
 
+pub const VALUE_NAME_UNINITIALIZED: &str = "#!uninitialized";
+
+pub const VALUE_NAME_EOF_OBJECT: &str = "#!eof";
+
+pub const TYPE_NAME_EOF_OBJECT: &str = "eof-object";
+
+pub const TYPE_NAME_TAIL_CALL: &str = "tail-call";
+
+pub const TYPE_NAME_VALUES: &str = "values";
+
 // ------------------------------------------------------------------------------------------------
 // Private Types
 // ------------------------------------------------------------------------------------------------
@@ -61,37 +98,59 @@ pub const VALUE_NAME_UNSPECIFIED: &str = "#!unspecified";
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Resolve any `Expression::TailCall` returned from a form or procedure body, continuing until an
+/// actual value is produced. As tail calls are handled here, in a loop, rather than recursively
+/// the Rust stack does not grow for Scheme calls in tail position.
+///
+pub(crate) fn trampoline(
+    result: Expression,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    trampoline_in(None, result, environment)
+}
+
+///
+/// As `trampoline`, however if this is the body of a `call/cc` then `continuation` is its
+/// continuation, which is shared by any `call/cc` in tail position.
+///
+pub(crate) fn trampoline_in(
+    continuation: Option<&Continuation>,
+    mut result: Expression,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    loop {
+        result = match result {
+            Expression::TailCall(TailCall::Evaluate(datum, mut environment)) => {
+                eval_datum(&datum, &mut environment)
+                    .map_err(|error| raise_error(error, &mut environment))?
+            }
+            Expression::TailCall(TailCall::Apply(procedure, arguments)) => procedure
+                .call_tail(arguments, environment)
+                .map_err(|error| raise_error(error, environment))?,
+            Expression::TailCall(TailCall::CallWithContinuation(receiver)) => match continuation {
+                Some(continuation) => receiver
+                    .call_tail(
+                        vec![Expression::Continuation(continuation.clone())],
+                        environment,
+                    )
+                    .map_err(|error| raise_error(error, environment))?,
+                None => return call_with_new_continuation(*receiver, environment),
+            },
+            _ => return Ok(result),
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
 impl Evaluate for Datum {
     fn eval(&self, environment: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-        Ok(match self {
-            Datum::Symbol(v) => v.eval(environment)?,
-            Datum::Boolean(v) => v.eval(environment)?,
-            Datum::Number(v) => v.eval(environment)?,
-            Datum::Character(v) => v.eval(environment)?,
-            Datum::String(v) => v.eval(environment)?,
-            Datum::ByteVector(v) => v.eval(environment)?,
-            Datum::Vector(v) => v.eval(environment)?,
-            Datum::List(v) => call_or_form_from_list(&v, environment)?,
-            Datum::Abbreviation(a, d) => match a {
-                Abbreviation::Quote => forms::quote(vec![d.clone()], environment)?,
-                Abbreviation::QuasiQuote => forms::quasi_quote(vec![d.clone()], environment)?,
-                Abbreviation::Unquote => forms::unquote(vec![d.clone()], environment)?,
-                Abbreviation::UnquoteSplicing => {
-                    forms::unquote_splicing(vec![d.clone()], environment)?
-                }
-            },
-            Datum::Labeled(_, _) => {
-                unreachable!()
-            }
-            Datum::LabelRef(_) => {
-                unreachable!()
-            }
-            Datum::Null => Expression::Null,
-        })
+        let result =
+            eval_datum(self, environment).map_err(|error| raise_error(error, environment))?;
+        trampoline(result, environment)
     }
 }
 
@@ -109,6 +168,9 @@ impl SchemeRepr for Expression {
             Self::ByteVector(v) => v.to_repr_string(),
             Self::Quotation(v) => format!("{}{}", SYNTAX_ABBR_QUOTE, v.to_repr_string()),
             Self::Procedure(v) => v.to_repr_string(),
+            Self::Continuation(v) => v.to_repr_string(),
+            Self::ErrorObject(v) => v.to_repr_string(),
+            Self::Port(v) => v.to_repr_string(),
             Self::List(v) => format!(
                 "{}{}{}",
                 SYNTAX_LEFT_PARENTHESIS_CHAR,
@@ -118,10 +180,22 @@ impl SchemeRepr for Expression {
                     .join(" "),
                 SYNTAX_RIGHT_PARENTHESIS_CHAR
             ),
+            Self::Values(v) => v
+                .iter()
+                .map(|e| e.to_repr_string())
+                .collect::<Vec<String>>()
+                .join("\n"),
             Self::Null => VALUE_NULL_LIST.to_string(),
             Self::Unspecified => VALUE_NAME_UNSPECIFIED.to_string(),
+            Self::EofObject => VALUE_NAME_EOF_OBJECT.to_string(),
+            Self::Uninitialized => VALUE_NAME_UNINITIALIZED.to_string(),
             Self::Environment(v) => v.borrow().to_repr_string(),
             Self::Form(v) => v.to_repr_string(),
+            Self::Macro(v) => v.to_repr_string(),
+            Self::TailCall(_) => format!(
+                "{}{}{}",
+                PSEUDO_SYNTAX_LEFT_PROCEDURE, TYPE_NAME_TAIL_CALL, PSEUDO_SYNTAX_RIGHT_PROCEDURE
+            ),
         }
     }
 }
@@ -138,11 +212,19 @@ impl SchemeValue for Expression {
             Self::ByteVector(v) => v.type_name(),
             Self::Quotation(v) => v.type_name(),
             Self::Form(v) => v.type_name(),
+            Self::Macro(v) => v.type_name(),
             Self::Procedure(v) => v.type_name(),
+            Self::Continuation(v) => v.type_name(),
+            Self::ErrorObject(v) => v.type_name(),
+            Self::Port(v) => v.type_name(),
             Self::List(_) => TYPE_NAME_LIST,
+            Self::Values(_) => TYPE_NAME_VALUES,
             Self::Null => TYPE_NAME_LIST,
             Self::Unspecified => VALUE_NAME_UNSPECIFIED,
+            Self::EofObject => TYPE_NAME_EOF_OBJECT,
+            Self::Uninitialized => VALUE_NAME_UNINITIALIZED,
             Self::Environment(v) => v.borrow().type_name(),
+            Self::TailCall(_) => TYPE_NAME_TAIL_CALL,
         }
     }
 }
@@ -205,6 +287,26 @@ impl Expression {
         matches!(self, Self::Procedure(_))
     }
 
+    pub fn is_continuation(&self) -> bool {
+        matches!(self, Self::Continuation(_))
+    }
+
+    pub fn is_error_object(&self) -> bool {
+        matches!(self, Self::ErrorObject(_))
+    }
+
+    pub fn is_port(&self) -> bool {
+        matches!(self, Self::Port(_))
+    }
+
+    ///
+    /// Returns `true` if this value may be called with arguments, either a procedure or a
+    /// continuation.
+    ///
+    pub fn is_callable(&self) -> bool {
+        self.is_procedure() || self.is_continuation()
+    }
+
     pub fn is_builtin_procedure(&self) -> bool {
         match self {
             Self::Procedure(p) => p.is_builtin(),
@@ -220,6 +322,10 @@ impl Expression {
         matches!(self, Self::Unspecified)
     }
 
+    pub fn is_eof_object(&self) -> bool {
+        matches!(self, Self::EofObject)
+    }
+
     pub fn is_environment(&self) -> bool {
         matches!(self, Self::Environment(_))
     }
@@ -227,44 +333,188 @@ impl Expression {
     pub fn is_form(&self) -> bool {
         matches!(self, Self::Form(_))
     }
+
+    pub fn is_macro(&self) -> bool {
+        matches!(self, Self::Macro(_))
+    }
+
+    pub fn is_values(&self) -> bool {
+        matches!(self, Self::Values(_))
+    }
+
+    pub fn is_tail_call(&self) -> bool {
+        matches!(self, Self::TailCall(_))
+    }
+
+    ///
+    /// Call this value, which must be a procedure or continuation, with already evaluated
+    /// arguments.
+    ///
+    pub fn call(
+        &self,
+        arguments: Vec<Expression>,
+        environment: &mut MutableRef<Environment>,
+    ) -> Result<Expression, Error> {
+        match self {
+            Self::Procedure(procedure) => procedure.call(arguments, environment),
+            Self::Continuation(continuation) => continuation.call(arguments),
+            _ => Error::from(ErrorKind::UnexpectedType {
+                expected: TYPE_NAME_PROCEDURE.to_string(),
+                actual: Some(self.type_name().to_string()),
+            })
+            .into(),
+        }
+    }
+
+    ///
+    /// Call this value as `call` does, however if it is a procedure the call is returned as an
+    /// `Expression::TailCall` for the evaluator to continue with. A builtin that calls a
+    /// procedure in tail position, such as `apply`, should return the result of this method.
+    ///
+    pub fn call_tail(
+        &self,
+        arguments: Vec<Expression>,
+        environment: &mut MutableRef<Environment>,
+    ) -> Result<Expression, Error> {
+        match self {
+            Self::Procedure(procedure) => Ok(Self::TailCall(TailCall::Apply(
+                procedure.clone(),
+                arguments,
+            ))),
+            _ => self.call(arguments, environment),
+        }
+    }
+
+    ///
+    /// The result of returning `values` to a continuation, a single value is returned as-is.
+    ///
+    pub fn from_values(mut values: Vec<Expression>) -> Self {
+        if values.len() == 1 {
+            values.remove(0)
+        } else {
+            Self::Values(values)
+        }
+    }
+
+    ///
+    /// The values produced by an expression, only `Expression::Values` produces other than
+    /// exactly one.
+    ///
+    pub fn into_values(self) -> Vec<Expression> {
+        match self {
+            Self::Values(values) => values,
+            value => vec![value],
+        }
+    }
+
+    ///
+    /// The value denoted by `datum` when quoted; self-evaluating data and the empty list are
+    /// returned directly while symbols, lists, and vectors remain quoted so that the pairs of a
+    /// quoted list are shared, not copied.
+    ///
+    pub fn from_datum(datum: Ref<Datum>) -> Self {
+        match &*datum {
+            Datum::Boolean(v) => Self::Boolean(*v),
+            Datum::Number(v) => Self::Number(v.clone()),
+            Datum::Character(v) => Self::Character(*v),
+            Datum::String(v) => Self::String(v.clone()),
+            Datum::ByteVector(v) => Self::ByteVector(v.clone()),
+            Datum::Object(v) => v.as_ref().clone(),
+            Datum::Null => Self::Null,
+            _ => Self::Quotation(datum),
+        }
+    }
+
+    ///
+    /// Convert this value into data; a quoted datum is returned as-is so that any pairs are
+    /// shared, not copied.
+    ///
+    pub fn into_datum(self) -> Ref<Datum> {
+        match self {
+            Self::Quotation(datum) => datum,
+            value => Ref::new(Datum::from(value)),
+        }
+    }
+
+    ///
+    /// A newly allocated proper list of `values`, or the empty list if there are none.
+    ///
+    pub fn from_list(values: Vec<Expression>) -> Self {
+        Self::from_datum(vec_to_improper_list(
+            values.into_iter().map(Self::into_datum).collect(),
+            Ref::new(Datum::Null),
+        ))
+    }
+
+    pub fn new_tail_call(datum: Ref<Datum>, environment: &MutableRef<Environment>) -> Self {
+        Self::TailCall(TailCall::Evaluate(datum, environment.clone()))
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+fn eval_datum(
+    datum: &Datum,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(match datum {
+        Datum::Symbol(v) => v.eval(environment)?,
+        Datum::Boolean(v) => v.eval(environment)?,
+        Datum::Number(v) => v.eval(environment)?,
+        Datum::Character(v) => v.eval(environment)?,
+        Datum::String(v) => v.eval(environment)?,
+        Datum::ByteVector(v) => v.eval(environment)?,
+        Datum::Vector(v) => v.eval(environment)?,
+        Datum::List(v) => call_or_form_from_list(v, environment)?,
+        Datum::Abbreviation(a, d) => match a {
+            Abbreviation::Quote => forms::quote(vec![d.clone()], environment)?,
+            Abbreviation::QuasiQuote => forms::quasi_quote(vec![d.clone()], environment)?,
+            Abbreviation::Unquote => forms::unquote(vec![d.clone()], environment)?,
+            Abbreviation::UnquoteSplicing => forms::unquote_splicing(vec![d.clone()], environment)?,
+        },
+        Datum::Labeled(_, _) => {
+            unreachable!()
+        }
+        Datum::LabelRef(_) => {
+            unreachable!()
+        }
+        Datum::Object(v) => v.as_ref().clone(),
+        Datum::Null => Expression::Null,
+    })
+}
+
 fn call_or_form_from_list(
     from: &Pair,
     environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
     if from.is_proper_list() {
-        if let Datum::Symbol(id) = &**from.car() {
-            let variable = environment.borrow().get(&id);
-            if let Some(Expression::Form(form)) = variable {
+        let operator = from.car().eval(environment)?;
+        match operator {
+            Expression::Form(form) => {
                 let arguments = if from.cdr().is_null() {
                     Vec::default()
                 } else {
                     datum_to_vec(from.cdr().clone())
                 };
-                form.call(arguments, environment)
-            } else if let Some(Expression::Procedure(procedure)) = variable {
-                let procedure = procedure.clone();
-                procedure.call(make_parameters(from.cdr(), environment)?, environment)
-            } else if let Some(expr) = variable {
-                Error::from(ErrorKind::UnexpectedType {
-                    expected: TYPE_NAME_SYMBOL.to_string(),
-                    actual: Some(expr.type_name().to_string()),
-                })
-                .into()
-            } else {
-                Error::from(ErrorKind::UnboundVariable { name: id.clone() }).into()
+                form.call_tail(arguments, environment)
             }
-        } else {
-            Error::from(ErrorKind::UnexpectedType {
-                expected: TYPE_NAME_SYMBOL.to_string(),
-                actual: Some(from.car().inner_type_name()),
+            Expression::Macro(syntax) => Ok(Expression::new_tail_call(
+                syntax.expand(from, environment)?,
+                environment,
+            )),
+            Expression::Procedure(procedure) => {
+                procedure.call_tail(make_parameters(&from.cdr(), environment)?, environment)
+            }
+            Expression::Continuation(continuation) => {
+                continuation.call(make_parameters(&from.cdr(), environment)?)
+            }
+            expr => Error::from(ErrorKind::UnexpectedType {
+                expected: TYPE_NAME_PROCEDURE.to_string(),
+                actual: Some(expr.type_name().to_string()),
             })
-            .into()
+            .into(),
         }
     } else {
         Error::from(ErrorKind::ImproperList).into()
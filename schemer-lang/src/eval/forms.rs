@@ -13,16 +13,27 @@ More detailed description, with
 use crate::error::{Error, ErrorKind};
 use crate::eval::callable::Callable;
 use crate::eval::environment::Exports;
-use crate::eval::{Environment, Evaluate, Expression, Procedure};
-use crate::read::datum::{datum_to_vec, Datum};
+use crate::eval::exceptions::raise_error;
+use crate::eval::expression::trampoline;
+use crate::eval::macros::{resolve_alias, strip_aliases, unalias, TYPE_NAME_MACRO};
+use crate::eval::procedures::TYPE_NAME_PROCEDURE;
+use crate::eval::{exceptions, Environment, Evaluate, Expression, Macro, Procedure, TailCall};
+use crate::read::datum::{Abbreviation, Datum};
 use crate::read::syntax_str::{
-    FORM_NAME_BEGIN, FORM_NAME_DEFINE, FORM_NAME_IF, FORM_NAME_LAMBDA, FORM_NAME_QUOTE,
-    FORM_NAME_SET, PSEUDO_SYNTAX_COLON_CHAR, PSEUDO_SYNTAX_LEFT_PROCEDURE, PSEUDO_SYNTAX_RANGE,
-    PSEUDO_SYNTAX_RIGHT_PROCEDURE,
+    FORM_NAME_AND, FORM_NAME_BEGIN, FORM_NAME_CASE, FORM_NAME_COND, FORM_NAME_DEFINE,
+    FORM_NAME_DEFINE_SYNTAX, FORM_NAME_DEFINE_VALUES, FORM_NAME_DO, FORM_NAME_ELSE,
+    FORM_NAME_GUARD, FORM_NAME_IF, FORM_NAME_LAMBDA, FORM_NAME_LET, FORM_NAME_LET_REC,
+    FORM_NAME_LET_REC_STAR, FORM_NAME_LET_REC_SYNTAX, FORM_NAME_LET_STAR,
+    FORM_NAME_LET_STAR_VALUES, FORM_NAME_LET_SYNTAX, FORM_NAME_LET_VALUES, FORM_NAME_OR,
+    FORM_NAME_QUASI_QUOTE, FORM_NAME_QUOTE, FORM_NAME_SET, FORM_NAME_SYNTAX_ERROR,
+    FORM_NAME_SYNTAX_RULES, FORM_NAME_UNLESS, FORM_NAME_UNQUOTE, FORM_NAME_UNQUOTE_SPLICING,
+    FORM_NAME_WHEN, FORM_PART_ARROW, PSEUDO_SYNTAX_COLON_CHAR, PSEUDO_SYNTAX_LEFT_PROCEDURE,
+    PSEUDO_SYNTAX_RANGE, PSEUDO_SYNTAX_RIGHT_PROCEDURE,
 };
-use crate::types::lists::{list_to_vec, TYPE_NAME_LIST};
+use crate::types::lists::{list_to_vec, vec_to_improper_list, TYPE_NAME_LIST};
+use crate::types::strings::TYPE_NAME_STRING;
 use crate::types::symbols::TYPE_NAME_SYMBOL;
-use crate::types::{Identifier, MutableRef, Ref, SchemeRepr, SchemeValue};
+use crate::types::{Boolean, Identifier, MutableRef, Ref, SchemeRepr, SchemeValue, Vector};
 use std::fmt::{Debug, Formatter};
 
 // ------------------------------------------------------------------------------------------------
@@ -65,6 +76,29 @@ pub fn standard_form_exports() -> Exports {
     let mut exports = syntactic_core_exports();
 
     export_standard_form!(exports, FORM_NAME_BEGIN => begin ; "expression-or-definition");
+    export_standard_form!(exports, FORM_NAME_COND => cond "clause" ; "clause");
+    export_standard_form!(exports, FORM_NAME_CASE => case "key" "clause" ; "clause");
+    export_standard_form!(exports, FORM_NAME_AND => and ; "test");
+    export_standard_form!(exports, FORM_NAME_OR => or ; "test");
+    export_standard_form!(exports, FORM_NAME_WHEN => when "test" "expression" ; "expression");
+    export_standard_form!(exports, FORM_NAME_UNLESS => unless "test" "expression" ; "expression");
+    export_standard_form!(exports, FORM_NAME_LET => bind_let "bindings" "body" ; "body");
+    export_standard_form!(exports, FORM_NAME_LET_STAR => bind_let_star "bindings" "body" ; "body");
+    export_standard_form!(exports, FORM_NAME_LET_REC => bind_let_rec "bindings" "body" ; "body");
+    export_standard_form!(exports, FORM_NAME_LET_REC_STAR => bind_let_rec_star "bindings" "body" ; "body");
+    export_standard_form!(exports, FORM_NAME_LET_VALUES => bind_values_let "mv-bindings" "body" ; "body");
+    export_standard_form!(exports, FORM_NAME_LET_STAR_VALUES => bind_let_values_star "mv-bindings" "body" ; "body");
+    export_standard_form!(exports, FORM_NAME_DO => iter_do "iteration-specs" "test-and-result" ; "command");
+    export_standard_form!(exports, FORM_NAME_GUARD => guard "clauses" "body" ; "body");
+    export_standard_form!(exports, FORM_NAME_QUASI_QUOTE => quasi_quote "qq-template");
+    export_standard_form!(exports, FORM_NAME_UNQUOTE => unquote "qq-template");
+    export_standard_form!(exports, FORM_NAME_UNQUOTE_SPLICING => unquote_splicing "qq-template");
+    export_standard_form!(exports, FORM_NAME_LET_SYNTAX => let_syntax "bindings" "body" ; "body");
+    export_standard_form!(exports, FORM_NAME_LET_REC_SYNTAX => let_rec_syntax "bindings" "body" ; "body");
+    export_standard_form!(exports, FORM_NAME_SYNTAX_RULES => syntax_rules "literals" ; "syntax-rule");
+    export_standard_form!(exports, FORM_NAME_SYNTAX_ERROR => syntax_error "message" ; "args");
+    export_standard_form!(exports, FORM_NAME_DEFINE_SYNTAX => define_syntax "keyword" "transformer-spec");
+    export_standard_form!(exports, FORM_NAME_DEFINE_VALUES => define_values "formals" "expression");
     export_standard_form!(exports, FORM_NAME_DEFINE => define "variable-or-formals" "expression-or-body" ; "expression-or-body");
 
     exports
@@ -153,16 +187,10 @@ impl Callable<Ref<Datum>> for Form {
         arguments: Vec<Ref<Datum>>,
         environment: &mut MutableRef<Environment>,
     ) -> Result<Expression, Error> {
-        let argument_len = arguments.len();
-        if argument_len < self.min_arg_count()
-            || (!self.has_variadic_argument() && argument_len > self.max_arg_count().unwrap())
-        {
-            return Err(Error::from(ErrorKind::BadFormSyntax {
-                name: self.id.clone(),
-                value: "incorrect argument count".to_string(),
-            }));
-        }
-        (self.body)(arguments, environment)
+        let result = self
+            .call_tail(arguments, environment)
+            .map_err(|error| raise_error(error, environment))?;
+        trampoline(result, environment)
     }
 }
 
@@ -178,6 +206,27 @@ impl Form {
             body,
         }
     }
+
+    ///
+    /// Call this form, any expression in tail position is returned as an `Expression::TailCall`
+    /// for the evaluator to continue with.
+    ///
+    pub fn call_tail(
+        &self,
+        arguments: Vec<Ref<Datum>>,
+        environment: &mut MutableRef<Environment>,
+    ) -> Result<Expression, Error> {
+        let argument_len = arguments.len();
+        if argument_len < self.min_arg_count()
+            || (!self.has_variadic_argument() && argument_len > self.max_arg_count().unwrap())
+        {
+            return Err(Error::from(ErrorKind::BadFormSyntax {
+                name: self.id.clone(),
+                value: "incorrect argument count".to_string(),
+            }));
+        }
+        (self.body)(arguments, environment)
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -189,6 +238,278 @@ pub fn head(arguments: &mut Vec<Ref<Datum>>) -> Ref<Datum> {
     arguments.remove(0)
 }
 
+///
+/// Evaluate a body, or sequence, of expressions returning the last as a tail call.
+///
+fn eval_sequence(
+    mut body: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    match body.pop() {
+        None => Ok(Expression::Unspecified),
+        Some(last) => {
+            for datum in body {
+                let _ = datum.eval(environment)?;
+            }
+            Ok(Expression::new_tail_call(last, environment))
+        }
+    }
+}
+
+///
+/// Evaluate the body of a `cond` or `case` clause, `value` is the result of the clause's test and
+/// is passed to the receiver of an `=>` clause.
+///
+fn eval_clause_body(
+    form_name: &str,
+    value: Option<Expression>,
+    mut body: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    if body.len() == 2 && is_keyword(&body[0], FORM_PART_ARROW) {
+        match (value, body.remove(1).eval(environment)?) {
+            (Some(value), Expression::Procedure(receiver)) => {
+                Ok(Expression::TailCall(TailCall::Apply(receiver, vec![value])))
+            }
+            (Some(value), Expression::Continuation(receiver)) => receiver.call(vec![value]),
+            (None, _) => Err(Error::from(ErrorKind::BadFormSyntax {
+                name: Identifier::from_str_unchecked(form_name),
+                value: format!("{} {}", FORM_NAME_ELSE, FORM_PART_ARROW),
+            })),
+            (_, receiver) => Err(Error::from(ErrorKind::UnexpectedType {
+                expected: TYPE_NAME_PROCEDURE.to_string(),
+                actual: Some(receiver.type_name().to_string()),
+            })),
+        }
+    } else {
+        eval_sequence(body, environment)
+    }
+}
+
+///
+/// Evaluate the clauses of a `cond`, or `guard`, returning `None` if no clause was selected.
+///
+fn eval_cond_clauses(
+    form_name: &str,
+    clauses: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Option<Expression>, Error> {
+    for clause in clauses {
+        let mut clause = clause_to_vec(form_name, &clause)?;
+        let test = head(&mut clause);
+        if is_keyword(&test, FORM_NAME_ELSE) {
+            return eval_clause_body(form_name, None, clause, environment).map(Some);
+        }
+        let result = test.eval(environment)?;
+        if result.is_true() {
+            return if clause.is_empty() {
+                Ok(Some(result))
+            } else {
+                eval_clause_body(form_name, Some(result), clause, environment).map(Some)
+            };
+        }
+    }
+    Ok(None)
+}
+
+fn clause_to_vec(form_name: &str, clause: &Datum) -> Result<Vec<Ref<Datum>>, Error> {
+    match clause {
+        Datum::List(list) if list.is_proper_list() => Ok(list_to_vec(list.clone())),
+        _ => Err(Error::from(ErrorKind::BadFormSyntax {
+            name: Identifier::from_str_unchecked(form_name),
+            value: clause.to_repr_string(),
+        })),
+    }
+}
+
+#[inline]
+fn is_keyword(datum: &Datum, keyword: &str) -> bool {
+    matches!(datum, Datum::Symbol(id) if unalias(id).as_str() == keyword)
+}
+
+///
+/// The `eqv?` comparison used by `case` between the evaluated key and the clause data.
+///
+fn case_datum_matches(key: &Expression, datum: &Datum) -> bool {
+    match (key, datum) {
+        (Expression::Boolean(key), Datum::Boolean(datum)) => key == datum,
+        (Expression::Number(key), Datum::Number(datum)) => key == datum,
+        (Expression::Character(key), Datum::Character(datum)) => key == datum,
+        (Expression::Identifier(key), Datum::Symbol(datum)) => *key == unalias(datum),
+        (Expression::Null, Datum::Null) => true,
+        (Expression::Quotation(key), datum) => match (&**key, datum) {
+            (Datum::Symbol(key), Datum::Symbol(datum)) => *key == unalias(datum),
+            (Datum::Null, Datum::Null) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+///
+/// Parse the bindings `((variable init) ...)` used by the `let` family of forms.
+///
+fn let_bindings(form_name: &str, bindings: &Datum) -> Result<Vec<(Identifier, Ref<Datum>)>, Error> {
+    let_bindings_with(form_name, bindings, datum_to_id)
+}
+
+///
+/// Parse the bindings `((formals init) ...)` used by `let-values` and `let*-values`.
+///
+#[allow(clippy::type_complexity)]
+fn let_values_bindings(
+    form_name: &str,
+    bindings: &Datum,
+) -> Result<Vec<((Vec<Identifier>, Option<Identifier>), Ref<Datum>)>, Error> {
+    let_bindings_with(form_name, bindings, |binding| {
+        datum_to_formals(form_name, &binding)
+    })
+}
+
+fn let_bindings_with<T>(
+    form_name: &str,
+    bindings: &Datum,
+    binding_fn: impl Fn(Ref<Datum>) -> Result<T, Error>,
+) -> Result<Vec<(T, Ref<Datum>)>, Error> {
+    let bad_syntax = |datum: &Datum| {
+        Error::from(ErrorKind::BadFormSyntax {
+            name: Identifier::from_str_unchecked(form_name),
+            value: datum.to_repr_string(),
+        })
+    };
+    match bindings {
+        Datum::Null => Ok(Vec::default()),
+        Datum::List(list) if list.is_proper_list() => list
+            .iter()
+            .map(|binding| match &*binding {
+                Datum::List(pair) if pair.is_proper_list() && pair.length() == 2 => {
+                    let mut pair = list_to_vec(pair.clone());
+                    let init = pair.remove(1);
+                    Ok((binding_fn(head(&mut pair))?, init))
+                }
+                _ => Err(bad_syntax(&binding)),
+            })
+            .collect(),
+        _ => Err(bad_syntax(bindings)),
+    }
+}
+
+///
+/// Parse the iteration specs `((variable init step) ...)` of a `do` form, the step is optional.
+///
+#[allow(clippy::type_complexity)]
+fn do_iteration_specs(
+    specs: &Datum,
+) -> Result<Vec<(Identifier, Ref<Datum>, Option<Ref<Datum>>)>, Error> {
+    let bad_syntax = |datum: &Datum| {
+        Error::from(ErrorKind::BadFormSyntax {
+            name: Identifier::from_str_unchecked(FORM_NAME_DO),
+            value: datum.to_repr_string(),
+        })
+    };
+    match specs {
+        Datum::Null => Ok(Vec::default()),
+        Datum::List(list) if list.is_proper_list() => list
+            .iter()
+            .map(|spec| match &*spec {
+                Datum::List(pair)
+                    if pair.is_proper_list() && (pair.length() == 2 || pair.length() == 3) =>
+                {
+                    let mut spec = list_to_vec(pair.clone());
+                    let id = datum_to_id(head(&mut spec))?;
+                    let init = head(&mut spec);
+                    Ok((id, init, spec.pop()))
+                }
+                _ => Err(bad_syntax(&spec)),
+            })
+            .collect(),
+        _ => Err(bad_syntax(specs)),
+    }
+}
+
+///
+/// Create the environment for `letrec` and `letrec*` with all variables bound, but as yet
+/// uninitialized.
+///
+fn uninitialized_environment(
+    form_name: &str,
+    bindings: &[(Identifier, Ref<Datum>)],
+    environment: &MutableRef<Environment>,
+) -> Result<MutableRef<Environment>, Error> {
+    let inner = Environment::new_child_named(environment.clone(), form_name);
+    for (id, _) in bindings {
+        let _ = inner
+            .borrow_mut()
+            .insert(id.clone(), Expression::Uninitialized)?;
+    }
+    Ok(inner)
+}
+
+///
+/// Bind values to formals in the same manner as procedure arguments, the formals may include
+/// a variadic formal which takes any remaining values as a list.
+///
+fn bind_formals(
+    form_name: &str,
+    environment: &MutableRef<Environment>,
+    formals: Vec<Identifier>,
+    variadic: Option<Identifier>,
+    mut values: Vec<Expression>,
+) -> Result<(), Error> {
+    let given = values.len();
+    if given < formals.len() || (variadic.is_none() && given > formals.len()) {
+        return Err(Error::from(ErrorKind::ValuesCardinality {
+            name: Identifier::from_str_unchecked(form_name),
+            min: formals.len(),
+            max: if variadic.is_none() {
+                Some(formals.len())
+            } else {
+                None
+            },
+            given,
+        }));
+    }
+    let mut environment = environment.borrow_mut();
+    for formal in formals {
+        let _ = environment.insert(formal, values.remove(0))?;
+    }
+    if let Some(variadic) = variadic {
+        let _ = environment.insert(variadic, Expression::from_list(values))?;
+    }
+    Ok(())
+}
+
+///
+/// Parse the formals of a lambda, `(a b)`, `(a b . c)` or `c`, into a list of identifiers and
+/// an optional variadic identifier.
+///
+fn datum_to_formals(
+    form_name: &str,
+    formals: &Datum,
+) -> Result<(Vec<Identifier>, Option<Identifier>), Error> {
+    if let Datum::Symbol(symbol) = formals {
+        Ok((vec![], Some(symbol.clone())))
+    } else if formals.is_null() {
+        Ok((vec![], None))
+    } else if let Datum::List(list) = formals {
+        let vector: Result<Vec<Identifier>, _> = list_to_vec(list.clone())
+            .into_iter()
+            .map(datum_to_id)
+            .collect();
+        if list.is_proper_list() {
+            Ok((vector?, None))
+        } else {
+            let last_pair = list.last();
+            Ok((vector?, Some(datum_to_id(last_pair.cdr().clone())?)))
+        }
+    } else {
+        Err(Error::from(ErrorKind::BadFormSyntax {
+            name: Identifier::from_str_unchecked(form_name),
+            value: formals.to_repr_string(),
+        }))
+    }
+}
+
 fn datum_to_id(datum: Ref<Datum>) -> Result<Identifier, Error> {
     if let Datum::Symbol(symbol) = &*datum {
         Ok(symbol.clone())
@@ -200,6 +521,144 @@ fn datum_to_id(datum: Ref<Datum>) -> Result<Identifier, Error> {
     }
 }
 
+///
+/// Returns the kind and operand if `datum` is any of the quotation forms, in either the
+/// abbreviated (`` `x ``) or long (`(quasiquote x)`) syntax.
+///
+fn quasi_quote_form(datum: &Datum) -> Option<(Abbreviation, Ref<Datum>)> {
+    match datum {
+        Datum::Abbreviation(abbreviation, datum) => Some((abbreviation.clone(), datum.clone())),
+        Datum::List(list) if list.is_proper_list() && list.length() == 2 => {
+            let abbreviation = match &*list.car() {
+                Datum::Symbol(id) => match unalias(id).as_str() {
+                    FORM_NAME_QUOTE => Abbreviation::Quote,
+                    FORM_NAME_QUASI_QUOTE => Abbreviation::QuasiQuote,
+                    FORM_NAME_UNQUOTE => Abbreviation::Unquote,
+                    FORM_NAME_UNQUOTE_SPLICING => Abbreviation::UnquoteSplicing,
+                    _ => return None,
+                },
+                _ => return None,
+            };
+            Some((abbreviation, list_to_vec(list.clone()).remove(1)))
+        }
+        _ => None,
+    }
+}
+
+///
+/// Evaluate the transformer spec for the macro `keyword`, which must result in a macro.
+///
+fn eval_transformer(
+    keyword: Identifier,
+    spec: &Datum,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    match spec.eval(environment)? {
+        Expression::Macro(mut syntax) => {
+            syntax.rename(keyword);
+            Ok(Expression::Macro(syntax))
+        }
+        value => Err(Error::from(ErrorKind::UnexpectedType {
+            expected: TYPE_NAME_MACRO.to_string(),
+            actual: Some(value.type_name().to_string()),
+        })),
+    }
+}
+
+///
+/// Construct the datum described by a quasiquote `template`, `depth` is the current nesting level
+/// of quasiquote forms; only unquotes at depth 1 are evaluated.
+///
+fn quasi_quote_datum(
+    template: &Ref<Datum>,
+    depth: usize,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Ref<Datum>, Error> {
+    if let Some((abbreviation, datum)) = quasi_quote_form(template) {
+        let depth = match abbreviation {
+            Abbreviation::Quote => depth,
+            Abbreviation::QuasiQuote => depth + 1,
+            Abbreviation::Unquote if depth == 1 => {
+                return Ok(datum.eval(environment)?.into_datum());
+            }
+            Abbreviation::UnquoteSplicing if depth == 1 => {
+                return Err(Error::from(ErrorKind::BadFormSyntax {
+                    name: Identifier::from_str_unchecked(FORM_NAME_UNQUOTE_SPLICING),
+                    value: format!("not within a list or vector: {}", template.to_repr_string()),
+                }));
+            }
+            Abbreviation::Unquote | Abbreviation::UnquoteSplicing => depth - 1,
+        };
+        Ok(Ref::new(Datum::Abbreviation(
+            abbreviation,
+            quasi_quote_datum(&datum, depth, environment)?,
+        )))
+    } else {
+        match &**template {
+            Datum::List(_) => {
+                let mut data = Vec::default();
+                let mut rest = template.clone();
+                while let (Datum::List(list), None) = (&*rest, quasi_quote_form(&rest)) {
+                    quasi_quote_element(&list.car(), depth, &mut data, environment)?;
+                    rest = list.cdr();
+                }
+                let tail = quasi_quote_datum(&rest, depth, environment)?;
+                Ok(vec_to_improper_list(data, tail))
+            }
+            Datum::Vector(vector) => {
+                let mut data = Vec::default();
+                for datum in vector.as_vec().iter() {
+                    quasi_quote_element(datum, depth, &mut data, environment)?;
+                }
+                Ok(Ref::new(Datum::Vector(Vector::from(data))))
+            }
+            _ => Ok(strip_aliases(template)),
+        }
+    }
+}
+
+///
+/// Add the result of a single list or vector element to `data`, splicing in the elements of the
+/// list produced by an `unquote-splicing` at depth 1.
+///
+fn quasi_quote_element(
+    template: &Ref<Datum>,
+    depth: usize,
+    data: &mut Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<(), Error> {
+    match quasi_quote_form(template) {
+        Some((Abbreviation::UnquoteSplicing, datum)) if depth == 1 => {
+            match datum.eval(environment)? {
+                Expression::Null => {}
+                Expression::List(values) => {
+                    data.extend(values.into_iter().map(Expression::into_datum))
+                }
+                Expression::Quotation(datum) => match &*datum {
+                    Datum::Null => {}
+                    Datum::List(list) if list.is_proper_list() => {
+                        data.extend(list_to_vec(list.clone()))
+                    }
+                    _ => {
+                        return Err(Error::from(ErrorKind::UnexpectedType {
+                            expected: TYPE_NAME_LIST.to_string(),
+                            actual: Some(datum.type_name().to_string()),
+                        }))
+                    }
+                },
+                value => {
+                    return Err(Error::from(ErrorKind::UnexpectedType {
+                        expected: TYPE_NAME_LIST.to_string(),
+                        actual: Some(value.type_name().to_string()),
+                    }))
+                }
+            }
+        }
+        _ => data.push(quasi_quote_datum(template, depth, environment)?),
+    }
+    Ok(())
+}
+
 // §4.1.2. Literal expressions --------------------------------------------------------------------
 
 pub fn quote(
@@ -212,7 +671,7 @@ pub fn quote(
             value: format!("{:?}", arguments),
         }))
     } else {
-        Ok(Expression::Quotation(head(&mut arguments)))
+        Ok(Expression::from_datum(strip_aliases(&head(&mut arguments))))
     }
 }
 
@@ -220,37 +679,19 @@ pub fn quote(
 
 fn lambda(
     mut arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    env: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
     let formals = head(&mut arguments);
-    let (formals, variadic): (Vec<Identifier>, Option<Identifier>) =
-        if let Datum::Symbol(symbol) = &*formals {
-            (vec![], Some(symbol.clone()))
-        } else if let Datum::List(list) = &*formals {
-            let vector: Result<Vec<Identifier>, _> = list_to_vec(list.clone())
-                .into_iter()
-                .map(|d| datum_to_id(d))
-                .collect();
-            if list.is_proper_list() {
-                (vector?, None)
-            } else {
-                let last_pair = list.last();
-                (vector?, Some(datum_to_id(last_pair.cdr().clone())?))
-            }
-        } else {
-            return Err(Error::from(ErrorKind::BadFormSyntax {
-                name: Identifier::from_str_unchecked(FORM_NAME_LAMBDA),
-                value: format!("{:?}", formals),
-            }));
-        };
+    let (formals, variadic) = datum_to_formals(FORM_NAME_LAMBDA, &formals)?;
 
-    let bodies = datum_to_vec(head(&mut arguments));
+    let bodies = arguments;
 
     Ok(Expression::Procedure(Procedure::new_lambda(
         Identifier::from_str_unchecked("|<unknown>|"),
         formals,
         variadic,
         bodies,
+        env.clone(),
     )))
 }
 
@@ -264,10 +705,10 @@ fn conditional(
     let result = test.eval(environment)?;
     if result.is_true() {
         let consequent = head(&mut arguments);
-        consequent.eval(environment)
+        Ok(Expression::new_tail_call(consequent, environment))
     } else if arguments.len() == 2 {
         let alternate = arguments.remove(1);
-        alternate.eval(environment)
+        Ok(Expression::new_tail_call(alternate, environment))
     } else {
         Ok(Expression::Unspecified)
     }
@@ -282,7 +723,16 @@ fn set_bang(
     let variable = head(&mut arguments);
     if let Datum::Symbol(id) = &*variable {
         let expr = head(&mut arguments).eval(env)?;
-        env.borrow_mut().update(id.clone(), expr)?;
+        let result = env.borrow_mut().update(id.clone(), expr.clone());
+        match (result, resolve_alias(id)) {
+            // introduced by a macro, and not bound by the expansion itself.
+            (Err(_), Some((name, environment))) => {
+                let _ = environment.borrow_mut().update(name, expr)?;
+            }
+            (result, _) => {
+                let _ = result?;
+            }
+        }
         Ok(Expression::Unspecified)
     } else {
         Err(Error::from(ErrorKind::UnexpectedType {
@@ -299,45 +749,98 @@ fn set_bang(
 // §4.2. Derived expression types -----------------------------------------------------------------
 
 fn cond(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    Ok(eval_cond_clauses(FORM_NAME_COND, arguments, environment)?
+        .unwrap_or(Expression::Unspecified))
 }
 
 fn case(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let key = head(&mut arguments).eval(environment)?;
+    for clause in arguments {
+        let mut clause = clause_to_vec(FORM_NAME_CASE, &clause)?;
+        let data = head(&mut clause);
+        let matched = if is_keyword(&data, FORM_NAME_ELSE) {
+            true
+        } else if data.is_null() {
+            false
+        } else if let Datum::List(data) = &*data {
+            data.iter().any(|datum| case_datum_matches(&key, &datum))
+        } else {
+            return Err(Error::from(ErrorKind::BadFormSyntax {
+                name: Identifier::from_str_unchecked(FORM_NAME_CASE),
+                value: data.to_repr_string(),
+            }));
+        };
+        if matched {
+            return eval_clause_body(FORM_NAME_CASE, Some(key), clause, environment);
+        }
+    }
+    Ok(Expression::Unspecified)
 }
 
 fn and(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    match arguments.pop() {
+        None => Ok(etrue!()),
+        Some(last) => {
+            for test in arguments {
+                let result = test.eval(environment)?;
+                if result.is_false() {
+                    return Ok(result);
+                }
+            }
+            Ok(Expression::new_tail_call(last, environment))
+        }
+    }
 }
 
 fn or(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    match arguments.pop() {
+        None => Ok(efalse!()),
+        Some(last) => {
+            for test in arguments {
+                let result = test.eval(environment)?;
+                if result.is_true() {
+                    return Ok(result);
+                }
+            }
+            Ok(Expression::new_tail_call(last, environment))
+        }
+    }
 }
 
 fn when(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let test = head(&mut arguments);
+    if test.eval(environment)?.is_true() {
+        eval_sequence(arguments, environment)
+    } else {
+        Ok(Expression::Unspecified)
+    }
 }
 
 fn unless(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let test = head(&mut arguments);
+    if test.eval(environment)?.is_false() {
+        eval_sequence(arguments, environment)
+    } else {
+        Ok(Expression::Unspecified)
+    }
 }
 
 fn cond_expand(
@@ -350,45 +853,89 @@ fn cond_expand(
 // 4.2.2. Binding constructs ----------------------------------------------------------------------
 
 fn bind_let(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    if arguments[0].is_symbol() {
+        return iter_let(arguments, environment);
+    }
+    let bindings = let_bindings(FORM_NAME_LET, &head(&mut arguments))?;
+    let mut inner = Environment::new_child_named(environment.clone(), FORM_NAME_LET);
+    for (id, init) in bindings {
+        let value = init.eval(environment)?;
+        let _ = inner.borrow_mut().insert(id, value)?;
+    }
+    eval_sequence(arguments, &mut inner)
 }
 
 fn bind_let_star(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let bindings = let_bindings(FORM_NAME_LET_STAR, &head(&mut arguments))?;
+    let mut inner = environment.clone();
+    for (id, init) in bindings {
+        let value = init.eval(&mut inner)?;
+        inner = Environment::new_child_named(inner, FORM_NAME_LET_STAR);
+        let _ = inner.borrow_mut().insert(id, value)?;
+    }
+    eval_sequence(arguments, &mut inner)
 }
 
 fn bind_let_rec(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let bindings = let_bindings(FORM_NAME_LET_REC, &head(&mut arguments))?;
+    let mut inner = uninitialized_environment(FORM_NAME_LET_REC, &bindings, environment)?;
+    let values: Result<Vec<Expression>, Error> = bindings
+        .iter()
+        .map(|(_, init)| init.eval(&mut inner))
+        .collect();
+    for ((id, _), value) in bindings.into_iter().zip(values?) {
+        let _ = inner.borrow_mut().insert(id, value)?;
+    }
+    eval_sequence(arguments, &mut inner)
 }
 
 fn bind_let_rec_star(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let bindings = let_bindings(FORM_NAME_LET_REC_STAR, &head(&mut arguments))?;
+    let mut inner = uninitialized_environment(FORM_NAME_LET_REC_STAR, &bindings, environment)?;
+    for (id, init) in bindings {
+        let value = init.eval(&mut inner)?;
+        let _ = inner.borrow_mut().insert(id, value)?;
+    }
+    eval_sequence(arguments, &mut inner)
 }
 
-fn bind_values_let(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+pub fn bind_values_let(
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let bindings = let_values_bindings(FORM_NAME_LET_VALUES, &head(&mut arguments))?;
+    let mut inner = Environment::new_child_named(environment.clone(), FORM_NAME_LET_VALUES);
+    for ((formals, variadic), init) in bindings {
+        let values = init.eval(environment)?.into_values();
+        bind_formals(FORM_NAME_LET_VALUES, &inner, formals, variadic, values)?;
+    }
+    eval_sequence(arguments, &mut inner)
 }
 
 fn bind_let_values_star(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let bindings = let_values_bindings(FORM_NAME_LET_STAR_VALUES, &head(&mut arguments))?;
+    let mut inner = environment.clone();
+    for ((formals, variadic), init) in bindings {
+        let values = init.eval(&mut inner)?.into_values();
+        inner = Environment::new_child_named(inner, FORM_NAME_LET_STAR_VALUES);
+        bind_formals(FORM_NAME_LET_STAR_VALUES, &inner, formals, variadic, values)?;
+    }
+    eval_sequence(arguments, &mut inner)
 }
 
 // 4.2.3. Sequencing ------------------------------------------------------------------------------
@@ -397,27 +944,74 @@ fn begin(
     arguments: Vec<Ref<Datum>>,
     environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    arguments
-        .into_iter()
-        .fold(Ok(Expression::Unspecified), |_, datum| {
-            datum.eval(environment)
-        })
+    eval_sequence(arguments, environment)
 }
 
 // 4.2.4. Iteration -------------------------------------------------------------------------------
 
 fn iter_do(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let specs = do_iteration_specs(&head(&mut arguments))?;
+    let mut test_and_result = clause_to_vec(FORM_NAME_DO, &head(&mut arguments))?;
+    let test = head(&mut test_and_result);
+    let commands = arguments;
+
+    let values: Result<Vec<Expression>, Error> = specs
+        .iter()
+        .map(|(_, init, _)| init.eval(environment))
+        .collect();
+    let mut values = values?;
+    loop {
+        // each iteration has a fresh set of bindings, so closures created in the body of one
+        // iteration do not see the updates made by the next.
+        let mut inner = Environment::new_child_named(environment.clone(), FORM_NAME_DO);
+        for ((id, _, _), value) in specs.iter().zip(values) {
+            let _ = inner.borrow_mut().insert(id.clone(), value)?;
+        }
+
+        if test.eval(&mut inner)?.is_true() {
+            return eval_sequence(test_and_result, &mut inner);
+        }
+
+        for command in &commands {
+            let _ = command.eval(&mut inner)?;
+        }
+
+        let next_values: Result<Vec<Expression>, Error> = specs
+            .iter()
+            .map(|(id, _, step)| match step {
+                Some(step) => step.eval(&mut inner),
+                None => id.eval(&mut inner),
+            })
+            .collect();
+        values = next_values?;
+    }
 }
 
 fn iter_let(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let name = datum_to_id(head(&mut arguments))?;
+    let bindings = let_bindings(FORM_NAME_LET, &head(&mut arguments))?;
+    let (formals, inits): (Vec<Identifier>, Vec<Ref<Datum>>) = bindings.into_iter().unzip();
+    let arguments_values: Result<Vec<Expression>, Error> =
+        inits.iter().map(|init| init.eval(environment)).collect();
+
+    // the procedure is bound in its own environment so that the body may call it recursively,
+    // but the initial values cannot see it.
+    let inner = Environment::new_child_named(environment.clone(), name.as_str());
+    let procedure = Procedure::new_lambda(name.clone(), formals, None, arguments, inner.clone());
+    let _ = inner
+        .borrow_mut()
+        .insert(name, Expression::Procedure(procedure.clone()))?;
+
+    Ok(Expression::TailCall(TailCall::Apply(
+        procedure,
+        arguments_values?,
+    )))
 }
 
 // §4.2.5. Delayed evaluation ---------------------------------------------------------------------
@@ -443,33 +1037,71 @@ fn parameterize(
 // §4.2.7. Exception handling ---------------------------------------------------------------------
 
 fn guard(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let mut clauses = clause_to_vec(FORM_NAME_GUARD, &head(&mut arguments))?;
+    if clauses.is_empty() {
+        return Err(Error::from(ErrorKind::BadFormSyntax {
+            name: Identifier::from_str_unchecked(FORM_NAME_GUARD),
+            value: "missing variable".to_string(),
+        }));
+    }
+    let variable = datum_to_id(head(&mut clauses))?;
+    let outer = environment.clone();
+    // the body is not in tail position, it must be evaluated here for the guard to see
+    // any exception it raises.
+    exceptions::guard(
+        || trampoline(eval_sequence(arguments, environment)?, environment),
+        Ref::new(move |condition| {
+            let mut environment = Environment::new_child_named(outer.clone(), FORM_NAME_GUARD);
+            let _ = environment
+                .borrow_mut()
+                .insert(variable.clone(), condition)?;
+            eval_cond_clauses(FORM_NAME_GUARD, clauses.clone(), &mut environment)
+        }),
+    )
 }
 
 // §4.2.8. Quasiquotation -------------------------------------------------------------------------
 
 pub fn quasi_quote(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    env: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    if arguments.len() != 1 {
+        return Err(Error::from(ErrorKind::BadFormSyntax {
+            name: Identifier::from_str_unchecked(FORM_NAME_QUASI_QUOTE),
+            value: format!("{:?}", arguments),
+        }));
+    }
+    let template = head(&mut arguments);
+    match quasi_quote_form(&template) {
+        Some((Abbreviation::Unquote, expression)) => expression.eval(env),
+        _ => Ok(Expression::from_datum(quasi_quote_datum(
+            &template, 1, env,
+        )?)),
+    }
 }
 
 pub fn unquote(
-    _arguments: Vec<Ref<Datum>>,
+    arguments: Vec<Ref<Datum>>,
     _env: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    Err(Error::from(ErrorKind::BadFormSyntax {
+        name: Identifier::from_str_unchecked(FORM_NAME_UNQUOTE),
+        value: format!("not within {}: {:?}", FORM_NAME_QUASI_QUOTE, arguments),
+    }))
 }
 
 pub fn unquote_splicing(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &MutableRef<Environment>,
+    arguments: Vec<Ref<Datum>>,
+    _env: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    Err(Error::from(ErrorKind::BadFormSyntax {
+        name: Identifier::from_str_unchecked(FORM_NAME_UNQUOTE_SPLICING),
+        value: format!("not within {}: {:?}", FORM_NAME_QUASI_QUOTE, arguments),
+    }))
 }
 
 // §4.2.9. Case-lambda ----------------------------------------------------------------------------
@@ -484,35 +1116,60 @@ fn case_lambda(
 // §4.3.1. Binding constructs for syntactic keywords ----------------------------------------------
 
 fn let_syntax(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    env: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let bindings = let_bindings(FORM_NAME_LET_SYNTAX, &head(&mut arguments))?;
+    let mut inner = Environment::new_child_named(env.clone(), FORM_NAME_LET_SYNTAX);
+    for (id, spec) in bindings {
+        let syntax = eval_transformer(id.clone(), &spec, env)?;
+        let _ = inner.borrow_mut().insert(id, syntax)?;
+    }
+    eval_sequence(arguments, &mut inner)
 }
 
 fn let_rec_syntax(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    env: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let bindings = let_bindings(FORM_NAME_LET_REC_SYNTAX, &head(&mut arguments))?;
+    let mut inner = Environment::new_child_named(env.clone(), FORM_NAME_LET_REC_SYNTAX);
+    for (id, spec) in bindings {
+        let syntax = eval_transformer(id.clone(), &spec, &mut inner)?;
+        let _ = inner.borrow_mut().insert(id, syntax)?;
+    }
+    eval_sequence(arguments, &mut inner)
 }
 
 // §4.3.2. Pattern language -----------------------------------------------------------------------
 
 fn syntax_rules(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &MutableRef<Environment>,
+    arguments: Vec<Ref<Datum>>,
+    env: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    Ok(Expression::Macro(Macro::new_syntax_rules(arguments, env)?))
 }
 
 // §4.3.3. Signaling errors in macro transformers -------------------------------------------------
 
 fn syntax_error(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    _env: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let message = head(&mut arguments);
+    match &*message {
+        Datum::String(message) => Err(Error::from(ErrorKind::SyntaxError {
+            message: message.to_string(),
+            irritants: arguments
+                .iter()
+                .map(|datum| strip_aliases(datum).to_repr_string())
+                .collect(),
+        })),
+        _ => Err(Error::from(ErrorKind::UnexpectedType {
+            expected: TYPE_NAME_STRING.to_string(),
+            actual: Some(message.type_name().to_string()),
+        })),
+    }
 }
 
 // §5.2. Import declarations ----------------------------------------------------------------------
@@ -552,8 +1209,13 @@ fn define(
 
             let bodies = arguments;
 
-            let value =
-                Expression::Procedure(Procedure::new_lambda(id.clone(), formals, variadic, bodies));
+            let value = Expression::Procedure(Procedure::new_lambda(
+                id.clone(),
+                formals,
+                variadic,
+                bodies,
+                env.clone(),
+            ));
             let _ = env.borrow_mut().insert(id, value);
             Ok(Expression::Unspecified)
         }
@@ -574,19 +1236,25 @@ fn define(
 // §5.3.3. Multiple-value definitions -------------------------------------------------------------
 
 fn define_values(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    env: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let (formals, variadic) = datum_to_formals(FORM_NAME_DEFINE_VALUES, &head(&mut arguments))?;
+    let values = head(&mut arguments).eval(env)?.into_values();
+    bind_formals(FORM_NAME_DEFINE_VALUES, env, formals, variadic, values)?;
+    Ok(Expression::Unspecified)
 }
 
 // §5.4. Syntax definitions -----------------------------------------------------------------------
 
 fn define_syntax(
-    _arguments: Vec<Ref<Datum>>,
-    _env: &mut MutableRef<Environment>,
+    mut arguments: Vec<Ref<Datum>>,
+    env: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let keyword = datum_to_id(head(&mut arguments))?;
+    let syntax = eval_transformer(keyword.clone(), &head(&mut arguments), env)?;
+    let _ = env.borrow_mut().insert(keyword, syntax)?;
+    Ok(Expression::Unspecified)
 }
 
 // §5.6.1. Library Syntax -------------------------------------------------------------------------
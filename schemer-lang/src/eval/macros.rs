@@ -0,0 +1,696 @@
+/*!
+Macros defined with `syntax-rules`, and their expansion.
+
+Hygiene is implemented by renaming: each identifier a template introduces into an expansion is
+replaced by a fresh alias, an uninterned identifier with the same name which records the original
+identifier and the environment in which the macro was defined. An alias bound by the expansion itself, say the temporary in a `swap!` macro, is
+distinct from any identifier in the macro use; an alias that is not bound is resolved as the
+original name in the macro's environment, see `resolve_alias`.
+
+# Example
+
+```scheme
+(define-syntax swap!
+  (syntax-rules ()
+    ((_ a b) (let ((tmp a)) (set! a b) (set! b tmp)))))
+```
+
+*/
+
+use crate::error::{Error, ErrorKind};
+use crate::eval::Environment;
+use crate::read::datum::Datum;
+use crate::read::syntax_str::{
+    FORM_NAME_SYNTAX_RULES, PSEUDO_SYNTAX_COLON_CHAR, PSEUDO_SYNTAX_LEFT_PROCEDURE,
+    PSEUDO_SYNTAX_RIGHT_PROCEDURE, SYNTAX_ELLIPSIS, SYNTAX_UNDERSCORE,
+};
+use crate::types::lists::{list_to_vec, vec_to_improper_list};
+use crate::types::{Identifier, MutableRef, Pair, Ref, SchemeRepr, SchemeValue, Vector};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone)]
+pub struct Macro {
+    id: Identifier,
+    rules: Ref<SyntaxRules>,
+    environment: MutableRef<Environment>,
+}
+
+///
+/// The identifier that an alias replaced, and the environment in which the macro that introduced
+/// it was defined. An alias holds this itself, so that it lives only as long as the expansion.
+///
+pub(crate) struct Alias {
+    name: Identifier,
+    environment: MutableRef<Environment>,
+}
+
+pub const TYPE_NAME_MACRO: &str = "macro";
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct SyntaxRules {
+    ellipsis: Option<Identifier>,
+    literals: Vec<Identifier>,
+    rules: Vec<(Ref<Datum>, Ref<Datum>)>,
+}
+
+#[derive(Clone, Debug)]
+enum Binding {
+    One(Ref<Datum>),
+    Many(Vec<Binding>),
+}
+
+type Bindings = BTreeMap<Identifier, Binding>;
+
+type Renames = BTreeMap<Identifier, Identifier>;
+
+thread_local! {
+    // Set once any alias has been created, until then there are none to strip from data.
+    static ALIASED: Cell<bool> = const { Cell::new(false) };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// If `id` was introduced by a macro expansion return the identifier it replaced, and the
+/// environment in which the macro was defined.
+///
+pub fn resolve_alias(id: &Identifier) -> Option<(Identifier, MutableRef<Environment>)> {
+    id.alias()
+        .map(|alias| (alias.name.clone(), alias.environment.clone()))
+}
+
+///
+/// The identifier as it was written, before any renaming by macro expansion.
+///
+pub fn unalias(id: &Identifier) -> Identifier {
+    match resolve_alias(id) {
+        Some((name, _)) => unalias(&name),
+        None => id.clone(),
+    }
+}
+
+///
+/// Replace any aliases in `datum` with the identifiers they replaced, this is used where a
+/// template contains literal data such as `(quote tmp)`.
+///
+pub fn strip_aliases(datum: &Ref<Datum>) -> Ref<Datum> {
+    if !ALIASED.with(Cell::get) {
+        return datum.clone();
+    }
+    match &**datum {
+        Datum::Symbol(id) if resolve_alias(id).is_some() => Ref::new(Datum::Symbol(unalias(id))),
+        Datum::List(list) => {
+            let car = strip_aliases(&list.car());
+            let cdr = strip_aliases(&list.cdr());
+            if Ref::ptr_eq(&car, &list.car()) && Ref::ptr_eq(&cdr, &list.cdr()) {
+                datum.clone()
+            } else {
+                Ref::new(Datum::List(Pair::cons(car, cdr)))
+            }
+        }
+        Datum::Vector(vector) => {
+            let data: Vec<Ref<Datum>> = vector.as_vec().iter().map(strip_aliases).collect();
+            if data
+                .iter()
+                .zip(vector.as_vec().iter())
+                .all(|(l, r)| Ref::ptr_eq(l, r))
+            {
+                datum.clone()
+            } else {
+                Ref::new(Datum::Vector(Vector::from(data)))
+            }
+        }
+        Datum::Abbreviation(abbreviation, inner) => {
+            let stripped = strip_aliases(inner);
+            if Ref::ptr_eq(&stripped, inner) {
+                datum.clone()
+            } else {
+                Ref::new(Datum::Abbreviation(abbreviation.clone(), stripped))
+            }
+        }
+        _ => datum.clone(),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Debug for Alias {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // the environment is omitted, it is usually large and may well contain this alias.
+        f.debug_struct("Alias").field("name", &self.name).finish()
+    }
+}
+
+impl Alias {
+    pub(crate) fn name(&self) -> &Identifier {
+        &self.name
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Debug for Macro {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Macro")
+            .field("id", &self.id)
+            .field("ellipsis", &self.rules.ellipsis)
+            .field("literals", &self.rules.literals)
+            .field("rules", &self.rules.rules)
+            .finish()
+    }
+}
+
+impl PartialEq for Macro {
+    fn eq(&self, other: &Self) -> bool {
+        // as for closures, the environment is compared by identity.
+        self.id == other.id
+            && Ref::ptr_eq(&self.rules, &other.rules)
+            && Ref::ptr_eq(&self.environment, &other.environment)
+    }
+}
+
+impl SchemeRepr for Macro {
+    fn to_repr_string(&self) -> String {
+        format!(
+            "{}{}{}{}{}",
+            PSEUDO_SYNTAX_LEFT_PROCEDURE,
+            self.type_name(),
+            PSEUDO_SYNTAX_COLON_CHAR,
+            self.id.to_repr_string(),
+            PSEUDO_SYNTAX_RIGHT_PROCEDURE
+        )
+    }
+}
+
+impl SchemeValue for Macro {
+    fn type_name(&self) -> &'static str {
+        TYPE_NAME_MACRO
+    }
+}
+
+impl Macro {
+    ///
+    /// Create a new macro from the arguments of a `syntax-rules` form, that is an optional
+    /// ellipsis identifier, the list of literals, and the rules.
+    ///
+    pub fn new_syntax_rules(
+        mut arguments: Vec<Ref<Datum>>,
+        environment: &MutableRef<Environment>,
+    ) -> Result<Self, Error> {
+        let ellipsis = match arguments.first().map(|datum| &**datum) {
+            Some(Datum::Symbol(id)) => {
+                let id = id.clone();
+                let _ = arguments.remove(0);
+                id
+            }
+            _ => Identifier::from_str_unchecked(SYNTAX_ELLIPSIS),
+        };
+        if arguments.is_empty() {
+            return Err(bad_syntax_rules("missing literals"));
+        }
+        let literals = match &*arguments.remove(0) {
+            Datum::Null => Vec::default(),
+            Datum::List(list) if list.is_proper_list() => list
+                .iter()
+                .map(|datum| match &*datum {
+                    Datum::Symbol(id) => Ok(id.clone()),
+                    _ => Err(bad_syntax_rules(&datum.to_repr_string())),
+                })
+                .collect::<Result<Vec<Identifier>, Error>>()?,
+            datum => return Err(bad_syntax_rules(&datum.to_repr_string())),
+        };
+        let rules = arguments
+            .into_iter()
+            .map(|rule| match &*rule {
+                Datum::List(list) if list.is_proper_list() && list.length() == 2 => {
+                    let mut rule = list_to_vec(list.clone());
+                    let template = rule.remove(1);
+                    let pattern = rule.remove(0);
+                    if pattern.is_pair() {
+                        Ok((pattern, template))
+                    } else {
+                        Err(bad_syntax_rules(&pattern.to_repr_string()))
+                    }
+                }
+                _ => Err(bad_syntax_rules(&rule.to_repr_string())),
+            })
+            .collect::<Result<Vec<(Ref<Datum>, Ref<Datum>)>, Error>>()?;
+        let ellipsis = if literals.contains(&ellipsis) {
+            None
+        } else {
+            Some(ellipsis)
+        };
+        Ok(Self {
+            id: Identifier::from_str_unchecked(FORM_NAME_SYNTAX_RULES),
+            rules: Ref::new(SyntaxRules {
+                ellipsis,
+                literals,
+                rules,
+            }),
+            environment: environment.clone(),
+        })
+    }
+
+    pub fn id(&self) -> &Identifier {
+        &self.id
+    }
+
+    pub fn rename(&mut self, id: Identifier) {
+        self.id = id;
+    }
+
+    pub fn environment(&self) -> &MutableRef<Environment> {
+        &self.environment
+    }
+
+    ///
+    /// Expand the macro use `form`, in `environment`, the result is the datum to evaluate in its
+    /// place. Each expansion introduces its own aliases, and so a macro use is expanded each time
+    /// it is evaluated.
+    ///
+    pub fn expand(
+        &self,
+        form: &Pair,
+        environment: &MutableRef<Environment>,
+    ) -> Result<Ref<Datum>, Error> {
+        for (pattern, template) in &self.rules.rules {
+            let mut bindings = Bindings::default();
+            // the keyword position of the pattern is ignored.
+            if let Datum::List(pattern) = &**pattern {
+                if self.match_pattern(&pattern.cdr(), &form.cdr(), environment, &mut bindings) {
+                    return self.expand_template(
+                        template,
+                        &bindings,
+                        &mut Renames::default(),
+                        false,
+                    );
+                }
+            }
+        }
+        Err(Error::from(ErrorKind::BadFormSyntax {
+            name: self.id.clone(),
+            value: format!(
+                "no rule matches {}",
+                Datum::List(form.clone()).to_repr_string()
+            ),
+        }))
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    fn is_literal(&self, id: &Identifier) -> bool {
+        let id = unalias(id);
+        self.rules
+            .literals
+            .iter()
+            .any(|literal| unalias(literal) == id)
+    }
+
+    ///
+    /// An identifier in the macro use, in `environment`, matches the `literal` if both have the
+    /// same binding, or if both are unbound and have the same name.
+    ///
+    fn matches_literal(
+        &self,
+        literal: &Identifier,
+        form: &Identifier,
+        environment: &MutableRef<Environment>,
+    ) -> bool {
+        match (
+            binding_of(literal, &self.environment),
+            binding_of(form, environment),
+        ) {
+            (Some((literal, literal_env)), Some((form, form_env))) => {
+                literal == form && Ref::ptr_eq(&literal_env, &form_env)
+            }
+            (None, None) => unalias(literal) == unalias(form),
+            _ => false,
+        }
+    }
+
+    fn is_ellipsis(&self, datum: &Datum) -> bool {
+        match (datum, &self.rules.ellipsis) {
+            (Datum::Symbol(id), Some(ellipsis)) => unalias(id) == unalias(ellipsis),
+            _ => false,
+        }
+    }
+
+    fn is_underscore(&self, id: &Identifier) -> bool {
+        unalias(id).as_str() == SYNTAX_UNDERSCORE
+    }
+
+    fn match_pattern(
+        &self,
+        pattern: &Ref<Datum>,
+        form: &Ref<Datum>,
+        environment: &MutableRef<Environment>,
+        bindings: &mut Bindings,
+    ) -> bool {
+        match &**pattern {
+            Datum::Symbol(id) if self.is_literal(id) => {
+                matches!(&**form, Datum::Symbol(form) if self.matches_literal(id, form, environment))
+            }
+            Datum::Symbol(id) if self.is_underscore(id) => true,
+            Datum::Symbol(id) => {
+                let _ = bindings.insert(id.clone(), Binding::One(form.clone()));
+                true
+            }
+            Datum::List(_) => {
+                let (patterns, pattern_tail) = list_parts(pattern);
+                let (forms, form_tail) = list_parts(form);
+                self.match_sequence(
+                    &patterns,
+                    &pattern_tail,
+                    &forms,
+                    &form_tail,
+                    environment,
+                    bindings,
+                )
+            }
+            Datum::Vector(patterns) => match &**form {
+                Datum::Vector(forms) => {
+                    let null = Ref::new(Datum::Null);
+                    self.match_sequence(
+                        &patterns.as_vec(),
+                        &null,
+                        &forms.as_vec(),
+                        &null,
+                        environment,
+                        bindings,
+                    )
+                }
+                _ => false,
+            },
+            _ => pattern == form,
+        }
+    }
+
+    fn match_sequence(
+        &self,
+        patterns: &[Ref<Datum>],
+        pattern_tail: &Ref<Datum>,
+        forms: &[Ref<Datum>],
+        form_tail: &Ref<Datum>,
+        environment: &MutableRef<Environment>,
+        bindings: &mut Bindings,
+    ) -> bool {
+        match patterns
+            .iter()
+            .position(|pattern| self.is_ellipsis(pattern))
+        {
+            Some(0) => false,
+            Some(index) => {
+                let before = &patterns[..index - 1];
+                let repeated = &patterns[index - 1];
+                let after = &patterns[index + 1..];
+                if forms.len() < before.len() + after.len() {
+                    return false;
+                }
+                let repeat_end = forms.len() - after.len();
+                if !self.match_each(before, &forms[..before.len()], environment, bindings)
+                    || !self.match_each(after, &forms[repeat_end..], environment, bindings)
+                    || !self.match_pattern(pattern_tail, form_tail, environment, bindings)
+                {
+                    return false;
+                }
+                let mut matches: Vec<Bindings> = Vec::default();
+                for form in &forms[before.len()..repeat_end] {
+                    let mut repeat_bindings = Bindings::default();
+                    if !self.match_pattern(repeated, form, environment, &mut repeat_bindings) {
+                        return false;
+                    }
+                    matches.push(repeat_bindings);
+                }
+                for variable in self.pattern_variables(repeated) {
+                    let values = matches
+                        .iter_mut()
+                        .filter_map(|repeat_bindings| repeat_bindings.remove(&variable))
+                        .collect();
+                    let _ = bindings.insert(variable, Binding::Many(values));
+                }
+                true
+            }
+            None => {
+                if forms.len() < patterns.len() {
+                    return false;
+                }
+                let rest =
+                    vec_to_improper_list(forms[patterns.len()..].to_vec(), form_tail.clone());
+                self.match_each(patterns, &forms[..patterns.len()], environment, bindings)
+                    && self.match_pattern(pattern_tail, &rest, environment, bindings)
+            }
+        }
+    }
+
+    fn match_each(
+        &self,
+        patterns: &[Ref<Datum>],
+        forms: &[Ref<Datum>],
+        environment: &MutableRef<Environment>,
+        bindings: &mut Bindings,
+    ) -> bool {
+        patterns
+            .iter()
+            .zip(forms.iter())
+            .all(|(pattern, form)| self.match_pattern(pattern, form, environment, bindings))
+    }
+
+    fn pattern_variables(&self, pattern: &Ref<Datum>) -> Vec<Identifier> {
+        match &**pattern {
+            Datum::Symbol(id)
+                if !(self.is_literal(id)
+                    || self.is_underscore(id)
+                    || self.is_ellipsis(pattern)) =>
+            {
+                vec![id.clone()]
+            }
+            Datum::List(_) => {
+                let (patterns, tail) = list_parts(pattern);
+                patterns
+                    .iter()
+                    .chain(std::iter::once(&tail))
+                    .flat_map(|pattern| self.pattern_variables(pattern))
+                    .collect()
+            }
+            Datum::Vector(patterns) => patterns
+                .as_vec()
+                .iter()
+                .flat_map(|pattern| self.pattern_variables(pattern))
+                .collect(),
+            _ => Vec::default(),
+        }
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    fn expand_template(
+        &self,
+        template: &Ref<Datum>,
+        bindings: &Bindings,
+        renames: &mut Renames,
+        escaped: bool,
+    ) -> Result<Ref<Datum>, Error> {
+        match &**template {
+            Datum::Symbol(id) => match bindings.get(id) {
+                Some(Binding::One(datum)) => Ok(datum.clone()),
+                Some(Binding::Many(_)) => Err(self.bad_template(template)),
+                None => Ok(Ref::new(Datum::Symbol(self.alias(id, renames)))),
+            },
+            Datum::List(list) => {
+                if !escaped && self.is_ellipsis(&list.car()) {
+                    // (... template) escapes any ellipsis in template.
+                    return match &*list.cdr() {
+                        Datum::List(rest) if rest.cdr().is_null() => {
+                            self.expand_template(&rest.car(), bindings, renames, true)
+                        }
+                        _ => Err(self.bad_template(template)),
+                    };
+                }
+                let (templates, tail) = list_parts(template);
+                let data = self.expand_sequence(&templates, bindings, renames, escaped)?;
+                let tail = self.expand_template(&tail, bindings, renames, escaped)?;
+                Ok(vec_to_improper_list(data, tail))
+            }
+            Datum::Vector(templates) => Ok(Ref::new(Datum::Vector(Vector::from(
+                self.expand_sequence(&templates.as_vec(), bindings, renames, escaped)?,
+            )))),
+            Datum::Abbreviation(abbreviation, inner) => Ok(Ref::new(Datum::Abbreviation(
+                abbreviation.clone(),
+                self.expand_template(inner, bindings, renames, escaped)?,
+            ))),
+            _ => Ok(template.clone()),
+        }
+    }
+
+    fn expand_sequence(
+        &self,
+        templates: &[Ref<Datum>],
+        bindings: &Bindings,
+        renames: &mut Renames,
+        escaped: bool,
+    ) -> Result<Vec<Ref<Datum>>, Error> {
+        let mut data = Vec::default();
+        let mut index = 0;
+        while index < templates.len() {
+            let template = &templates[index];
+            let mut depth = 0;
+            while !escaped
+                && index + depth + 1 < templates.len()
+                && self.is_ellipsis(&templates[index + depth + 1])
+            {
+                depth += 1;
+            }
+            if depth == 0 {
+                data.push(self.expand_template(template, bindings, renames, escaped)?);
+            } else {
+                data.extend(self.expand_repeated(template, depth, bindings, renames)?);
+            }
+            index += depth + 1;
+        }
+        Ok(data)
+    }
+
+    fn expand_repeated(
+        &self,
+        template: &Ref<Datum>,
+        depth: usize,
+        bindings: &Bindings,
+        renames: &mut Renames,
+    ) -> Result<Vec<Ref<Datum>>, Error> {
+        let mut variables: Vec<(Identifier, &Vec<Binding>)> = Default::default();
+        for id in template_symbols(template) {
+            if let Some(Binding::Many(values)) = bindings.get(&id) {
+                variables.push((id, values));
+            }
+        }
+        let length = match variables.first() {
+            None => return Err(self.bad_template(template)),
+            Some((_, values)) => values.len(),
+        };
+        if variables.iter().any(|(_, values)| values.len() != length) {
+            return Err(self.bad_template(template));
+        }
+        let mut data = Vec::default();
+        for index in 0..length {
+            let mut repeat_bindings = bindings.clone();
+            for (id, values) in &variables {
+                let _ = repeat_bindings.insert(id.clone(), values[index].clone());
+            }
+            if depth > 1 {
+                data.extend(self.expand_repeated(
+                    template,
+                    depth - 1,
+                    &repeat_bindings,
+                    renames,
+                )?);
+            } else {
+                data.push(self.expand_template(template, &repeat_bindings, renames, false)?);
+            }
+        }
+        Ok(data)
+    }
+
+    fn alias(&self, id: &Identifier, renames: &mut Renames) -> Identifier {
+        renames
+            .entry(id.clone())
+            .or_insert_with(|| new_alias(id, &self.environment))
+            .clone()
+    }
+
+    fn bad_template(&self, template: &Datum) -> Error {
+        Error::from(ErrorKind::BadFormSyntax {
+            name: self.id.clone(),
+            value: format!("bad template {}", template.to_repr_string()),
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn new_alias(id: &Identifier, environment: &MutableRef<Environment>) -> Identifier {
+    ALIASED.with(|aliased| aliased.set(true));
+    Identifier::new_alias(Alias {
+        name: id.clone(),
+        environment: environment.clone(),
+    })
+}
+
+///
+/// The identifier, and the environment, of the binding that `id` refers to in `environment`; an
+/// alias not bound by its expansion refers to the binding of the identifier it replaced.
+///
+fn binding_of(
+    id: &Identifier,
+    environment: &MutableRef<Environment>,
+) -> Option<(Identifier, MutableRef<Environment>)> {
+    let mut current = environment.clone();
+    loop {
+        if current.borrow().is_bound_locally(id) {
+            return Some((id.clone(), current));
+        }
+        let parent = current.borrow().parent().cloned();
+        match parent {
+            Some(parent) => current = parent,
+            None => break,
+        }
+    }
+    resolve_alias(id).and_then(|(name, environment)| binding_of(&name, &environment))
+}
+
+///
+/// Split a list into its elements and final `cdr`, which is `Datum::Null` for a proper list. Any
+/// other datum is returned as the tail of an empty list.
+///
+fn list_parts(datum: &Ref<Datum>) -> (Vec<Ref<Datum>>, Ref<Datum>) {
+    let mut data = Vec::default();
+    let mut rest = datum.clone();
+    while let Datum::List(list) = &*rest.clone() {
+        data.push(list.car().clone());
+        rest = list.cdr().clone();
+    }
+    (data, rest)
+}
+
+fn template_symbols(template: &Ref<Datum>) -> Vec<Identifier> {
+    match &**template {
+        Datum::Symbol(id) => vec![id.clone()],
+        Datum::List(_) => {
+            let (templates, tail) = list_parts(template);
+            templates
+                .iter()
+                .chain(std::iter::once(&tail))
+                .flat_map(template_symbols)
+                .collect()
+        }
+        Datum::Vector(templates) => templates
+            .as_vec()
+            .iter()
+            .flat_map(template_symbols)
+            .collect(),
+        Datum::Abbreviation(_, inner) => template_symbols(inner),
+        _ => Vec::default(),
+    }
+}
+
+fn bad_syntax_rules(value: &str) -> Error {
+    Error::from(ErrorKind::BadFormSyntax {
+        name: Identifier::from_str_unchecked(FORM_NAME_SYNTAX_RULES),
+        value: value.to_string(),
+    })
+}
@@ -33,14 +33,26 @@ More detailed description, with
 
 pub mod callable;
 
+pub mod continuations;
+pub use continuations::Continuation;
+
 pub mod environment;
 pub use environment::{Environment, ExportList, Exports};
 
+pub mod exceptions;
+pub use exceptions::ErrorObject;
+
 pub mod expression;
-pub use expression::{Evaluate, Expression};
+pub use expression::{Evaluate, Expression, TailCall};
 
 pub mod forms;
 pub use forms::Form;
 
+pub mod macros;
+pub use macros::Macro;
+
+pub mod ports;
+pub use ports::Port;
+
 pub mod procedures;
 pub use procedures::Procedure;
@@ -0,0 +1,769 @@
+/*!
+Ports, from R7RS §6.13; the objects through which textual and binary data is read and written.
+
+A port is either an input or an output port, and is either textual or binary. Ports are shared
+rather than copied, so reading from a port through one reference advances it for every reference
+to the same port. Input is decoded as UTF-8 a character at a time, with any bytes examined by
+`peek-char` or `peek-u8` held until they are read.
+
+The current input, output, and error ports are initially bound to the process's standard input,
+output, and error streams. String and bytevector ports read from, or accumulate output in, memory
+but are otherwise the same as any other port.
+
+# Example
+
+```scheme
+(define port (current-output-port))
+(output-port? port)        ;; => #t
+(textual-port? port)       ;; => #t
+(write-string "hello" port)
+```
+
+*/
+
+use crate::error::{Error, ErrorKind};
+use crate::read::syntax_str::{
+    PSEUDO_SYNTAX_COLON_CHAR, PSEUDO_SYNTAX_LEFT_PROCEDURE, PSEUDO_SYNTAX_RIGHT_PROCEDURE,
+};
+use crate::types::{MutableRef, SchemeRepr, SchemeValue};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+use std::io::{BufRead, BufReader, Cursor, Write};
+use std::thread::LocalKey;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone)]
+pub struct Port(MutableRef<PortState>);
+
+pub const TYPE_NAME_PORT: &str = "port";
+
+pub const TYPE_NAME_INPUT_PORT: &str = "input-port";
+
+pub const TYPE_NAME_OUTPUT_PORT: &str = "output-port";
+
+pub const TYPE_NAME_TEXTUAL_PORT: &str = "textual-port";
+
+pub const TYPE_NAME_BINARY_PORT: &str = "binary-port";
+
+pub const PORT_NAME_STDIN: &str = "stdin";
+
+pub const PORT_NAME_STDOUT: &str = "stdout";
+
+pub const PORT_NAME_STDERR: &str = "stderr";
+
+pub const PORT_NAME_STRING: &str = "string";
+
+pub const PORT_NAME_BYTE_VECTOR: &str = "bytevector";
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+struct PortState {
+    name: String,
+    textual: bool,
+    /// An interactive port may block waiting for input, so it is only ready when input has
+    /// already been received.
+    interactive: bool,
+    /// Whether identifiers read from this port are case folded, set by the `#!fold-case` and
+    /// `#!no-fold-case` directives.
+    fold_case: bool,
+    direction: Direction,
+}
+
+///
+/// The source or sink of a port, which is `None` once the port has been closed.
+///
+enum Direction {
+    Input(Option<Input>),
+    Output(Option<Output>),
+}
+
+struct Input {
+    reader: Box<dyn BufRead>,
+    /// Bytes taken from `reader` by a peek, or a partial read, and not yet consumed.
+    lookahead: VecDeque<u8>,
+}
+
+enum Output {
+    Stream(Box<dyn Write>),
+    /// The output of a string or bytevector port, kept until it is retrieved with
+    /// `get-output-string` or `get-output-bytevector`.
+    Buffer(Vec<u8>),
+}
+
+///
+/// The current input, output, and error ports, as saved by a continuation.
+///
+#[derive(Clone)]
+pub(crate) struct CurrentPorts {
+    input: Port,
+    output: Port,
+    error: Port,
+}
+
+///
+/// Restores the port `saved` as the current port `key` when dropped, unless the port installed
+/// in its place has since been replaced; as it will have been if a continuation was invoked.
+///
+struct RestorePort {
+    key: &'static LocalKey<RefCell<Port>>,
+    installed: Port,
+    saved: Option<Port>,
+}
+
+thread_local! {
+    static CURRENT_INPUT_PORT: RefCell<Port> = RefCell::new(Port::stdin());
+    static CURRENT_OUTPUT_PORT: RefCell<Port> = RefCell::new(Port::stdout());
+    static CURRENT_ERROR_PORT: RefCell<Port> = RefCell::new(Port::stderr());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn current_input_port() -> Port {
+    CURRENT_INPUT_PORT.with(|port| port.borrow().clone())
+}
+
+pub fn current_output_port() -> Port {
+    CURRENT_OUTPUT_PORT.with(|port| port.borrow().clone())
+}
+
+pub fn current_error_port() -> Port {
+    CURRENT_ERROR_PORT.with(|port| port.borrow().clone())
+}
+
+///
+/// Call `f` with `port` as the current input port, the previous current input port is restored
+/// once `f` returns, or is unwound.
+///
+pub fn with_current_input_port<T>(port: Port, f: impl FnOnce() -> T) -> T {
+    let _restore = RestorePort::replace(&CURRENT_INPUT_PORT, port);
+    f()
+}
+
+///
+/// Call `f` with `port` as the current output port, the previous current output port is
+/// restored once `f` returns, or is unwound.
+///
+pub fn with_current_output_port<T>(port: Port, f: impl FnOnce() -> T) -> T {
+    let _restore = RestorePort::replace(&CURRENT_OUTPUT_PORT, port);
+    f()
+}
+
+///
+/// The current ports, as saved by a continuation.
+///
+pub(crate) fn current_ports() -> CurrentPorts {
+    CurrentPorts {
+        input: current_input_port(),
+        output: current_output_port(),
+        error: current_error_port(),
+    }
+}
+
+///
+/// Install `ports`, as saved by `current_ports`, as the current ports.
+///
+pub(crate) fn restore_ports(ports: CurrentPorts) {
+    let CurrentPorts {
+        input,
+        output,
+        error,
+    } = ports;
+    let _ = CURRENT_INPUT_PORT.with(|current| current.replace(input));
+    let _ = CURRENT_OUTPUT_PORT.with(|current| current.replace(output));
+    let _ = CURRENT_ERROR_PORT.with(|current| current.replace(error));
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl RestorePort {
+    fn replace(key: &'static LocalKey<RefCell<Port>>, port: Port) -> Self {
+        let saved = key.with(|current| current.replace(port.clone()));
+        Self {
+            key,
+            installed: port,
+            saved: Some(saved),
+        }
+    }
+}
+
+impl Drop for RestorePort {
+    fn drop(&mut self) {
+        if let Some(saved) = self.saved.take() {
+            let _ = self.key.try_with(|current| {
+                if current.borrow().ptr_eq(&self.installed) {
+                    let _ = current.replace(saved);
+                }
+            });
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Debug for Port {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let state = self.0.borrow();
+        f.debug_struct("Port")
+            .field("name", &state.name)
+            .field("textual", &state.textual)
+            .field("input", &self.is_input())
+            .field("open", &self.is_open())
+            .finish()
+    }
+}
+
+impl PartialEq for Port {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other)
+    }
+}
+
+impl SchemeRepr for Port {
+    fn to_repr_string(&self) -> String {
+        format!(
+            "{}{}{}{}{}",
+            PSEUDO_SYNTAX_LEFT_PROCEDURE,
+            self.type_name(),
+            PSEUDO_SYNTAX_COLON_CHAR,
+            self.name(),
+            PSEUDO_SYNTAX_RIGHT_PROCEDURE
+        )
+    }
+}
+
+impl SchemeValue for Port {
+    fn type_name(&self) -> &'static str {
+        if self.is_input() {
+            TYPE_NAME_INPUT_PORT
+        } else {
+            TYPE_NAME_OUTPUT_PORT
+        }
+    }
+}
+
+impl Port {
+    pub fn new_textual_input<R: BufRead + 'static>(name: &str, reader: R) -> Self {
+        Self::new_input(name, true, false, reader)
+    }
+
+    pub fn new_binary_input<R: BufRead + 'static>(name: &str, reader: R) -> Self {
+        Self::new_input(name, false, false, reader)
+    }
+
+    pub fn new_textual_output<W: Write + 'static>(name: &str, writer: W) -> Self {
+        Self::new_output(name, true, false, writer)
+    }
+
+    pub fn new_binary_output<W: Write + 'static>(name: &str, writer: W) -> Self {
+        Self::new_output(name, false, false, writer)
+    }
+
+    ///
+    /// A new textual input port reading the characters of `s`.
+    ///
+    pub fn new_input_string(s: &str) -> Self {
+        Self::new_textual_input(PORT_NAME_STRING, Cursor::new(s.as_bytes().to_vec()))
+    }
+
+    ///
+    /// A new binary input port reading `bytes`.
+    ///
+    pub fn new_input_bytes(bytes: Vec<u8>) -> Self {
+        Self::new_binary_input(PORT_NAME_BYTE_VECTOR, Cursor::new(bytes))
+    }
+
+    ///
+    /// A new textual output port accumulating the characters written to it, see `output_string`.
+    ///
+    pub fn new_output_string() -> Self {
+        Self::new(
+            PORT_NAME_STRING,
+            true,
+            false,
+            Direction::Output(Some(Output::Buffer(Vec::default()))),
+        )
+    }
+
+    ///
+    /// A new binary output port accumulating the bytes written to it, see `output_bytes`.
+    ///
+    pub fn new_output_bytes() -> Self {
+        Self::new(
+            PORT_NAME_BYTE_VECTOR,
+            false,
+            false,
+            Direction::Output(Some(Output::Buffer(Vec::default()))),
+        )
+    }
+
+    ///
+    /// A new textual input port reading the process's standard input.
+    ///
+    pub fn stdin() -> Self {
+        Self::new_input(
+            PORT_NAME_STDIN,
+            true,
+            true,
+            BufReader::new(std::io::stdin()),
+        )
+    }
+
+    ///
+    /// A new textual output port writing to the process's standard output.
+    ///
+    pub fn stdout() -> Self {
+        Self::new_output(PORT_NAME_STDOUT, true, true, std::io::stdout())
+    }
+
+    ///
+    /// A new textual output port writing to the process's standard error.
+    ///
+    pub fn stderr() -> Self {
+        Self::new_output(PORT_NAME_STDERR, true, true, std::io::stderr())
+    }
+
+    pub fn name(&self) -> String {
+        self.0.borrow().name.clone()
+    }
+
+    pub fn is_input(&self) -> bool {
+        matches!(self.0.borrow().direction, Direction::Input(_))
+    }
+
+    pub fn is_output(&self) -> bool {
+        matches!(self.0.borrow().direction, Direction::Output(_))
+    }
+
+    pub fn is_textual(&self) -> bool {
+        self.0.borrow().textual
+    }
+
+    pub fn is_binary(&self) -> bool {
+        !self.is_textual()
+    }
+
+    pub fn is_open(&self) -> bool {
+        match &self.0.borrow().direction {
+            Direction::Input(input) => input.is_some(),
+            Direction::Output(output) => output.is_some(),
+        }
+    }
+
+    ///
+    /// Close this port, any buffered output is flushed first. Closing a port that is already
+    /// closed has no effect.
+    ///
+    pub fn close(&self) -> Result<(), Error> {
+        match &mut self.0.borrow_mut().direction {
+            Direction::Input(input) => {
+                let _ = input.take();
+            }
+            Direction::Output(output) => {
+                if let Some(mut output) = output.take() {
+                    output.writer().flush()?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_fold_case(&self) -> bool {
+        self.0.borrow().fold_case
+    }
+
+    pub fn set_fold_case(&self, fold_case: bool) {
+        self.0.borrow_mut().fold_case = fold_case;
+    }
+
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        MutableRef::ptr_eq(&self.0, &other.0)
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// The next character from this textual input port, or `None` at the end of input.
+    ///
+    pub fn read_char(&self) -> Result<Option<char>, Error> {
+        self.with_input(true, |input| {
+            let c = input.peek_char()?;
+            if let Some(c) = c {
+                let _ = input.lookahead.drain(..c.len_utf8());
+            }
+            Ok(c)
+        })
+    }
+
+    ///
+    /// The next character from this textual input port, without consuming it, or `None` at the
+    /// end of input.
+    ///
+    pub fn peek_char(&self) -> Result<Option<char>, Error> {
+        self.with_input(true, |input| input.peek_char())
+    }
+
+    ///
+    /// The next line of text from this textual input port, without its line ending, or `None`
+    /// if the end of input has already been reached.
+    ///
+    pub fn read_line(&self) -> Result<Option<String>, Error> {
+        let mut line = match self.read_char()? {
+            None => return Ok(None),
+            Some('\n') => return Ok(Some(String::new())),
+            Some(c) => String::from(c),
+        };
+        loop {
+            match self.read_char()? {
+                None | Some('\n') => break,
+                Some(c) => line.push(c),
+            }
+        }
+        if line.ends_with('\r') {
+            let _ = line.pop();
+        }
+        Ok(Some(line))
+    }
+
+    ///
+    /// At most `k` characters from this textual input port, or `None` if the end of input has
+    /// already been reached.
+    ///
+    pub fn read_string(&self, k: usize) -> Result<Option<String>, Error> {
+        let mut result = String::new();
+        for _ in 0..k {
+            match self.read_char()? {
+                Some(c) => result.push(c),
+                None if result.is_empty() => return Ok(None),
+                None => break,
+            }
+        }
+        Ok(Some(result))
+    }
+
+    ///
+    /// Whether a character is ready on this textual input port, so that `read_char` will not
+    /// block.
+    ///
+    pub fn is_char_ready(&self) -> Result<bool, Error> {
+        self.is_ready(true)
+    }
+
+    ///
+    /// The next byte from this binary input port, or `None` at the end of input.
+    ///
+    pub fn read_u8(&self) -> Result<Option<u8>, Error> {
+        self.with_input(false, |input| {
+            let _ = input.fill(1)?;
+            Ok(input.lookahead.pop_front())
+        })
+    }
+
+    ///
+    /// The next byte from this binary input port, without consuming it, or `None` at the end of
+    /// input.
+    ///
+    pub fn peek_u8(&self) -> Result<Option<u8>, Error> {
+        self.with_input(false, |input| {
+            let _ = input.fill(1)?;
+            Ok(input.lookahead.front().copied())
+        })
+    }
+
+    ///
+    /// At most `k` bytes from this binary input port, or `None` if the end of input has already
+    /// been reached.
+    ///
+    pub fn read_bytes(&self, k: usize) -> Result<Option<Vec<u8>>, Error> {
+        self.with_input(false, |input| {
+            let available = input.fill(k)?;
+            if available == 0 && k > 0 {
+                Ok(None)
+            } else {
+                Ok(Some(input.lookahead.drain(..available.min(k)).collect()))
+            }
+        })
+    }
+
+    ///
+    /// Whether a byte is ready on this binary input port, so that `read_u8` will not block.
+    ///
+    pub fn is_u8_ready(&self) -> Result<bool, Error> {
+        self.is_ready(false)
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Write the string `s` to this textual output port.
+    ///
+    pub fn write_str(&self, s: &str) -> Result<(), Error> {
+        self.with_output(true, |writer| Ok(writer.write_all(s.as_bytes())?))
+    }
+
+    ///
+    /// Write the character `c` to this textual output port.
+    ///
+    pub fn write_char(&self, c: char) -> Result<(), Error> {
+        self.write_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    ///
+    /// Write `bytes` to this binary output port.
+    ///
+    pub fn write_bytes(&self, bytes: &[u8]) -> Result<(), Error> {
+        self.with_output(false, |writer| Ok(writer.write_all(bytes)?))
+    }
+
+    ///
+    /// Flush any buffered output from this output port, textual or binary.
+    ///
+    pub fn flush(&self) -> Result<(), Error> {
+        let textual = self.is_textual();
+        self.with_output(textual, |writer| Ok(writer.flush()?))
+    }
+
+    ///
+    /// The characters written so far to this port, which must have been created by
+    /// `new_output_string`.
+    ///
+    pub fn output_string(&self) -> Result<String, Error> {
+        // textual output is always written as valid UTF-8.
+        self.with_buffer(true, |buffer| {
+            Ok(String::from_utf8_lossy(buffer).into_owned())
+        })
+    }
+
+    ///
+    /// The bytes written so far to this port, which must have been created by
+    /// `new_output_bytes`.
+    ///
+    pub fn output_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.with_buffer(false, |buffer| Ok(buffer.clone()))
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    fn new_input<R: BufRead + 'static>(
+        name: &str,
+        textual: bool,
+        interactive: bool,
+        reader: R,
+    ) -> Self {
+        Self::new(
+            name,
+            textual,
+            interactive,
+            Direction::Input(Some(Input {
+                reader: Box::new(reader),
+                lookahead: Default::default(),
+            })),
+        )
+    }
+
+    fn new_output<W: Write + 'static>(
+        name: &str,
+        textual: bool,
+        interactive: bool,
+        writer: W,
+    ) -> Self {
+        Self::new(
+            name,
+            textual,
+            interactive,
+            Direction::Output(Some(Output::Stream(Box::new(writer)))),
+        )
+    }
+
+    fn new(name: &str, textual: bool, interactive: bool, direction: Direction) -> Self {
+        Self(MutableRef::new(RefCell::new(PortState {
+            name: name.to_string(),
+            textual,
+            interactive,
+            fold_case: false,
+            direction,
+        })))
+    }
+
+    fn is_ready(&self, textual: bool) -> Result<bool, Error> {
+        let interactive = self.0.borrow().interactive;
+        self.with_input(textual, |input| {
+            Ok(!interactive || !input.lookahead.is_empty())
+        })
+    }
+
+    fn with_input<T>(
+        &self,
+        textual: bool,
+        f: impl FnOnce(&mut Input) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        self.check_kind(true, textual)?;
+        let mut state = self.0.borrow_mut();
+        match &mut state.direction {
+            Direction::Input(Some(input)) => f(input),
+            _ => Err(Error::from(ErrorKind::ClosedPort {
+                name: state.name.clone(),
+            })),
+        }
+    }
+
+    fn with_output<T>(
+        &self,
+        textual: bool,
+        f: impl FnOnce(&mut dyn Write) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        self.check_kind(false, textual)?;
+        let mut state = self.0.borrow_mut();
+        match &mut state.direction {
+            Direction::Output(Some(output)) => f(output.writer()),
+            _ => Err(Error::from(ErrorKind::ClosedPort {
+                name: state.name.clone(),
+            })),
+        }
+    }
+
+    fn with_buffer<T>(
+        &self,
+        textual: bool,
+        f: impl FnOnce(&Vec<u8>) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        self.check_kind(false, textual)?;
+        let state = self.0.borrow();
+        match &state.direction {
+            Direction::Output(Some(Output::Buffer(buffer))) => f(buffer),
+            Direction::Output(Some(Output::Stream(_))) => {
+                Err(Error::from(ErrorKind::UnexpectedType {
+                    expected: format!(
+                        "{}-{}",
+                        if textual {
+                            PORT_NAME_STRING
+                        } else {
+                            PORT_NAME_BYTE_VECTOR
+                        },
+                        TYPE_NAME_OUTPUT_PORT
+                    ),
+                    actual: Some(state.name.clone()),
+                }))
+            }
+            _ => Err(Error::from(ErrorKind::ClosedPort {
+                name: state.name.clone(),
+            })),
+        }
+    }
+
+    fn check_kind(&self, input: bool, textual: bool) -> Result<(), Error> {
+        if self.is_input() != input || self.is_textual() != textual {
+            Err(Error::from(ErrorKind::UnexpectedType {
+                expected: format!(
+                    "{}-{}",
+                    if textual { "textual" } else { "binary" },
+                    if input {
+                        TYPE_NAME_INPUT_PORT
+                    } else {
+                        TYPE_NAME_OUTPUT_PORT
+                    }
+                ),
+                actual: Some(format!(
+                    "{}-{}",
+                    if self.is_textual() {
+                        "textual"
+                    } else {
+                        "binary"
+                    },
+                    self.type_name()
+                )),
+            }))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Output {
+    fn writer(&mut self) -> &mut dyn Write {
+        match self {
+            Self::Stream(writer) => writer.as_mut(),
+            Self::Buffer(buffer) => buffer,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Input {
+    ///
+    /// Ensure that at least `n` bytes are held in `lookahead`, unless the end of input is reached
+    /// first, returning the number of bytes held.
+    ///
+    fn fill(&mut self, n: usize) -> Result<usize, Error> {
+        while self.lookahead.len() < n {
+            let buffer = self.reader.fill_buf()?;
+            if buffer.is_empty() {
+                break;
+            }
+            let taken = buffer.len().min(n - self.lookahead.len());
+            self.lookahead.extend(&buffer[..taken]);
+            self.reader.consume(taken);
+        }
+        Ok(self.lookahead.len())
+    }
+
+    fn peek_char(&mut self) -> Result<Option<char>, Error> {
+        if self.fill(1)? == 0 {
+            return Ok(None);
+        }
+        let width = utf8_width(self.lookahead[0]);
+        if width == 0 || self.fill(width)? < width {
+            return Err(invalid_utf8());
+        }
+        let bytes: Vec<u8> = self.lookahead.range(..width).copied().collect();
+        match std::str::from_utf8(&bytes) {
+            Ok(s) => Ok(s.chars().next()),
+            Err(e) => Err(Error::chain(Box::new(e), ErrorKind::Read)),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The number of bytes in the UTF-8 encoding of a character starting with `first`, or `0` if
+/// `first` cannot start a character.
+///
+fn utf8_width(first: u8) -> usize {
+    match first {
+        0x00..=0x7F => 1,
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => 0,
+    }
+}
+
+fn invalid_utf8() -> Error {
+    Error::chain(
+        Box::new(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "input is not valid UTF-8",
+        )),
+        ErrorKind::Read,
+    )
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
@@ -9,6 +9,8 @@ More detailed description, with
 
 use crate::error::{Error, ErrorKind};
 use crate::eval::callable::Callable;
+use crate::eval::exceptions::raise_error;
+use crate::eval::expression::trampoline;
 use crate::eval::{Environment, Evaluate, Expression};
 use crate::read::datum::Datum;
 
@@ -34,7 +36,9 @@ pub struct Procedure {
 #[derive(Clone)]
 pub enum ProcedureBody {
     Builtin(BuiltinFn),
-    Lambda(Vec<Ref<Datum>>),
+    /// The body of a lambda, along with the environment in which it was defined; calls create
+    /// their frames as children of this captured environment, not the caller's.
+    Lambda(Vec<Ref<Datum>>, MutableRef<Environment>),
 }
 
 pub type BuiltinFn =
@@ -65,7 +69,7 @@ impl Debug for ProcedureBody {
                     ProcedureBody::Builtin(_) => String::from(
                         "fn(Vec<Expression>, &MutableRef<Environment>) -> Result<Expression, Error>>",
                     ),
-                    ProcedureBody::Lambda(v) => format!("{:?}", v),
+                    ProcedureBody::Lambda(v, _) => format!("{:?}", v),
                 },
             )
             .finish()
@@ -75,17 +79,14 @@ impl Debug for ProcedureBody {
 impl PartialEq for ProcedureBody {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::Builtin(lhs), Self::Builtin(rhs)) => {
-                lhs as *const dyn Fn(
-                    Vec<Expression>,
-                    &mut MutableRef<Environment>,
-                ) -> Result<Expression, Error>
-                    == rhs as *const dyn Fn(
-                        Vec<Expression>,
-                        &mut MutableRef<Environment>,
-                    ) -> Result<Expression, Error>
+            // compare the function references themselves, not the addresses at which they are
+            // stored in each body.
+            (Self::Builtin(lhs), Self::Builtin(rhs)) => std::ptr::eq(*lhs, *rhs),
+            (Self::Lambda(lhs, lhs_env), Self::Lambda(rhs, rhs_env)) => {
+                // compare environments by identity, a closure is reachable from its own
+                // environment and a structural comparison would never terminate.
+                lhs == rhs && Ref::ptr_eq(lhs_env, rhs_env)
             }
-            (Self::Lambda(lhs), Self::Lambda(rhs)) => lhs == rhs,
             _ => false,
         }
     }
@@ -116,7 +117,7 @@ impl SchemeValue for Procedure {
     fn type_name(&self) -> &'static str {
         match &self.body {
             ProcedureBody::Builtin(_) => TYPE_NAME_BUILTIN_PROCEDURE,
-            ProcedureBody::Lambda(_) => TYPE_NAME_PROCEDURE,
+            ProcedureBody::Lambda(_, _) => TYPE_NAME_PROCEDURE,
         }
     }
 }
@@ -140,49 +141,13 @@ impl Callable<Expression> for Procedure {
 
     fn call(
         &self,
-        mut arguments: Vec<Expression>,
+        arguments: Vec<Expression>,
         environment: &mut MutableRef<Environment>,
     ) -> Result<Expression, Error> {
-        let argument_len = arguments.len();
-        if argument_len < self.min_arg_count()
-            || (!self.has_variadic_argument() && argument_len > self.max_arg_count().unwrap())
-        {
-            return Err(Error::from(ErrorKind::ProcedureArgumentCardinality {
-                name: self.id.clone(),
-                min: self.min_arg_count(),
-                max: self.max_arg_count(),
-                given: argument_len,
-            }));
-        }
-        match &self.body {
-            ProcedureBody::Builtin(body) => (body)(arguments, environment),
-            ProcedureBody::Lambda(body) => {
-                let mut environment =
-                    Environment::new_child_named(environment.clone(), self.id().as_str());
-
-                for i in 0..self.min_arg_count() {
-                    let argument = arguments.remove(0);
-                    environment
-                        .borrow_mut()
-                        .insert(self.formals.get(i).unwrap().clone(), argument)?;
-                }
-
-                if self.has_variadic_argument() {
-                    environment.borrow_mut().insert(
-                        self.variadic_formal.as_ref().unwrap().clone(),
-                        if !arguments.is_empty() {
-                            Expression::List(arguments)
-                        } else {
-                            Expression::Null
-                        },
-                    )?;
-                }
-
-                body.iter().fold(Ok(Expression::Unspecified), |_, datum| {
-                    datum.eval(&mut environment)
-                })
-            }
-        }
+        let result = self
+            .call_tail(arguments, environment)
+            .map_err(|error| raise_error(error, environment))?;
+        trampoline(result, environment)
     }
 }
 
@@ -192,12 +157,13 @@ impl Procedure {
         formals: Vec<Identifier>,
         variadic_formal: Option<Identifier>,
         body: Vec<Ref<Datum>>,
+        environment: MutableRef<Environment>,
     ) -> Self {
         Self {
             id,
             formals,
             variadic_formal,
-            body: ProcedureBody::Lambda(body),
+            body: ProcedureBody::Lambda(body, environment),
         }
     }
 
@@ -218,6 +184,73 @@ impl Procedure {
         }
     }
 
+    ///
+    /// Whether this and `other` are the same procedure, as for `eqv?`; two lambdas are the same
+    /// if they share both their body and the environment in which they were created.
+    ///
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.body, &other.body) {
+            (ProcedureBody::Lambda(lhs, lhs_env), ProcedureBody::Lambda(rhs, rhs_env)) => {
+                Ref::ptr_eq(lhs_env, rhs_env)
+                    && lhs.len() == rhs.len()
+                    && lhs.iter().zip(rhs).all(|(lhs, rhs)| Ref::ptr_eq(lhs, rhs))
+            }
+            (lhs, rhs) => lhs == rhs,
+        }
+    }
+
+    ///
+    /// Call this procedure, however the last expression in a lambda body is not evaluated but
+    /// returned as an `Expression::TailCall` for the evaluator to continue with.
+    ///
+    pub fn call_tail(
+        &self,
+        mut arguments: Vec<Expression>,
+        environment: &mut MutableRef<Environment>,
+    ) -> Result<Expression, Error> {
+        let argument_len = arguments.len();
+        if argument_len < self.min_arg_count()
+            || (!self.has_variadic_argument() && argument_len > self.max_arg_count().unwrap())
+        {
+            return Err(Error::from(ErrorKind::ProcedureArgumentCardinality {
+                name: self.id.clone(),
+                min: self.min_arg_count(),
+                max: self.max_arg_count(),
+                given: argument_len,
+            }));
+        }
+        match &self.body {
+            ProcedureBody::Builtin(body) => (body)(arguments, environment),
+            ProcedureBody::Lambda(body, closure) => {
+                let mut environment =
+                    Environment::new_child_named(closure.clone(), self.id().as_str());
+
+                for i in 0..self.min_arg_count() {
+                    let argument = arguments.remove(0);
+                    environment
+                        .borrow_mut()
+                        .insert(self.formals.get(i).unwrap().clone(), argument)?;
+                }
+
+                if self.has_variadic_argument() {
+                    environment.borrow_mut().insert(
+                        self.variadic_formal.as_ref().unwrap().clone(),
+                        Expression::from_list(arguments),
+                    )?;
+                }
+
+                if let Some((last, body)) = body.split_last() {
+                    for datum in body {
+                        let _ = datum.eval(&mut environment)?;
+                    }
+                    Ok(Expression::new_tail_call(last.clone(), &environment))
+                } else {
+                    Ok(Expression::Unspecified)
+                }
+            }
+        }
+    }
+
     pub fn is_builtin(&self) -> bool {
         matches!(self.body, ProcedureBody::Builtin(_))
     }
@@ -44,6 +44,15 @@ pub fn get_global_flag(s: &str) -> Option<bool> {
     }
 }
 
+///
+/// Set the flag named `s` to `value`, returning its previous value or `None` if there is no flag
+/// with that name.
+///
+pub fn set_global_flag(s: &str, value: bool) -> Option<bool> {
+    let mut flag = GLOBAL_FLAGS.get(s).and_then(|f| f.write().ok())?;
+    Some(std::mem::replace(&mut *flag, value))
+}
+
 pub fn global_flags() -> Pair {
     vec_to_list(
         GLOBAL_FLAGS
@@ -8,6 +8,7 @@ More detailed description, with
 */
 
 use crate::error::{Error, ErrorKind};
+use crate::eval::Expression;
 use crate::parameters::{get_global_flag, WRITE_QUOTE_LONG_FORM};
 use crate::read::syntax_str::{
     FORM_NAME_QUASI_QUOTE, FORM_NAME_QUOTE, FORM_NAME_UNQUOTE, FORM_NAME_UNQUOTE_SPLICING,
@@ -15,7 +16,7 @@ use crate::read::syntax_str::{
     SYNTAX_HASH_CHAR, SYNTAX_LEFT_PARENTHESIS_CHAR, SYNTAX_MATH_EQUALITY_CHAR,
     SYNTAX_RIGHT_PARENTHESIS_CHAR, VALUE_NULL_LIST,
 };
-use crate::types::lists::{list_to_vec, TYPE_NAME_LIST};
+use crate::types::lists::{list_to_vec, vec_to_improper_list, TYPE_NAME_LIST};
 use crate::types::strings::ByteVector;
 use crate::types::symbols::TYPE_NAME_SYMBOL;
 use crate::types::{
@@ -48,6 +49,9 @@ pub enum Datum {
     /* Other */
     Labeled(Label, Ref<Datum>),
     LabelRef(Label),
+    /// A runtime value, such as a procedure, placed into a list or vector by evaluation; these are
+    /// never produced by the reader.
+    Object(Ref<Expression>),
     Null,
 }
 
@@ -77,6 +81,7 @@ pub fn datum_to_vec(datum: Ref<Datum>) -> Vec<Ref<Datum>> {
         | Datum::Vector(_)
         | Datum::ByteVector(_)
         | Datum::Abbreviation(_, _)
+        | Datum::Object(_)
         | Datum::Null => vec![datum],
         Datum::List(pair) => list_to_vec(pair.clone()),
         Datum::Labeled(_, _) | Datum::LabelRef(_) => unreachable!(),
@@ -195,6 +200,33 @@ impl From<Pair> for Datum {
     }
 }
 
+///
+/// Convert an evaluated value back into data, values with no datum representation are
+/// wrapped as `Datum::Object`.
+///
+impl From<Expression> for Datum {
+    fn from(v: Expression) -> Self {
+        match v {
+            Expression::Identifier(v) => Self::Symbol(v),
+            Expression::Boolean(v) => Self::Boolean(v),
+            Expression::Number(v) => Self::Number(v),
+            Expression::Vector(v) => Self::Vector(v),
+            Expression::Character(v) => Self::Character(v),
+            Expression::String(v) => Self::String(v),
+            Expression::ByteVector(v) => Self::ByteVector(v),
+            Expression::Quotation(v) => v.as_ref().clone(),
+            Expression::List(vs) => vec_to_improper_list(
+                vs.into_iter().map(Expression::into_datum).collect(),
+                Ref::new(Self::Null),
+            )
+            .as_ref()
+            .clone(),
+            Expression::Null => Self::Null,
+            v => Self::Object(Ref::new(v)),
+        }
+    }
+}
+
 impl SchemeValue for Datum {
     fn type_name(&self) -> &'static str {
         match self {
@@ -211,6 +243,7 @@ impl SchemeValue for Datum {
             Datum::LabelRef(_) => {
                 unreachable!()
             }
+            Datum::Object(v) => v.type_name(),
             Datum::Null => TYPE_NAME_LIST,
         }
     }
@@ -248,6 +281,7 @@ impl SchemeRepr for Datum {
                 v.to_repr_string()
             ),
             Self::LabelRef(l) => format!("{}{}{}", SYNTAX_HASH_CHAR, l, SYNTAX_HASH_CHAR),
+            Self::Object(v) => v.to_repr_string(),
             Datum::Null => VALUE_NULL_LIST.to_string(),
         }
     }
@@ -291,7 +325,7 @@ impl Datum {
         matches!(self, Datum::String(_))
     }
 
-    pub fn as_string(&self) -> Option<&String> {
+    pub fn as_string(&self) -> Option<&SchemeString> {
         match self {
             Self::String(v) => Some(v),
             _ => None,
@@ -361,6 +395,17 @@ impl Datum {
         matches!(self, Datum::LabelRef(_))
     }
 
+    pub fn is_object(&self) -> bool {
+        matches!(self, Datum::Object(_))
+    }
+
+    pub fn as_object(&self) -> Option<&Expression> {
+        match self {
+            Self::Object(v) => Some(v),
+            _ => None,
+        }
+    }
+
     pub fn is_null(&self) -> bool {
         matches!(self, Datum::Null)
     }
@@ -378,6 +423,7 @@ impl Datum {
             Self::Abbreviation(_, _) => TYPE_NAME_SYMBOL.to_string(),
             Self::Labeled(_, v) => v.inner_type_name(),
             Self::LabelRef(_) => unreachable!(),
+            Self::Object(v) => v.type_name().to_string(),
             Datum::Null => TYPE_NAME_LIST.to_string(),
         }
     }
@@ -37,6 +37,8 @@ pub const SYNTAX_SPACE_CHAR: char = ' ';
 pub const SYNTAX_SPACE: &str = " ";
 pub const SYNTAX_UNDERSCORE_CHAR: char = '_';
 pub const SYNTAX_UNDERSCORE: &str = "_";
+pub const SYNTAX_VERTICAL_LINE_CHAR: char = '|';
+pub const SYNTAX_VERTICAL_LINE: &str = "|";
 
 pub const SYNTAX_ABBR_QUOTE: &str = "'";
 pub const SYNTAX_ABBR_UNQUOTE_SPLICING: &str = ",@";
@@ -47,6 +49,7 @@ pub const SYNTAX_CHAR_PREFIX: &str = "#\\";
 pub const SYNTAX_COMMENT_START: &str = "#|";
 pub const SYNTAX_COMMENT_END: &str = "|#";
 pub const SYNTAX_CONS_DOT: &str = " . ";
+pub const SYNTAX_ELLIPSIS: &str = "...";
 pub const SYNTAX_DIRECTIVE_FOLD_CASE: &str = "#!fold-case";
 pub const SYNTAX_DIRECTIVE_NO_FOLD_CASE: &str = "#!no-fold-case";
 pub const SYNTAX_HEX_CHAR_PREFIX: &str = "#\\x";
@@ -75,25 +78,40 @@ pub const FORM_NAME_BEGIN: &str = "begin";
 pub const FORM_NAME_CASE: &str = "case";
 pub const FORM_NAME_COND: &str = "cond";
 pub const FORM_NAME_DEFINE: &str = "define";
+pub const FORM_NAME_DEFINE_SYNTAX: &str = "define-syntax";
+pub const FORM_NAME_DEFINE_VALUES: &str = "define-values";
 pub const FORM_NAME_DELAY: &str = "delay";
+pub const FORM_NAME_DO: &str = "do";
 pub const FORM_NAME_DELAY_FORCE: &str = "delay-force";
 pub const FORM_NAME_ELSE: &str = "else";
 pub const FORM_NAME_FORCE: &str = "force";
+pub const FORM_NAME_GUARD: &str = "guard";
 pub const FORM_NAME_IF: &str = "if";
 pub const FORM_NAME_IMPORT: &str = "import";
 pub const FORM_NAME_PROMISE: &str = "promise?";
 pub const FORM_NAME_LAMBDA: &str = "lambda";
+pub const FORM_NAME_LET: &str = "let";
+pub const FORM_NAME_LET_STAR: &str = "let*";
+pub const FORM_NAME_LET_REC: &str = "letrec";
+pub const FORM_NAME_LET_REC_STAR: &str = "letrec*";
+pub const FORM_NAME_LET_VALUES: &str = "let-values";
+pub const FORM_NAME_LET_STAR_VALUES: &str = "let*-values";
+pub const FORM_NAME_LET_SYNTAX: &str = "let-syntax";
+pub const FORM_NAME_LET_REC_SYNTAX: &str = "letrec-syntax";
 pub const FORM_NAME_LAMBDA_ALT: &str = "λ";
 pub const FORM_NAME_MAKE_PROMISE: &str = "make-promise";
 pub const FORM_NAME_OR: &str = "or";
 pub const FORM_NAME_QUASI_QUOTE: &str = "quasiquote";
 pub const FORM_NAME_QUOTE: &str = "quote";
 pub const FORM_NAME_SET: &str = "set!";
+pub const FORM_NAME_SYNTAX_ERROR: &str = "syntax-error";
+pub const FORM_NAME_SYNTAX_RULES: &str = "syntax-rules";
 pub const FORM_NAME_UNQUOTE: &str = "unquote";
 pub const FORM_NAME_UNQUOTE_SPLICING: &str = "unquote-splicing";
 pub const FORM_NAME_UNLESS: &str = "unless";
 pub const FORM_NAME_WHEN: &str = "when";
 
+pub const FORM_PART_ARROW: &str = "=>";
 pub const FORM_PART_ONLY: &str = "only";
 pub const FORM_PART_EXCEPT: &str = "except";
 pub const FORM_PART_PREFIX: &str = "prefix";
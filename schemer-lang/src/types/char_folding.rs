@@ -1,20 +1,28 @@
 /*!
-One-line description.
+Unicode case folding, from the Unicode Character Database `CaseFolding.txt`.
 
-More detailed description, with
+Simple folding maps a character to a single character, as used by `char-foldcase`, while full
+folding may map a character to more than one, as used by `string-foldcase` and by the reader
+after a `#!fold-case` directive. The Turkic mappings are not used.
 
 # Example
 
+```rust
+use schemer_lang::types::char_folding::{fold_char, fold_string};
+
+assert_eq!(fold_char('A'), 'a');
+assert_eq!(fold_char('ß'), 'ß');
+assert_eq!(fold_string("Straße"), "strasse");
+```
+
 */
 
-// use ...
+use std::collections::HashMap;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-use std::collections::HashMap;
-
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Mapping {
     Common,
@@ -39,6 +47,37 @@ lazy_static! {
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
+///
+/// The simple case folding of `c`, or `c` itself if it has none.
+///
+pub fn fold_char(c: char) -> char {
+    match CASE_FOLDING_MAP.get(&c) {
+        Some(map) => map
+            .get(&Mapping::Common)
+            .or_else(|| map.get(&Mapping::Simple))
+            .map(|to| to[0])
+            .unwrap_or(c),
+        None => c,
+    }
+}
+
+///
+/// The full case folding of `s`, where a single character may fold to more than one.
+///
+pub fn fold_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match CASE_FOLDING_MAP.get(&c).and_then(|map| {
+            map.get(&Mapping::Common)
+                .or_else(|| map.get(&Mapping::Full))
+        }) {
+            Some(to) => result.extend(to),
+            None => result.push(c),
+        }
+    }
+    result
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
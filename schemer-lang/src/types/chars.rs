@@ -49,12 +49,12 @@ pub const CHAR_NAME_TAB: &str = "tab";
 
 impl SchemeRepr for Char {
     fn to_repr_string(&self) -> String {
-        if self.is_ascii() && !self.is_ascii_control() {
+        if let Some(name) = self.to_scheme_name() {
+            name
+        } else if self.is_ascii() && !self.is_ascii_control() {
             format!("{}{}", SYNTAX_CHAR_PREFIX, **self)
         } else if self.is_alphanumeric() {
             format!("{}{}", SYNTAX_CHAR_PREFIX, **self)
-        } else if let Some(name) = self.to_scheme_name() {
-            name
         } else {
             format!(
                 "{}{:X}",
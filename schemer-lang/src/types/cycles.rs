@@ -0,0 +1,161 @@
+/*!
+Keeps the representation of circular pairs and vectors finite.
+
+While a pair, or vector, is being represented it is recorded as in progress; if it is reached
+again before it is complete the data are circular, and so the second occurrence is represented
+as a datum label reference `#n#` and the first occurrence is prefixed with the label `#n=`.
+Labels are numbered in the order in which the cycles are found, starting at `0` for each
+outermost representation.
+
+# Example
+
+```rust
+use schemer_lang::read::datum::Datum;
+use schemer_lang::types::{Pair, Ref, SchemeRepr};
+
+let list = Ref::new(Datum::List(Pair::cons(
+    Ref::new(Datum::Symbol("a".parse().unwrap())),
+    Ref::new(Datum::Null),
+)));
+if let Datum::List(pair) = &*list {
+    pair.set_cdr(list.clone());
+}
+assert_eq!(list.to_repr_string(), "#0=(a . #0#)");
+```
+
+ */
+
+use crate::read::datum::Label;
+use crate::read::syntax_str::{
+    SYNTAX_HASH_CHAR, SYNTAX_LEFT_PARENTHESIS_CHAR, SYNTAX_MATH_EQUALITY_CHAR,
+    SYNTAX_RIGHT_PARENTHESIS_CHAR,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The identity of a pair, or vector, while it is being represented.
+///
+type Key = *const ();
+
+///
+/// The pairs and vectors currently being represented, each with the label assigned to it if it
+/// has been reached again.
+///
+#[derive(Debug, Default)]
+struct InProgress {
+    labels: HashMap<Key, Option<Label>>,
+    next: Label,
+}
+
+enum Visit {
+    Cycle(Label),
+    Done(String, Option<Label>),
+}
+
+thread_local! {
+    static IN_PROGRESS: RefCell<InProgress> = RefCell::new(InProgress::default());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Represent the pair, or vector, identified by `key` with `repr`, unless it is already being
+/// represented, in which case a label reference is returned instead.
+///
+pub(crate) fn repr_compound<F>(key: Key, repr: F) -> String
+where
+    F: FnOnce() -> String,
+{
+    match visit(key, repr) {
+        Visit::Cycle(label) => label_ref(label),
+        Visit::Done(repr, None) => repr,
+        Visit::Done(repr, Some(label)) => format!("{}{}", label_def(label), repr),
+    }
+}
+
+///
+/// Represent the pair identified by `key`, which is the `cdr` of another pair, with `repr` as
+/// the remaining elements of a list. If the pair has to be labeled it cannot be written as part
+/// of the enclosing list and so `Err` is returned with the labeled, parenthesized, pair that
+/// follows a dot.
+///
+pub(crate) fn repr_list_tail<F>(key: Key, repr: F) -> Result<String, String>
+where
+    F: FnOnce() -> String,
+{
+    match visit(key, repr) {
+        Visit::Cycle(label) => Err(label_ref(label)),
+        Visit::Done(repr, None) => Ok(repr),
+        Visit::Done(repr, Some(label)) => Err(format!(
+            "{}{}{}{}",
+            label_def(label),
+            SYNTAX_LEFT_PARENTHESIS_CHAR,
+            repr,
+            SYNTAX_RIGHT_PARENTHESIS_CHAR
+        )),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn visit<F>(key: Key, repr: F) -> Visit
+where
+    F: FnOnce() -> String,
+{
+    let cycle = IN_PROGRESS.with(|in_progress| {
+        let InProgress { labels, next } = &mut *in_progress.borrow_mut();
+        match labels.get_mut(&key) {
+            Some(label) => Some(*label.get_or_insert_with(|| {
+                let label = *next;
+                *next += 1;
+                label
+            })),
+            None => {
+                let _ = labels.insert(key, None);
+                None
+            }
+        }
+    });
+    if let Some(label) = cycle {
+        return Visit::Cycle(label);
+    }
+    let repr = repr();
+    let label = IN_PROGRESS.with(|in_progress| {
+        let InProgress { labels, next } = &mut *in_progress.borrow_mut();
+        let label = labels.remove(&key).flatten();
+        if labels.is_empty() {
+            *next = 0;
+        }
+        label
+    });
+    Visit::Done(repr, label)
+}
+
+fn label_def(label: Label) -> String {
+    format!("{}{}{}", SYNTAX_HASH_CHAR, label, SYNTAX_MATH_EQUALITY_CHAR)
+}
+
+fn label_ref(label: Label) -> String {
+    format!("{}{}{}", SYNTAX_HASH_CHAR, label, SYNTAX_HASH_CHAR)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
@@ -9,31 +9,40 @@ More detailed description, with
 
 use crate::read::datum::Datum;
 use crate::read::syntax_str::{
-    EMPTY_STR, SYNTAX_CONS_DOT, SYNTAX_LEFT_PARENTHESIS, SYNTAX_RIGHT_PARENTHESIS, SYNTAX_SPACE,
-    VALUE_NULL_LIST,
+    SYNTAX_CONS_DOT, SYNTAX_LEFT_PARENTHESIS, SYNTAX_RIGHT_PARENTHESIS, SYNTAX_SPACE,
 };
+use crate::types::cycles::{repr_compound, repr_list_tail};
 use crate::types::{Ref, SchemeRepr, SchemeValue, Vector};
+use std::cell::RefCell;
 use std::iter::FromIterator;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
+///
+/// A pair, or cons cell. The `car` and `cdr` are mutable through a shared reference so that a
+/// mutation made with `set_car` or `set_cdr` is visible through every `Ref<Datum>` that shares
+/// this pair. Cloning a `Pair` however creates a new cell with the same `car` and `cdr`.
+///
 #[derive(Clone, Debug, PartialEq)]
 pub struct Pair {
-    car: Ref<Datum>,
-    cdr: Ref<Datum>,
+    car: RefCell<Ref<Datum>>,
+    cdr: RefCell<Ref<Datum>>,
 }
 
+///
+/// Iterates over the pairs of a list, each is a clone of the pair in the list and so mutation
+/// must be made through the `Ref<Datum>` returned by `cdr` instead.
+///
 #[derive(Clone, Debug, PartialEq)]
-pub struct PairIterator<'a> {
-    current: Option<&'a Pair>,
+pub struct PairIterator {
+    current: Option<Pair>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct ListIterator<'a> {
-    current: Option<&'a Pair>,
-    take_cdr: bool,
+pub struct ListIterator {
+    current: Option<Pair>,
 }
 
 pub const TYPE_NAME_LIST: &str = "list";
@@ -44,8 +53,9 @@ pub const TYPE_NAME_PAIR: &str = "pair";
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
-lazy_static! {
-    static ref SHARED_NULL: Ref<Datum> = Ref::new(Datum::Null);
+thread_local! {
+    // Not a `lazy_static` as `Ref` is not `Sync`.
+    static SHARED_NULL: Ref<Datum> = Ref::new(Datum::Null);
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -79,9 +89,19 @@ pub fn make_filled_list(k: usize, fill: &Datum) -> Pair {
 //     head
 // }
 
+///
+/// Construct a list from `data` where the final `cdr` is `tail`; if `tail` is `Datum::Null` the
+/// result is a proper list, and if `data` is empty the result is simply `tail`.
+///
+pub fn vec_to_improper_list(data: Vec<Ref<Datum>>, tail: Ref<Datum>) -> Ref<Datum> {
+    data.into_iter()
+        .rev()
+        .fold(tail, |cdr, car| Ref::new(Datum::List(Pair::cons(car, cdr))))
+}
+
 pub fn vector_to_list(data: Vector<Datum>) -> Pair {
     let mut head = Pair::empty();
-    for datum in data.iter().rev().cloned() {
+    for datum in data.as_vec().iter().rev().cloned() {
         if head.is_null() {
             head = Pair::cons_nil(datum);
         } else {
@@ -96,7 +116,7 @@ pub fn vec_to_list(data: Vec<Datum>) -> Pair {
 }
 
 pub fn list_to_vec(list: Pair) -> Vec<Ref<Datum>> {
-    let result: Vec<Ref<Datum>> = Vec::from_iter(list.into_iter().cloned());
+    let result: Vec<Ref<Datum>> = Vec::from_iter(list.iter());
     result
 }
 
@@ -110,7 +130,14 @@ pub fn list_to_vector(list: Pair) -> Vector<Datum> {
 
 impl SchemeRepr for Pair {
     fn to_repr_string(&self) -> String {
-        self.repr_string(true)
+        repr_compound(self.key(), || {
+            format!(
+                "{}{}{}",
+                SYNTAX_LEFT_PARENTHESIS,
+                self.repr_elements(),
+                SYNTAX_RIGHT_PARENTHESIS
+            )
+        })
     }
 }
 
@@ -127,6 +154,19 @@ impl SchemeValue for Pair {
     }
 }
 
+impl Drop for Pair {
+    ///
+    /// Unlink the `cdr` chain in a loop, while each pair is owned only by the one before it, so
+    /// that dropping a long list does not recurse once for each element.
+    ///
+    fn drop(&mut self) {
+        let mut next = self.cdr.replace(shared_null());
+        while let Ok(Datum::List(pair)) = Ref::try_unwrap(next) {
+            next = pair.cdr.replace(shared_null());
+        }
+    }
+}
+
 impl FromIterator<Datum> for Pair {
     #[allow(unused_assignments)]
     fn from_iter<I: IntoIterator<Item = Datum>>(iter: I) -> Self {
@@ -134,15 +174,12 @@ impl FromIterator<Datum> for Pair {
     }
 }
 
-impl<'a> IntoIterator for &'a Pair {
-    type Item = &'a Ref<Datum>;
-    type IntoIter = ListIterator<'a>;
+impl IntoIterator for &Pair {
+    type Item = Ref<Datum>;
+    type IntoIter = ListIterator;
 
     fn into_iter(self) -> Self::IntoIter {
-        ListIterator {
-            current: Some(self),
-            take_cdr: false,
-        }
+        self.iter()
     }
 }
 
@@ -160,11 +197,14 @@ impl From<(Datum, Datum)> for Pair {
 
 impl Pair {
     pub fn empty() -> Self {
-        Self::cons(SHARED_NULL.clone(), SHARED_NULL.clone())
+        Self::cons(shared_null(), shared_null())
     }
 
     pub fn cons(car: Ref<Datum>, cdr: Ref<Datum>) -> Self {
-        Self { car, cdr }
+        Self {
+            car: RefCell::new(car),
+            cdr: RefCell::new(cdr),
+        }
     }
 
     pub fn cons_list(car: Ref<Datum>, cdr: Pair) -> Self {
@@ -172,100 +212,114 @@ impl Pair {
     }
 
     pub fn cons_nil(car: Ref<Datum>) -> Self {
-        Self::cons(car, SHARED_NULL.clone())
+        Self::cons(car, shared_null())
     }
 
-    pub fn car(&self) -> &Ref<Datum> {
-        &self.car
+    pub fn car(&self) -> Ref<Datum> {
+        self.car.borrow().clone()
     }
 
-    pub fn set_car(&mut self, datum: Ref<Datum>) {
-        self.car = datum;
+    ///
+    /// Replace the `car` of this pair, the change is visible through any shared reference to it.
+    ///
+    pub fn set_car(&self, datum: Ref<Datum>) {
+        let _ = self.car.replace(datum);
     }
 
-    pub fn cdr(&self) -> &Ref<Datum> {
-        &self.cdr
+    pub fn cdr(&self) -> Ref<Datum> {
+        self.cdr.borrow().clone()
     }
 
-    pub fn set_cdr(&mut self, datum: Ref<Datum>) {
-        self.cdr = datum;
+    ///
+    /// Replace the `cdr` of this pair, the change is visible through any shared reference to it.
+    ///
+    pub fn set_cdr(&self, datum: Ref<Datum>) {
+        let _ = self.cdr.replace(datum);
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = &Ref<Datum>> {
+    pub fn iter(&self) -> ListIterator {
         ListIterator {
-            current: Some(self),
-            take_cdr: false,
+            current: Some(self.clone()),
         }
     }
 
-    pub fn pairs(&self) -> impl Iterator<Item = &Pair> {
+    pub fn pairs(&self) -> PairIterator {
         PairIterator {
-            current: Some(self),
+            current: Some(self.clone()),
         }
     }
 
     pub fn is_null(&self) -> bool {
-        self.car.is_null() && self.cdr.is_null()
+        self.car().is_null() && self.cdr().is_null()
     }
 
     pub fn is_proper_pair(&self) -> bool {
-        self.cdr.is_pair() || self.cdr.is_null()
+        let cdr = self.cdr();
+        cdr.is_pair() || cdr.is_null()
     }
 
+    ///
+    /// Whether the list that starts with this pair ends in the empty list; a circular list is
+    /// detected by following the `cdr` at two speeds and so is not a proper list.
+    ///
     pub fn is_proper_list(&self) -> bool {
-        match &*self.cdr {
-            Datum::List(list) => list.is_proper_list(),
-            Datum::Null => true,
-            _ => false,
+        let mut slow = self.cdr();
+        let mut fast = self.cdr();
+        loop {
+            for _ in 0..2 {
+                let next = match &*fast {
+                    Datum::Null => return true,
+                    Datum::List(pair) => pair.cdr(),
+                    _ => return false,
+                };
+                fast = next;
+            }
+            let next = match &*slow {
+                Datum::List(pair) => pair.cdr(),
+                _ => unreachable!(),
+            };
+            slow = next;
+            if Ref::ptr_eq(&slow, &fast) {
+                return false;
+            }
         }
     }
 
     pub fn length(&self) -> usize {
         if self.is_null() {
             0
-        } else if let Datum::List(pair) = &*self.cdr {
-            1 + pair.length()
         } else {
-            1
+            self.pairs().count()
         }
     }
 
-    pub fn append(&mut self, rhs: Pair) {
-        let last = self.last_mut();
-        last.cdr = Ref::new(Datum::List(rhs));
+    ///
+    /// Append `rhs` to this list by replacing the `cdr` of the final pair.
+    ///
+    pub fn append(&self, rhs: Pair) {
+        match &*self.cdr() {
+            Datum::List(next) => next.append(rhs),
+            _ => self.set_cdr(Ref::new(Datum::List(rhs))),
+        }
     }
 
-    // pub fn reverse(list: List) -> List {}
-
-    pub fn head(&self) -> &Datum {
+    pub fn head(&self) -> Ref<Datum> {
         self.car()
     }
 
-    pub fn tail(&self) -> Option<&Pair> {
-        if let Datum::List(pair) = &*self.cdr {
-            Some(pair)
+    pub fn tail(&self) -> Option<Pair> {
+        if let Datum::List(pair) = &*self.cdr() {
+            Some(pair.clone())
         } else {
             None
         }
     }
 
-    pub fn last(&self) -> &Pair {
-        if let Datum::List(next) = &*self.cdr {
+    pub fn last(&self) -> Pair {
+        if let Datum::List(next) = &*self.cdr() {
             next.last()
         } else {
-            self
-        }
-    }
-
-    pub fn last_mut(&mut self) -> &mut Pair {
-        if !self.cdr.is_list() {
-            self
-        } else {
-            if let Some(Datum::List(pair)) = Ref::get_mut(&mut self.cdr) {
-                Self::last_mut(pair)
-            } else {
-                unreachable!()
-            }
+            self.clone()
         }
     }
 
@@ -275,78 +329,51 @@ impl Pair {
     //
     // pub fn list_set(list: &List, k: usize, datum: Datum) -> Option<&Datum> {}
 
-    fn repr_string(&self, bounded: bool) -> String {
-        let proper = self.is_proper_pair();
+    fn key(&self) -> *const () {
+        self as *const Self as *const ()
+    }
+
+    ///
+    /// Represent the elements of the list that starts with this pair, without the enclosing
+    /// parentheses.
+    ///
+    fn repr_elements(&self) -> String {
         // TODO: use global CONS write flag
-        format!(
-            "{}{}{}{}{}",
-            if bounded {
-                SYNTAX_LEFT_PARENTHESIS
-            } else {
-                EMPTY_STR
-            },
-            self.car().to_repr_string(),
-            if proper && self.cdr.is_null() {
-                EMPTY_STR
-            } else if proper {
-                SYNTAX_SPACE
-            } else {
-                SYNTAX_CONS_DOT
-            },
-            match &*self.cdr {
-                Datum::List(p) => p.repr_string(!proper),
-                Datum::Null => if proper { EMPTY_STR } else { VALUE_NULL_LIST }.to_string(),
-                cdr => cdr.to_repr_string(),
+        let car = self.car().to_repr_string();
+        match &*self.cdr() {
+            Datum::Null => car,
+            Datum::List(next) => match repr_list_tail(next.key(), || next.repr_elements()) {
+                Ok(elements) => format!("{}{}{}", car, SYNTAX_SPACE, elements),
+                Err(labeled) => format!("{}{}{}", car, SYNTAX_CONS_DOT, labeled),
             },
-            if bounded {
-                SYNTAX_RIGHT_PARENTHESIS
-            } else {
-                EMPTY_STR
-            },
-        )
+            cdr => format!("{}{}{}", car, SYNTAX_CONS_DOT, cdr.to_repr_string()),
+        }
     }
 }
 
 // ------------------------------------------------------------------------------------------------
 
-impl<'a> Iterator for PairIterator<'a> {
-    type Item = &'a Pair;
+impl Iterator for PairIterator {
+    type Item = Pair;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.current {
-            None => None,
-            Some(pair) => {
-                if let Datum::List(next) = &**pair.cdr() {
-                    self.current = Some(next);
-                } else {
-                    self.current = None;
-                }
-                Some(pair)
-            }
+        let pair = self.current.take()?;
+        if let Datum::List(next) = &*pair.cdr() {
+            self.current = Some(next.clone());
         }
+        Some(pair)
     }
 }
 
-impl<'a> Iterator for ListIterator<'a> {
-    type Item = &'a Ref<Datum>;
+impl Iterator for ListIterator {
+    type Item = Ref<Datum>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.current {
-            None => None,
-            Some(pair) => {
-                if let Datum::List(next) = &**pair.cdr() {
-                    self.current = Some(next);
-                } else {
-                    self.current = None;
-                }
-                if self.take_cdr {
-                    self.current = None;
-                    Some(&pair.cdr)
-                } else {
-                    Some(&pair.car)
-                }
-            }
+        let pair = self.current.take()?;
+        if let Datum::List(next) = &*pair.cdr() {
+            self.current = Some(next.clone());
         }
+        Some(pair.car())
     }
 }
 
@@ -354,6 +381,10 @@ impl<'a> Iterator for ListIterator<'a> {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+fn shared_null() -> Ref<Datum> {
+    SHARED_NULL.with(|null| null.clone())
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -9,7 +9,7 @@ More detailed description, with
 
 use std::cell::RefCell;
 use std::fmt::Debug;
-use std::sync::Arc;
+use std::rc::Rc;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -23,9 +23,9 @@ pub trait SchemeValue: Debug + SchemeRepr {
     fn type_name(&self) -> &'static str;
 }
 
-pub type Ref<T> = Arc<T>;
+pub type Ref<T> = Rc<T>;
 
-pub type MutableRef<T> = Arc<RefCell<T>>;
+pub type MutableRef<T> = Rc<RefCell<T>>;
 
 // ------------------------------------------------------------------------------------------------
 // Private Macros
@@ -70,6 +70,10 @@ pub use booleans::Boolean;
 pub mod chars;
 pub use chars::Char;
 
+pub mod char_folding;
+
+mod cycles;
+
 pub mod lists;
 pub use lists::Pair;
 
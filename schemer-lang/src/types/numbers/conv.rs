@@ -13,7 +13,7 @@ use crate::types::numbers::{
     TYPE_NAME_EXACT_COMPLEX, TYPE_NAME_EXACT_REAL, TYPE_NAME_INEXACT_COMPLEX,
     TYPE_NAME_INEXACT_REAL, TYPE_NAME_INTEGER, TYPE_NAME_RATIONAL,
 };
-use num::traits::{FromPrimitive, One, ToPrimitive};
+use num::traits::{FromPrimitive, One, Pow, ToPrimitive, Zero};
 use std::convert::TryFrom;
 
 // ------------------------------------------------------------------------------------------------
@@ -30,7 +30,10 @@ use std::convert::TryFrom;
 
 pub fn inexact_complex_to_exact_complex(from: InexactComplex) -> Result<ExactComplex, Error> {
     let type_error = make_type_cast_error(TYPE_NAME_INEXACT_COMPLEX, TYPE_NAME_EXACT_COMPLEX);
-    ExactComplex::from_f64(from.to_f64().ok_or_else(&type_error)?).ok_or_else(&type_error)
+    Ok(ExactComplex::new(
+        ExactReal::from_f64(from.re).ok_or_else(&type_error)?,
+        ExactReal::from_f64(from.im).ok_or_else(&type_error)?,
+    ))
 }
 pub fn inexact_complex_to_inexact_real(from: InexactComplex) -> Result<InexactReal, Error> {
     let type_error = make_type_cast_error(TYPE_NAME_INEXACT_COMPLEX, TYPE_NAME_INEXACT_REAL);
@@ -42,17 +45,20 @@ pub fn inexact_complex_to_exact_real(from: InexactComplex) -> Result<ExactReal,
 }
 pub fn inexact_complex_to_rational(from: InexactComplex) -> Result<Rational, Error> {
     let type_error = make_type_cast_error(TYPE_NAME_INEXACT_COMPLEX, TYPE_NAME_RATIONAL);
-    Rational::from_f64(from.to_f64().ok_or_else(&type_error)?).ok_or_else(&type_error)
+    Rational::from_float(from.to_f64().ok_or_else(&type_error)?).ok_or_else(&type_error)
 }
 
 pub fn inexact_complex_to_integer(from: InexactComplex) -> Result<Integer, Error> {
     let type_error = make_type_cast_error(TYPE_NAME_INEXACT_COMPLEX, TYPE_NAME_INTEGER);
-    from.to_i64().ok_or_else(&type_error)
+    Integer::from_f64(from.to_f64().ok_or_else(&type_error)?).ok_or_else(&type_error)
 }
 
 pub fn exact_complex_to_inexact_complex(from: ExactComplex) -> Result<InexactComplex, Error> {
     let type_error = make_type_cast_error(TYPE_NAME_EXACT_COMPLEX, TYPE_NAME_INEXACT_COMPLEX);
-    InexactComplex::from_f64(from.to_f64().ok_or_else(&type_error)?).ok_or_else(&type_error)
+    Ok(InexactComplex::new(
+        from.re.to_f64().ok_or_else(&type_error)?,
+        from.im.to_f64().ok_or_else(&type_error)?,
+    ))
 }
 
 pub fn exact_complex_to_inexact_real(from: ExactComplex) -> Result<InexactReal, Error> {
@@ -61,18 +67,36 @@ pub fn exact_complex_to_inexact_real(from: ExactComplex) -> Result<InexactReal,
 }
 
 pub fn exact_complex_to_exact_real(from: ExactComplex) -> Result<ExactReal, Error> {
-    let type_error = make_type_cast_error(TYPE_NAME_EXACT_COMPLEX, TYPE_NAME_EXACT_REAL);
-    ExactReal::from_f64(from.to_f64().ok_or_else(&type_error)?).ok_or_else(&type_error)
+    if from.im.is_zero() {
+        Ok(from.re)
+    } else {
+        Err(make_type_cast_error(
+            TYPE_NAME_EXACT_COMPLEX,
+            TYPE_NAME_EXACT_REAL,
+        )())
+    }
 }
 
 pub fn exact_complex_to_rational(from: ExactComplex) -> Result<Rational, Error> {
-    let type_error = make_type_cast_error(TYPE_NAME_EXACT_COMPLEX, TYPE_NAME_RATIONAL);
-    Rational::from_f64(from.to_f64().ok_or_else(&type_error)?).ok_or_else(&type_error)
+    if from.im.is_zero() {
+        exact_real_to_rational(from.re)
+    } else {
+        Err(make_type_cast_error(
+            TYPE_NAME_EXACT_COMPLEX,
+            TYPE_NAME_RATIONAL,
+        )())
+    }
 }
 
 pub fn exact_complex_to_integer(from: ExactComplex) -> Result<Integer, Error> {
-    let type_error = make_type_cast_error(TYPE_NAME_EXACT_COMPLEX, TYPE_NAME_INTEGER);
-    from.to_i64().ok_or_else(&type_error)
+    if from.im.is_zero() {
+        exact_real_to_integer(from.re)
+    } else {
+        Err(make_type_cast_error(
+            TYPE_NAME_EXACT_COMPLEX,
+            TYPE_NAME_INTEGER,
+        )())
+    }
 }
 
 pub fn inexact_real_to_inexact_complex(from: InexactReal) -> Result<InexactComplex, Error> {
@@ -92,12 +116,12 @@ pub fn inexact_real_to_exact_real(from: InexactReal) -> Result<ExactReal, Error>
 
 pub fn inexact_real_to_rational(from: InexactReal) -> Result<Rational, Error> {
     let type_error = make_type_cast_error(TYPE_NAME_INEXACT_REAL, TYPE_NAME_RATIONAL);
-    Rational::from_f64(from.to_f64().ok_or_else(&type_error)?).ok_or_else(&type_error)
+    Rational::from_float(from).ok_or_else(&type_error)
 }
 
 pub fn inexact_real_to_integer(from: InexactReal) -> Result<Integer, Error> {
     let type_error = make_type_cast_error(TYPE_NAME_INEXACT_REAL, TYPE_NAME_INTEGER);
-    from.to_i64().ok_or_else(&type_error)
+    Integer::from_f64(from).ok_or_else(&type_error)
 }
 
 pub fn exact_real_to_inexact_complex(from: ExactReal) -> Result<InexactComplex, Error> {
@@ -106,8 +130,7 @@ pub fn exact_real_to_inexact_complex(from: ExactReal) -> Result<InexactComplex,
 }
 
 pub fn exact_real_to_exact_complex(from: ExactReal) -> Result<ExactComplex, Error> {
-    let type_error = make_type_cast_error(TYPE_NAME_EXACT_REAL, TYPE_NAME_EXACT_COMPLEX);
-    ExactComplex::from_f64(from.to_f64().ok_or_else(&type_error)?).ok_or_else(&type_error)
+    Ok(ExactComplex::new(from, ExactReal::zero()))
 }
 
 pub fn exact_real_to_inexact_real(from: ExactReal) -> Result<InexactReal, Error> {
@@ -116,13 +139,16 @@ pub fn exact_real_to_inexact_real(from: ExactReal) -> Result<InexactReal, Error>
 }
 
 pub fn exact_real_to_rational(from: ExactReal) -> Result<Rational, Error> {
-    let type_error = make_type_cast_error(TYPE_NAME_EXACT_REAL, TYPE_NAME_RATIONAL);
-    Rational::from_f64(from.to_f64().ok_or_else(&type_error)?).ok_or_else(&type_error)
+    let from = from.normalize();
+    Ok(Rational::new(
+        Integer::from(from.mantissa()),
+        Integer::from(10).pow(from.scale()),
+    ))
 }
 
 pub fn exact_real_to_integer(from: ExactReal) -> Result<Integer, Error> {
     let type_error = make_type_cast_error(TYPE_NAME_EXACT_REAL, TYPE_NAME_INTEGER);
-    from.to_i64().ok_or_else(&type_error)
+    from.to_i128().map(Integer::from).ok_or_else(&type_error)
 }
 
 pub fn rational_to_inexact_complex(from: Rational) -> Result<InexactComplex, Error> {
@@ -131,8 +157,7 @@ pub fn rational_to_inexact_complex(from: Rational) -> Result<InexactComplex, Err
 }
 
 pub fn rational_to_exact_complex(from: Rational) -> Result<ExactComplex, Error> {
-    let type_error = make_type_cast_error(TYPE_NAME_RATIONAL, TYPE_NAME_EXACT_COMPLEX);
-    ExactComplex::from_f64(from.to_f64().ok_or_else(&type_error)?).ok_or_else(&type_error)
+    exact_real_to_exact_complex(rational_to_exact_real(from)?)
 }
 
 pub fn rational_to_inexact_real(from: Rational) -> Result<InexactReal, Error> {
@@ -142,32 +167,33 @@ pub fn rational_to_inexact_real(from: Rational) -> Result<InexactReal, Error> {
 
 pub fn rational_to_exact_real(from: Rational) -> Result<ExactReal, Error> {
     let type_error = make_type_cast_error(TYPE_NAME_RATIONAL, TYPE_NAME_EXACT_REAL);
-    ExactReal::from_f64(from.to_f64().ok_or_else(&type_error)?).ok_or_else(&type_error)
+    integer_to_exact_real(from.numer().clone())?
+        .checked_div(integer_to_exact_real(from.denom().clone())?)
+        .ok_or_else(&type_error)
 }
 
 pub fn rational_to_integer(from: Rational) -> Result<Integer, Error> {
-    let type_error = make_type_cast_error(TYPE_NAME_RATIONAL, TYPE_NAME_INTEGER);
-    from.to_i64().ok_or_else(&type_error)
+    Ok(from.to_integer())
 }
 
 pub fn integer_to_inexact_complex(from: Integer) -> Result<InexactComplex, Error> {
-    let type_error = make_type_cast_error(TYPE_NAME_INTEGER, TYPE_NAME_INEXACT_COMPLEX);
-    InexactComplex::from_i64(from).ok_or_else(&type_error)
+    integer_to_inexact_real(from).map(InexactComplex::from)
 }
 
 pub fn integer_to_exact_complex(from: Integer) -> Result<ExactComplex, Error> {
-    let type_error = make_type_cast_error(TYPE_NAME_INTEGER, TYPE_NAME_EXACT_COMPLEX);
-    ExactComplex::from_i64(from).ok_or_else(&type_error)
+    integer_to_exact_real(from).map(ExactComplex::from)
 }
 
 pub fn integer_to_inexact_real(from: Integer) -> Result<InexactReal, Error> {
     let type_error = make_type_cast_error(TYPE_NAME_INTEGER, TYPE_NAME_INEXACT_REAL);
-    InexactReal::from_i64(from).ok_or_else(&type_error)
+    from.to_f64().ok_or_else(&type_error)
 }
 
 pub fn integer_to_exact_real(from: Integer) -> Result<ExactReal, Error> {
-    let type_error = make_type_cast_error(TYPE_NAME_INTEGER, TYPE_NAME_EXACT_REAL);
-    ExactReal::from_i64(from).ok_or_else(&type_error)
+    let truncation_error = make_truncation_error(TYPE_NAME_INTEGER, TYPE_NAME_EXACT_REAL);
+    from.to_i128()
+        .and_then(ExactReal::from_i128)
+        .ok_or_else(&truncation_error)
 }
 
 pub fn integer_to_rational(from: Integer) -> Result<Rational, Error> {
@@ -185,10 +211,18 @@ pub fn exact_to_inexact(from: Number) -> Result<Number, Error> {
     }
 }
 
-pub fn inexact_to_inexact(from: Number) -> Result<Number, Error> {
+///
+/// Convert an inexact number to the corresponding exact number, an inexact real with no
+/// fractional part becomes an integer if it is in range.
+///
+pub fn inexact_to_exact(from: Number) -> Result<Number, Error> {
     match from {
         Number::InexactComplex(v) => inexact_complex_to_exact_complex(v).map(|v| v.into()),
         Number::ExactComplex(_) => Ok(from),
+        Number::InexactReal(v) if v.fract().is_zero() => match inexact_real_to_integer(v) {
+            Ok(v) => Ok(v.into()),
+            Err(_) => inexact_real_to_exact_real(v).map(|v| v.into()),
+        },
         Number::InexactReal(v) => inexact_real_to_exact_real(v).map(|v| v.into()),
         Number::ExactReal(_) => Ok(from),
         Number::Rational(_) => Ok(from),
@@ -388,6 +422,16 @@ impl TryFrom<Number> for ExactComplex {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+#[inline]
+fn make_truncation_error(from: &'static str, to: &'static str) -> impl Fn() -> Error {
+    move || {
+        Error::from(ErrorKind::NumericTruncation {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    }
+}
+
 #[inline]
 fn make_type_cast_error(from: &'static str, to: &'static str) -> impl Fn() -> Error {
     move || {
@@ -1,15 +1,15 @@
 /*!
-One-line description.
+The numeric tower, from R7RS §6.2.
 
-More detailed description, with
+Exact integers are arbitrary-precision, so that integer arithmetic never overflows, and rationals
+are built on the same integer type. Exact reals are fixed-point decimals and inexact reals are
+IEEE-754 double-precision floating-point values.
 
-# Example
-
-| Base           | Alias | Not Big    | Big        |
-|----------------|-------|------------|------------|
-| Integer        | Int   | i64        | BigInt     |
-| Fixed-Point    | FxP   | Decimal    | BigDecimal |
-| Floating-Point | FlP   | f64        | f64        |
+| Base           | Alias | Type    |
+|----------------|-------|---------|
+| Integer        | Int   | BigInt  |
+| Fixed-Point    | FxP   | Decimal |
+| Floating-Point | FlP   | f64     |
 
 
 | Tower    | Exact        | Inexact       |
@@ -22,14 +22,16 @@ More detailed description, with
 */
 
 use crate::read::syntax_str::{
-    EMPTY_STR, SYNTAX_MATH_COMPLEX_CHAR, SYNTAX_MATH_MINUS, SYNTAX_MATH_MINUS_CHAR,
-    SYNTAX_MATH_PLUS, SYNTAX_MATH_PLUS_CHAR,
+    EMPTY_STR, SYNTAX_MATH_COMPLEX_CHAR, SYNTAX_MATH_MINUS, SYNTAX_MATH_PLUS,
 };
 use crate::types::{MutableRef, SchemeRepr, SchemeValue};
+use num::bigint::BigInt;
 use num::complex::Complex;
 use num::rational::Ratio;
-use num::traits::{Num, Signed, ToPrimitive, Zero};
-use rust_decimal::Decimal;
+use num::traits::{Num, One, Signed, ToPrimitive, Zero};
+use num::Integer as _;
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::cmp::Ordering;
 use std::fmt::Display;
 use std::str::FromStr;
 
@@ -37,19 +39,7 @@ use std::str::FromStr;
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-#[cfg(feature = "big-num-x")]
-mod bignum {
-    use bigdecimal::BigDecimal;
-    use bigdecimal::BigInt;
-
-    pub type Integer = BigInt;
-
-    pub type Rational = Ratio<Integer>;
-
-    pub type ExactReal = BigDecimal;
-}
-
-pub type Integer = i64;
+pub type Integer = BigInt;
 
 pub type Rational = Ratio<Integer>;
 
@@ -174,6 +164,9 @@ impl SchemeRepr for InexactReal {
             InfNan::PositiveNan.to_string()
         } else if self.is_nan() && self.is_sign_negative() {
             InfNan::NegativeNan.to_string()
+        } else if self.fract().is_zero() {
+            // Ensure an inexact integer does not read back as an exact one.
+            format!("{}.0", self)
         } else {
             self.to_string()
         }
@@ -199,26 +192,21 @@ impl SchemeNum for ExactComplex {}
 impl SchemeRepr for ExactComplex {
     fn to_repr_string(&self) -> String {
         format!(
-            "{}{}{}{}{}",
-            if self.re.is_sign_negative() {
-                SYNTAX_MATH_MINUS
-            } else {
-                EMPTY_STR
-            },
-            if self.re.is_sign_negative() || !self.re.is_zero() {
+            "{}{}{}{}",
+            if !self.re.is_zero() {
                 self.re.to_repr_string()
             } else {
                 String::new()
             },
             if self.im.is_sign_negative() {
-                SYNTAX_MATH_MINUS_CHAR
+                SYNTAX_MATH_MINUS
             } else {
-                SYNTAX_MATH_PLUS_CHAR
+                SYNTAX_MATH_PLUS
             },
-            if !self.im.is_zero() {
-                self.im.to_repr_string()
-            } else {
+            if self.im.abs().is_one() {
                 String::new()
+            } else {
+                self.im.abs().to_repr_string()
             },
             SYNTAX_MATH_COMPLEX_CHAR
         )
@@ -255,10 +243,12 @@ impl SchemeRepr for InexactComplex {
             } else {
                 EMPTY_STR
             },
-            if !self.im.is_zero() {
-                self.im.to_repr_string()
-            } else {
+            if self.im.is_one() {
                 String::new()
+            } else if (-self.im).is_one() {
+                SYNTAX_MATH_MINUS.to_string()
+            } else {
+                self.im.to_repr_string()
             },
             SYNTAX_MATH_COMPLEX_CHAR
         )
@@ -314,6 +304,7 @@ impl Number {
 
     pub fn is_finite(&self) -> bool {
         match self {
+            Number::InexactComplex(v) => v.is_finite(),
             Number::InexactReal(v) => v.is_finite(),
             _ => true,
         }
@@ -321,13 +312,15 @@ impl Number {
 
     pub fn is_infinite(&self) -> bool {
         match self {
+            Number::InexactComplex(v) => v.is_infinite(),
             Number::InexactReal(v) => v.is_infinite(),
-            _ => true,
+            _ => false,
         }
     }
 
     pub fn is_nan(&self) -> bool {
         match self {
+            Number::InexactComplex(v) => v.is_nan(),
             Number::InexactReal(v) => v.is_nan(),
             _ => false,
         }
@@ -367,8 +360,8 @@ impl Number {
             }
             Number::ExactReal(v) => v % ExactReal::from(2) == ExactReal::zero(),
             Number::InexactReal(v) => v % InexactReal::from(2) == InexactReal::zero(),
-            Number::Rational(v) => v % Rational::from(2) == Rational::zero(),
-            Number::Integer(v) => v % Integer::from(2) == Integer::zero(),
+            Number::Rational(v) => v.is_integer() && v.to_integer().is_even(),
+            Number::Integer(v) => v.is_even(),
         }
     }
 
@@ -442,7 +435,7 @@ impl Number {
     pub fn real_part(&self) -> Number {
         match self {
             Number::InexactComplex(v) => v.re.into(),
-            Number::ExactComplex(v) => v.re.into(),
+            Number::ExactComplex(v) => Number::from(v.re).simplify(),
             _ => self.clone().simplify(),
         }
     }
@@ -450,7 +443,7 @@ impl Number {
     pub fn imaginary_part(&self) -> Number {
         match self {
             Number::InexactComplex(v) => v.im.into(),
-            Number::ExactComplex(v) => v.im.into(),
+            Number::ExactComplex(v) => Number::from(v.im).simplify(),
             _ => Self::zero(),
         }
     }
@@ -473,49 +466,148 @@ impl Number {
         }
     }
 
+    pub fn abs(&self) -> Result<Number, Error> {
+        match self {
+            Number::ExactComplex(_) | Number::InexactComplex(_) => {
+                unexpected_type!(TYPE_NAME_REAL, self)
+            }
+            Number::ExactReal(v) => Ok(v.abs().into()),
+            Number::InexactReal(v) => Ok(v.abs().into()),
+            Number::Rational(v) => Ok(v.abs().into()),
+            Number::Integer(v) => Ok(v.abs().into()),
+        }
+    }
+
+    ///
+    /// The largest integer not larger than this number, the result is exact if this number is.
+    ///
+    pub fn floor(&self) -> Result<Number, Error> {
+        self.to_integral(Rational::floor, ExactReal::floor, InexactReal::floor)
+    }
+
+    ///
+    /// The smallest integer not smaller than this number, the result is exact if this number is.
+    ///
+    pub fn ceiling(&self) -> Result<Number, Error> {
+        self.to_integral(Rational::ceil, ExactReal::ceil, InexactReal::ceil)
+    }
+
+    ///
+    /// The integer closest to this number, rounding to even when this number is halfway between
+    /// two integers. The result is exact if this number is.
+    ///
+    pub fn round(&self) -> Result<Number, Error> {
+        self.to_integral(
+            rational_round_even,
+            |v| v.round_dp_with_strategy(0, RoundingStrategy::MidpointNearestEven),
+            InexactReal::round_ties_even,
+        )
+    }
+
+    ///
+    /// The integer closest to this number whose absolute value is not larger than this
+    /// number's, the result is exact if this number is.
+    ///
+    pub fn truncate(&self) -> Result<Number, Error> {
+        self.to_integral(Rational::trunc, ExactReal::trunc, InexactReal::trunc)
+    }
+
+    fn to_integral(
+        &self,
+        rational_fn: impl Fn(&Rational) -> Rational,
+        exact_real_fn: impl Fn(&ExactReal) -> ExactReal,
+        inexact_real_fn: impl Fn(InexactReal) -> InexactReal,
+    ) -> Result<Number, Error> {
+        match self {
+            Number::ExactComplex(_) | Number::InexactComplex(_) => {
+                unexpected_type!(TYPE_NAME_REAL, self)
+            }
+            Number::ExactReal(v) => {
+                let v = exact_real_fn(v);
+                Ok(match v.to_i128() {
+                    Some(i) => Integer::from(i).into(),
+                    None => v.into(),
+                })
+            }
+            Number::InexactReal(v) => Ok(inexact_real_fn(*v).into()),
+            Number::Rational(v) => Ok(rational_fn(v).to_integer().into()),
+            Number::Integer(_) => Ok(self.clone()),
+        }
+    }
+
+    ///
+    /// Return the external representation of this number in the given radix, which must be one
+    /// of 2, 8, 10, or 16. Only exact numbers may be written in a radix other than 10; exact
+    /// reals with a fractional part are written as the equivalent rational.
+    ///
+    pub fn to_string_radix(&self, radix: u32) -> Result<String, Error> {
+        match radix {
+            10 => Ok(self.to_repr_string()),
+            2 | 8 | 16 => match self {
+                Number::ExactComplex(v) => Ok(format!(
+                    "{}{}{}{}",
+                    if !v.re.is_zero() {
+                        exact_real_to_string_radix(&v.re, radix)?
+                    } else {
+                        String::new()
+                    },
+                    if v.im.is_sign_negative() {
+                        SYNTAX_MATH_MINUS
+                    } else {
+                        SYNTAX_MATH_PLUS
+                    },
+                    if v.im.abs().is_one() {
+                        String::new()
+                    } else {
+                        exact_real_to_string_radix(&v.im.abs(), radix)?
+                    },
+                    SYNTAX_MATH_COMPLEX_CHAR
+                )),
+                Number::ExactReal(v) => exact_real_to_string_radix(v, radix),
+                Number::Rational(v) => Ok(rational_to_string_radix(v, radix)),
+                Number::Integer(v) => Ok(v.to_str_radix(radix)),
+                Number::InexactComplex(_) | Number::InexactReal(_) => {
+                    Err(unexpected_radix(radix, "10"))
+                }
+            },
+            _ => Err(unexpected_radix(radix, "2, 8, 10, or 16")),
+        }
+    }
+
+    ///
+    /// Return the narrowest representation of this number without changing its exactness; a
+    /// complex number with a zero imaginary part becomes a real, and an exact number with no
+    /// fractional part becomes an integer.
+    ///
     pub fn simplify(self) -> Self {
         match self {
             Number::InexactComplex(v) => {
                 if v.im.is_zero() {
-                    if v.re.fract().is_zero() {
-                        Integer::from(v.re.to_i64().unwrap()).into()
-                    } else {
-                        v.re.into()
-                    }
+                    v.re.into()
                 } else {
                     self
                 }
             }
             Number::ExactComplex(v) => {
                 if v.im.is_zero() {
-                    if v.re.fract().is_zero() {
-                        Integer::from(v.re.to_i64().unwrap()).into()
-                    } else {
-                        v.re.into()
-                    }
+                    Number::ExactReal(v.re).simplify()
                 } else {
                     self
                 }
             }
-            Number::InexactReal(v) => {
-                if v.fract().is_zero() {
-                    Integer::from(v.to_i64().unwrap()).into()
-                } else {
-                    v.into()
-                }
-            }
+            Number::InexactReal(_) => self,
             Number::ExactReal(v) => {
                 if v.fract().is_zero() {
-                    Integer::from(v.to_i64().unwrap()).into()
+                    Integer::from(v.to_i128().unwrap()).into()
                 } else {
                     v.into()
                 }
             }
             Number::Rational(v) => {
                 if v.is_integer() {
-                    Integer::from(v.to_integer()).into()
+                    v.to_integer().into()
                 } else {
-                    self
+                    v.into()
                 }
             }
             Number::Integer(_) => self,
@@ -527,6 +619,50 @@ impl Number {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+///
+/// An exact real is written as a decimal, which is only possible in radix 10 unless it is an
+/// integer; writing it as a rational would not read back as the same value.
+///
+fn exact_real_to_string_radix(v: &ExactReal, radix: u32) -> Result<String, Error> {
+    let v = conv::exact_real_to_rational(*v)?;
+    if v.is_integer() {
+        Ok(v.numer().to_str_radix(radix))
+    } else {
+        Err(unexpected_radix(radix, "10"))
+    }
+}
+
+fn rational_to_string_radix(v: &Rational, radix: u32) -> String {
+    if v.is_integer() {
+        v.numer().to_str_radix(radix)
+    } else {
+        format!(
+            "{}/{}",
+            v.numer().to_str_radix(radix),
+            v.denom().to_str_radix(radix)
+        )
+    }
+}
+
+fn unexpected_radix(radix: u32, expected: &str) -> Error {
+    Error::from(ErrorKind::UnexpectedValue {
+        type_name: "radix".to_string(),
+        expected: expected.to_string(),
+        actual: radix.to_string(),
+    })
+}
+
+fn rational_round_even(v: &Rational) -> Rational {
+    let floor = v.floor();
+    let half = Rational::new(Integer::one(), Integer::from(2));
+    match (v - &floor).cmp(&half) {
+        Ordering::Less => floor,
+        Ordering::Greater => floor + Rational::one(),
+        Ordering::Equal if floor.to_integer().is_even() => floor,
+        Ordering::Equal => floor + Rational::one(),
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -534,7 +670,7 @@ impl Number {
 pub mod conv;
 
 mod inf_nan;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 use crate::eval::expression::Evaluate;
 use crate::eval::{Environment, Expression};
 pub use inf_nan::InfNan;
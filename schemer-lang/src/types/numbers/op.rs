@@ -1,10 +1,28 @@
 /*!
-One-line description.
+Arithmetic operators, and numeric comparison, on the `Number` type.
 
-More detailed description, with
+Binary operators follow the exactness contagion rules of R7RS §6.2.2; the result is inexact if
+either operand is inexact, and complex if either operand is complex. Otherwise the result is the
+wider of the two exact representations, so that `Integer` < `Rational` < `ExactReal`. Division of
+two integers results in a rational, any exact rational result with a denominator of `1` is
+returned as an integer and any exact complex result with a zero imaginary part as a real.
+
+An integer too large for an `ExactReal` is promoted to a rational instead, so that an operation on
+a decimal and a bignum has an exact, rational, result; in the same way an exact complex result
+that cannot be represented is returned as an inexact complex number.
+
+Note that, as for the primitive types, division of an exact number by exact zero will panic;
+callers are expected to check the divisor first.
 
 # Example
 
+```rust
+use schemer_lang::types::{Integer, Number, SchemeRepr};
+
+let n = Number::from(Integer::from(1)) / Number::from(Integer::from(2));
+assert_eq!(n.to_repr_string(), "1/2");
+```
+
 */
 
 use crate::error::Error;
@@ -12,7 +30,7 @@ use crate::types::numbers::{
     ExactComplex, ExactReal, InexactComplex, InexactReal, Integer, Number, Rational,
 };
 use num::traits::{One, Zero};
-use std::cmp::max;
+use std::cmp::{max, Ordering};
 use std::convert::TryFrom;
 use std::ops::{Add, Div, Mul, Rem, Sub};
 
@@ -39,20 +57,65 @@ enum NumberKind {
 // ------------------------------------------------------------------------------------------------
 
 macro_rules! num_binary_op {
-    ($op_trait:ident, $op_fn:ident) => {
+    ($op_trait:ident, $op_fn:ident, $checked_fn:ident) => {
+        num_binary_op!($op_trait, $op_fn, $checked_fn, pair_to_integer);
+    };
+    ($op_trait:ident, $op_fn:ident, $checked_fn:ident, $integer_pair_fn:ident) => {
         num_binary_op_impl!($op_trait, $op_fn);
 
-        num_binary_op_inner_impl!($op_trait, ExactComplex, ExactComplex, $op_fn);
-
-        num_binary_op_inner_impl!($op_trait, InexactComplex, InexactComplex, $op_fn);
-
-        num_binary_op_inner_impl!($op_trait, ExactReal, ExactReal, $op_fn);
-
-        num_binary_op_inner_impl!($op_trait, InexactReal, InexactReal, $op_fn);
-
-        num_binary_op_inner_impl!($op_trait, Rational, Rational, $op_fn);
-
-        num_binary_op_inner_impl!($op_trait, Integer, Integer, $op_fn);
+        num_binary_op_inner_impl!(
+            $op_trait,
+            ExactComplex,
+            ExactComplex,
+            $op_fn,
+            $checked_fn,
+            $integer_pair_fn
+        );
+
+        num_binary_op_inner_impl!(
+            $op_trait,
+            InexactComplex,
+            InexactComplex,
+            $op_fn,
+            $checked_fn,
+            $integer_pair_fn
+        );
+
+        num_binary_op_inner_impl!(
+            $op_trait,
+            ExactReal,
+            ExactReal,
+            $op_fn,
+            $checked_fn,
+            $integer_pair_fn
+        );
+
+        num_binary_op_inner_impl!(
+            $op_trait,
+            InexactReal,
+            InexactReal,
+            $op_fn,
+            $checked_fn,
+            $integer_pair_fn
+        );
+
+        num_binary_op_inner_impl!(
+            $op_trait,
+            Rational,
+            Rational,
+            $op_fn,
+            $checked_fn,
+            $integer_pair_fn
+        );
+
+        num_binary_op_inner_impl!(
+            $op_trait,
+            Integer,
+            Integer,
+            $op_fn,
+            $checked_fn,
+            $integer_pair_fn
+        );
     };
 }
 
@@ -76,31 +139,50 @@ macro_rules! num_binary_op_impl {
 }
 
 macro_rules! num_binary_op_inner_impl {
-    ($op_trait:ident, $num_type:ty, $num_kind:ident, $op_fn:ident) => {
+    (
+        $op_trait:ident,
+        $num_type:ty,
+        $num_kind:ident,
+        $op_fn:ident,
+        $checked_fn:ident,
+        $integer_pair_fn:ident
+    ) => {
         impl $op_trait<$num_type> for Number {
             type Output = Self;
 
             fn $op_fn(self, rhs: $num_type) -> Self::Output {
-                match max(number_kind(&self), NumberKind::$num_kind) {
+                let (lhs, rhs) = (self, Number::from(rhs));
+                let result = match contagion(number_kind(&lhs), NumberKind::$num_kind) {
                     NumberKind::InexactComplex => {
-                        num_op_pair!(self, rhs, add, pair_to_inexact_complex)
+                        num_op_pair!(lhs, rhs, $op_fn, pair_to_inexact_complex)
                     }
-                    NumberKind::ExactComplex => num_op_pair!(self, rhs, add, pair_to_exact_complex),
-                    NumberKind::InexactReal => num_op_pair!(self, rhs, add, pair_to_inexact_real),
-                    NumberKind::ExactReal => num_op_pair!(self, rhs, add, pair_to_exact_real),
-                    NumberKind::Rational => num_op_pair!(self, rhs, add, pair_to_rational),
-                    NumberKind::Integer => num_op_pair!(self, rhs, add, pair_to_integer),
-                }
+                    NumberKind::ExactComplex => {
+                        num_op_pair!(lhs.clone(), rhs.clone(), $op_fn, pair_to_exact_complex)
+                            .or_else(|_| num_op_pair!(lhs, rhs, $op_fn, pair_to_inexact_complex))
+                    }
+                    NumberKind::InexactReal => {
+                        num_op_pair!(lhs, rhs, $op_fn, pair_to_inexact_real)
+                    }
+                    NumberKind::ExactReal => match pair_to_exact_real(lhs.clone(), rhs.clone())
+                        .ok()
+                        .and_then(|(lhs, rhs)| lhs.$checked_fn(rhs))
+                    {
+                        Some(result) => Ok(Number::from(result)),
+                        None => num_op_pair!(lhs, rhs, $op_fn, pair_to_rational),
+                    },
+                    NumberKind::Rational => num_op_pair!(lhs, rhs, $op_fn, pair_to_rational),
+                    NumberKind::Integer => num_op_pair!(lhs, rhs, $op_fn, $integer_pair_fn),
+                };
+                normalize_exact(result.unwrap_or_else(|_| Number::from(InexactReal::NAN)))
             }
         }
     };
 }
 
 macro_rules! num_op_pair {
-    ($lhs:expr, $rhs:expr, $op:ident, $pair_fn:ident) => {{
-        let (lhs, rhs) = $pair_fn($lhs, Number::from($rhs)).unwrap();
-        Number::from(lhs.$op(rhs))
-    }};
+    ($lhs:expr, $rhs:expr, $op:ident, $pair_fn:ident) => {
+        $pair_fn($lhs, $rhs).map(|(lhs, rhs)| Number::from(lhs.$op(rhs)))
+    };
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -127,15 +209,15 @@ impl One for Number {
     }
 }
 
-num_binary_op!(Add, add);
+num_binary_op!(Add, add, checked_add);
 
-num_binary_op!(Sub, sub);
+num_binary_op!(Sub, sub, checked_sub);
 
-num_binary_op!(Mul, mul);
+num_binary_op!(Mul, mul, checked_mul);
 
-num_binary_op!(Div, div);
+num_binary_op!(Div, div, checked_div, pair_to_rational);
 
-num_binary_op!(Rem, rem);
+num_binary_op!(Rem, rem, checked_rem);
 
 impl std::ops::Neg for Number {
     type Output = Self;
@@ -145,13 +227,91 @@ impl std::ops::Neg for Number {
     }
 }
 
+impl Number {
+    ///
+    /// Numeric equality, as for the `=` procedure, rather than the structural equality of
+    /// `PartialEq`; so that `1`, `1.0` and `1+0i` are all equal.
+    ///
+    pub fn numeric_eq(&self, other: &Self) -> bool {
+        let (lhs, rhs) = (self.clone(), other.clone());
+        match contagion(number_kind(self), number_kind(other)) {
+            NumberKind::InexactComplex => {
+                pair_to_inexact_complex(lhs, rhs).is_ok_and(|(lhs, rhs)| lhs == rhs)
+            }
+            NumberKind::ExactComplex => {
+                pair_to_exact_complex(lhs, rhs).is_ok_and(|(lhs, rhs)| lhs == rhs)
+            }
+            _ => self.numeric_cmp(other) == Some(Ordering::Equal),
+        }
+    }
+
+    ///
+    /// Numeric ordering, as for the `<` and `>` procedures; complex numbers, other than those
+    /// with a zero imaginary part, and NaN values are not ordered.
+    ///
+    pub fn numeric_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (lhs, rhs) = (self.clone(), other.clone());
+        match contagion(number_kind(self), number_kind(other)) {
+            NumberKind::InexactComplex | NumberKind::ExactComplex => {
+                if self.imaginary_part().is_zero() && other.imaginary_part().is_zero() {
+                    self.real_part().numeric_cmp(&other.real_part())
+                } else {
+                    None
+                }
+            }
+            NumberKind::InexactReal => pair_to_inexact_real(lhs, rhs)
+                .ok()
+                .and_then(|(lhs, rhs)| lhs.partial_cmp(&rhs)),
+            NumberKind::ExactReal | NumberKind::Rational => pair_to_rational(lhs, rhs)
+                .ok()
+                .map(|(lhs, rhs)| lhs.cmp(&rhs)),
+            NumberKind::Integer => pair_to_integer(lhs, rhs)
+                .ok()
+                .map(|(lhs, rhs)| lhs.cmp(&rhs)),
+        }
+    }
+}
+
+impl NumberKind {
+    fn is_complex(&self) -> bool {
+        matches!(self, NumberKind::InexactComplex | NumberKind::ExactComplex)
+    }
+
+    fn is_inexact(&self) -> bool {
+        matches!(self, NumberKind::InexactComplex | NumberKind::InexactReal)
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+///
+/// The kind of number resulting from an operation on a `lhs` and `rhs` number.
+///
+fn contagion(lhs: NumberKind, rhs: NumberKind) -> NumberKind {
+    match (
+        lhs.is_complex() || rhs.is_complex(),
+        lhs.is_inexact() || rhs.is_inexact(),
+    ) {
+        (true, true) => NumberKind::InexactComplex,
+        (true, false) => NumberKind::ExactComplex,
+        (false, true) => NumberKind::InexactReal,
+        (false, false) => max(lhs, rhs),
+    }
+}
+
+///
+/// An exact result is returned in its simplest exact representation; so that a complex number
+/// with a zero imaginary part becomes a real, and a rational with a denominator of `1` an integer.
+///
+fn normalize_exact(n: Number) -> Number {
+    match n {
+        Number::ExactComplex(v) if v.im.is_zero() => Number::ExactReal(v.re),
+        Number::Rational(v) if v.is_integer() => Number::Integer(v.to_integer()),
+        _ => n,
+    }
+}
 
 fn pair_to_inexact_complex(
     l: Number,
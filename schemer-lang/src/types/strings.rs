@@ -14,19 +14,31 @@ use crate::read::syntax_str::{
     SYNTAX_BYTE_VECTOR_PREFIX, SYNTAX_DOUBLE_QUOTE_CHAR, SYNTAX_LEFT_PARENTHESIS_CHAR,
     SYNTAX_RIGHT_PARENTHESIS_CHAR,
 };
-use crate::types::new_type::NewType;
 use crate::types::{MutableRef, SchemeRepr, SchemeValue};
 use std::borrow::Cow;
-use std::ops::Deref;
+use std::cell::{RefCell, RefMut};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
 use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-pub type SchemeString = NewType<String>;
+///
+/// A string, the characters are held behind a shared reference so that a mutation made through
+/// `as_string_mut` is visible through every clone of this value. Two strings are equal if they
+/// have the same characters, use `ptr_eq` to determine if they are the same string.
+///
+#[derive(Clone, Default)]
+pub struct SchemeString(MutableRef<String>);
 
-pub type ByteVector = NewType<Vec<u8>>;
+///
+/// A byte vector, the bytes are held behind a shared reference in the same manner as
+/// `SchemeString` so that `bytevector-u8-set!` is visible through every clone of this value.
+///
+#[derive(Clone, Default, PartialEq)]
+pub struct ByteVector(MutableRef<Vec<u8>>);
 
 pub const TYPE_NAME_BYTE: &str = "u8";
 
@@ -38,16 +50,96 @@ pub const TYPE_NAME_BYTE: &str = "u8";
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
-pub fn escape_string(s: &str) -> Cow<str> {
-    if s.chars().any(char::is_control) {
-        s.into()
-    } else {
-        s.into()
+pub fn escape_string(s: &str) -> Cow<'_, str> {
+    escape_delimited(s, SYNTAX_DOUBLE_QUOTE_CHAR)
+}
+
+///
+/// Escape `s` so that it can be written between two `delimiter` characters, as a string or a
+/// `|` delimited symbol is; the delimiter, `\`, and control characters are escaped.
+///
+pub fn escape_delimited(s: &str, delimiter: char) -> Cow<'_, str> {
+    if !s
+        .chars()
+        .any(|c| c == delimiter || c == '\\' || c.is_control())
+    {
+        return s.into();
+    }
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\u{7}' => escaped.push_str("\\a"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c if c == delimiter => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c if c.is_control() => escaped.push_str(&format!("\\x{:x};", c as u32)),
+            c => escaped.push(c),
+        }
     }
+    escaped.into()
 }
 
-pub fn unescape_string(s: &str) -> Cow<str> {
-    s.into()
+///
+/// Replace the escapes in `s`, the characters of a string literal between its double quotes, with
+/// the characters they stand for; a `\` followed by a line ending, and any whitespace around it,
+/// is removed entirely.
+///
+pub fn unescape_string(s: &str) -> Result<Cow<'_, str>, Error> {
+    if !s.contains('\\') {
+        return Ok(s.into());
+    }
+    let bad_escape = || -> Error {
+        ErrorKind::ParseValue {
+            kind: TYPE_NAME_STRING.to_string(),
+            value: s.to_string(),
+        }
+        .into()
+    };
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('a') => unescaped.push('\u{7}'),
+            Some('b') => unescaped.push('\u{8}'),
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(c @ ('\\' | '"')) => unescaped.push(c),
+            Some('x') => {
+                let hex: String = chars.by_ref().take_while(|c| *c != ';').collect();
+                let c = u32::from_str_radix(&hex, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(bad_escape)?;
+                unescaped.push(c);
+            }
+            Some(mut c) if is_intraline_whitespace(c) || c == '\n' || c == '\r' => {
+                while is_intraline_whitespace(c) {
+                    c = chars.next().ok_or_else(bad_escape)?;
+                }
+                match c {
+                    '\r' => {
+                        let _ = chars.next_if_eq(&'\n');
+                    }
+                    '\n' => {}
+                    _ => return Err(bad_escape()),
+                }
+                while chars.next_if(|c| is_intraline_whitespace(*c)).is_some() {}
+            }
+            _ => return Err(bad_escape()),
+        }
+    }
+    Ok(unescaped.into())
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -59,7 +151,7 @@ impl FromStr for SchemeString {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.starts_with(SYNTAX_DOUBLE_QUOTE_CHAR) && s.ends_with(SYNTAX_DOUBLE_QUOTE_CHAR) {
-            let string = s[1..s.len() - 1].to_string();
+            let string = unescape_string(&s[1..s.len() - 1])?.into_owned();
             Ok(Self::from(string))
         } else {
             Err(ErrorKind::ParseValue {
@@ -76,7 +168,7 @@ impl SchemeRepr for SchemeString {
         format!(
             "{}{}{}",
             SYNTAX_DOUBLE_QUOTE_CHAR,
-            self.deref(),
+            self.as_string(),
             SYNTAX_DOUBLE_QUOTE_CHAR
         )
     }
@@ -90,10 +182,80 @@ impl Evaluate for SchemeString {
     }
 }
 
+impl Debug for SchemeString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0.borrow())
+    }
+}
+
+impl Display for SchemeString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.borrow())
+    }
+}
+
+impl PartialEq for SchemeString {
+    fn eq(&self, other: &Self) -> bool {
+        *self.0.borrow() == *other.0.borrow()
+    }
+}
+
+impl PartialOrd for SchemeString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.borrow().partial_cmp(&*other.0.borrow())
+    }
+}
+
+impl From<String> for SchemeString {
+    fn from(value: String) -> Self {
+        Self(MutableRef::new(RefCell::new(value)))
+    }
+}
+
+impl From<&str> for SchemeString {
+    fn from(value: &str) -> Self {
+        Self::from(value.to_string())
+    }
+}
+
 impl SchemeString {
     pub fn new_unchecked(s: &str) -> Self {
         Self::from(s.to_string())
     }
+
+    pub fn as_string(&self) -> std::cell::Ref<'_, String> {
+        self.0.borrow()
+    }
+
+    ///
+    /// Mutable access to the characters of this string, the change is visible through any clone
+    /// of this value.
+    ///
+    pub fn as_string_mut(&self) -> RefMut<'_, String> {
+        self.0.borrow_mut()
+    }
+
+    ///
+    /// A new string with a copy of the characters of this one.
+    ///
+    pub fn deep_clone(&self) -> Self {
+        Self::from(self.as_string().clone())
+    }
+
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        MutableRef::ptr_eq(&self.0, &other.0)
+    }
+
+    ///
+    /// The length of this string in characters, not bytes.
+    ///
+    pub fn len(&self) -> usize {
+        self.0.borrow().chars().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -104,7 +266,8 @@ impl SchemeRepr for ByteVector {
             "{}{}{}{}",
             SYNTAX_BYTE_VECTOR_PREFIX,
             SYNTAX_LEFT_PARENTHESIS_CHAR,
-            self.iter()
+            self.as_bytes()
+                .iter()
                 .map(|v| v.to_string())
                 .collect::<Vec<String>>()
                 .join(" "),
@@ -121,10 +284,66 @@ impl Evaluate for ByteVector {
     }
 }
 
+impl Debug for ByteVector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0.borrow())
+    }
+}
+
+impl From<Vec<u8>> for ByteVector {
+    fn from(value: Vec<u8>) -> Self {
+        Self(MutableRef::new(RefCell::new(value)))
+    }
+}
+
+impl From<&[u8]> for ByteVector {
+    fn from(value: &[u8]) -> Self {
+        Self::from(value.to_vec())
+    }
+}
+
+impl ByteVector {
+    pub fn as_bytes(&self) -> std::cell::Ref<'_, Vec<u8>> {
+        self.0.borrow()
+    }
+
+    ///
+    /// Mutable access to the bytes of this byte vector, the change is visible through any clone
+    /// of this value.
+    ///
+    pub fn as_bytes_mut(&self) -> RefMut<'_, Vec<u8>> {
+        self.0.borrow_mut()
+    }
+
+    ///
+    /// A new byte vector with a copy of the bytes of this one.
+    ///
+    pub fn deep_clone(&self) -> Self {
+        Self::from(self.as_bytes().clone())
+    }
+
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        MutableRef::ptr_eq(&self.0, &other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+#[inline]
+fn is_intraline_whitespace(c: char) -> bool {
+    c == ' ' || c == '\t'
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
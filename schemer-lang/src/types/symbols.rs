@@ -9,8 +9,13 @@ More detailed description, with
 
 use crate::error::{Error, ErrorKind};
 use crate::eval::expression::Evaluate;
+use crate::eval::macros::{resolve_alias, Alias};
 use crate::eval::{Environment, Expression};
-use crate::types::{MutableRef, SchemeRepr, SchemeValue};
+use crate::types::{MutableRef, Ref, SchemeRepr, SchemeValue};
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
 use std::ops::Deref;
 use std::str::FromStr;
 
@@ -18,8 +23,18 @@ use std::str::FromStr;
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
-pub struct Identifier(String);
+///
+/// An identifier, or symbol. Interned identifiers with the same name are the same symbol, while
+/// each uninterned identifier, as returned by `new_uninterned`, is distinct from every other
+/// identifier regardless of its name. An alias, introduced by a macro expansion, is an uninterned
+/// identifier that also records the identifier it replaced.
+///
+#[derive(Clone, Debug)]
+pub struct Identifier {
+    name: String,
+    uninterned: Option<NonZeroUsize>,
+    alias: Option<Ref<Alias>>,
+}
 
 pub const TYPE_NAME_SYMBOL: &str = "symbol";
 
@@ -27,6 +42,11 @@ pub const TYPE_NAME_SYMBOL: &str = "symbol";
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+thread_local! {
+    // starts at 1, as the number of an uninterned identifier is non-zero.
+    static NEXT_UNINTERNED: Cell<usize> = const { Cell::new(1) };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
@@ -35,9 +55,37 @@ pub const TYPE_NAME_SYMBOL: &str = "symbol";
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl PartialEq for Identifier {
+    fn eq(&self, other: &Self) -> bool {
+        // an alias is identified by its uninterned number alone.
+        self.name == other.name && self.uninterned == other.uninterned
+    }
+}
+
+impl Eq for Identifier {}
+
+impl PartialOrd for Identifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Identifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.name, self.uninterned).cmp(&(&other.name, other.uninterned))
+    }
+}
+
+impl Hash for Identifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.uninterned.hash(state);
+    }
+}
+
 impl From<Identifier> for String {
     fn from(v: Identifier) -> Self {
-        v.0
+        v.name
     }
 }
 
@@ -45,7 +93,7 @@ impl Deref for Identifier {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.name
     }
 }
 
@@ -54,13 +102,13 @@ impl FromStr for Identifier {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // TODO: actually check the string
-        Ok(Self(s.to_string()))
+        Ok(Self::from_str_unchecked(s))
     }
 }
 
 impl SchemeRepr for Identifier {
     fn to_repr_string(&self) -> String {
-        self.0.clone()
+        self.name.clone()
     }
 }
 
@@ -72,17 +120,77 @@ impl SchemeValue for Identifier {
 
 impl Evaluate for Identifier {
     fn eval(&self, environment: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-        if let Some(value) = environment.borrow().get(self) {
-            Ok(value.clone())
-        } else {
-            Err(Error::from(ErrorKind::UnboundVariable { name: self.clone() }).into())
+        let value = environment.borrow().get(self);
+        match value {
+            Some(Expression::Uninitialized) => Err(Error::from(ErrorKind::UninitializedVariable {
+                name: self.clone(),
+            })),
+            Some(value) => Ok(value),
+            None => match resolve_alias(self) {
+                // introduced by a macro, and not bound by the expansion itself.
+                Some((name, mut environment)) => name.eval(&mut environment),
+                None => Err(Error::from(ErrorKind::UnboundVariable {
+                    name: self.clone(),
+                })),
+            },
         }
     }
 }
 
 impl Identifier {
     pub fn from_str_unchecked(s: &str) -> Self {
-        Self(s.to_string())
+        Self {
+            name: s.to_string(),
+            uninterned: None,
+            alias: None,
+        }
+    }
+
+    ///
+    /// A new uninterned identifier named `s`, it is not equal to any other identifier, including
+    /// any other uninterned identifier with the same name.
+    ///
+    pub fn new_uninterned(s: &str) -> Self {
+        let next = NEXT_UNINTERNED.with(|next| next.replace(next.get() + 1));
+        Self {
+            name: s.to_string(),
+            uninterned: NonZeroUsize::new(next),
+            alias: None,
+        }
+    }
+
+    ///
+    /// A new uninterned identifier with a generated name, `prefix` followed by a number unique to
+    /// this thread.
+    ///
+    pub fn new_generated(prefix: &str) -> Self {
+        let next = NEXT_UNINTERNED.with(|next| next.replace(next.get() + 1));
+        Self {
+            name: format!("{}{}", prefix, next),
+            uninterned: NonZeroUsize::new(next),
+            alias: None,
+        }
+    }
+
+    ///
+    /// A new uninterned identifier, with the same name as the identifier `alias` replaces in a
+    /// macro expansion.
+    ///
+    pub(crate) fn new_alias(alias: Alias) -> Self {
+        let next = NEXT_UNINTERNED.with(|next| next.replace(next.get() + 1));
+        Self {
+            name: alias.name().as_str().to_string(),
+            uninterned: NonZeroUsize::new(next),
+            alias: Some(Ref::new(alias)),
+        }
+    }
+
+    pub(crate) fn alias(&self) -> Option<&Alias> {
+        self.alias.as_deref()
+    }
+
+    pub fn is_interned(&self) -> bool {
+        self.uninterned.is_none()
     }
 
     pub fn is_valid(s: &str) -> bool {
@@ -90,7 +198,7 @@ impl Identifier {
     }
 
     pub fn as_str(&self) -> &str {
-        self.0.as_str()
+        self.name.as_str()
     }
 }
 
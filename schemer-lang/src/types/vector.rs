@@ -14,18 +14,24 @@ use crate::read::datum::Datum;
 use crate::read::syntax_str::{
     SYNTAX_LEFT_PARENTHESIS_CHAR, SYNTAX_RIGHT_PARENTHESIS_CHAR, SYNTAX_VECTOR_PREFIX,
 };
+use crate::types::cycles::repr_compound;
 use crate::types::{MutableRef, Ref};
 use crate::types::{SchemeRepr, SchemeValue};
-use std::fmt::Debug;
+use std::cell::{RefCell, RefMut};
+use std::fmt::{Debug, Formatter};
 use std::iter::FromIterator;
-use std::ops::{Deref, DerefMut};
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct Vector<T>(Vec<Ref<T>>)
+///
+/// A vector, the elements are held behind a shared reference so that a mutation made through
+/// `as_vec_mut` is visible through every clone of this value. Two vectors are equal if they have
+/// equal elements, use `ptr_eq` to determine if they are the same vector.
+///
+#[derive(Clone, PartialEq)]
+pub struct Vector<T>(MutableRef<Vec<Ref<T>>>)
 where
     T: Clone + Debug + PartialEq + SchemeRepr;
 
@@ -46,27 +52,16 @@ where
     T: Clone + Debug + PartialEq + SchemeRepr,
 {
     fn default() -> Self {
-        Self(Vec::default())
+        Self::from(Vec::<Ref<T>>::default())
     }
 }
 
-impl<T> Deref for Vector<T>
+impl<T> Debug for Vector<T>
 where
     T: Clone + Debug + PartialEq + SchemeRepr,
 {
-    type Target = Vec<Ref<T>>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl<T> DerefMut for Vector<T>
-where
-    T: Clone + Debug + PartialEq + SchemeRepr,
-{
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0.borrow())
     }
 }
 
@@ -75,7 +70,7 @@ where
     T: Clone + Debug + PartialEq + SchemeRepr,
 {
     fn from(value: Vec<Ref<T>>) -> Self {
-        Self(value)
+        Self(MutableRef::new(RefCell::new(value)))
     }
 }
 
@@ -84,7 +79,7 @@ where
     T: Clone + Debug + PartialEq + SchemeRepr,
 {
     fn from(value: Vec<T>) -> Self {
-        Self(value.into_iter().map(|t| Ref::new(t)).collect())
+        Self::from(value.into_iter().map(Ref::new).collect::<Vec<Ref<T>>>())
     }
 }
 
@@ -102,7 +97,7 @@ where
     T: Clone + Debug + PartialEq + SchemeRepr,
 {
     fn from(v: Vector<T>) -> Self {
-        v.0
+        v.0.borrow().clone()
     }
 }
 
@@ -122,16 +117,19 @@ where
     T: Clone + Debug + PartialEq + SchemeRepr,
 {
     fn to_repr_string(&self) -> String {
-        format!(
-            "{}{}{}{}",
-            SYNTAX_VECTOR_PREFIX,
-            SYNTAX_LEFT_PARENTHESIS_CHAR,
-            self.iter()
-                .map(|v| v.to_repr_string())
-                .collect::<Vec<String>>()
-                .join(" "),
-            SYNTAX_RIGHT_PARENTHESIS_CHAR
-        )
+        repr_compound(self.as_ptr(), || {
+            format!(
+                "{}{}{}{}",
+                SYNTAX_VECTOR_PREFIX,
+                SYNTAX_LEFT_PARENTHESIS_CHAR,
+                self.as_vec()
+                    .iter()
+                    .map(|v| v.to_repr_string())
+                    .collect::<Vec<String>>()
+                    .join(" "),
+                SYNTAX_RIGHT_PARENTHESIS_CHAR
+            )
+        })
     }
 }
 
@@ -147,6 +145,54 @@ where
     }
 }
 
+impl<T> Vector<T>
+where
+    T: Clone + Debug + PartialEq + SchemeRepr,
+{
+    pub fn as_vec(&self) -> std::cell::Ref<'_, Vec<Ref<T>>> {
+        self.0.borrow()
+    }
+
+    ///
+    /// Mutable access to the elements of this vector, the change is visible through any clone
+    /// of this value.
+    ///
+    pub fn as_vec_mut(&self) -> RefMut<'_, Vec<Ref<T>>> {
+        self.0.borrow_mut()
+    }
+
+    ///
+    /// A new vector with a copy of the elements of this one, the elements themselves are shared.
+    ///
+    pub fn deep_clone(&self) -> Self {
+        Self::from(self.as_vec().clone())
+    }
+
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        MutableRef::ptr_eq(&self.0, &other.0)
+    }
+
+    ///
+    /// The address of the storage shared by every clone of this vector, which identifies it for
+    /// as long as it is referenced.
+    ///
+    pub fn as_ptr(&self) -> *const () {
+        MutableRef::as_ptr(&self.0) as *const ()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Ref<T>> {
+        self.0.borrow().get(index).cloned()
+    }
+}
+
 impl Evaluate for Vector<Datum> {
     fn eval(&self, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
         Ok(Expression::Vector(self.clone()))
@@ -0,0 +1,116 @@
+#![allow(dead_code)]
+
+use schemer_lang::error::{Error, ErrorKind};
+use schemer_lang::eval::forms::standard_form_exports;
+use schemer_lang::eval::{Environment, Evaluate, Exports, Expression, Procedure};
+use schemer_lang::types::numbers::TYPE_NAME_INTEGER;
+use schemer_lang::types::{Boolean, Identifier, Integer, MutableRef, Number, SchemeRepr};
+use schemer_macros::{builtin, eboolean, einteger, export_builtin, id_from_str};
+use schemer_parse::parser::parse_data_str;
+
+// ------------------------------------------------------------------------------------------------
+// Test environment
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An environment with the standard forms and just enough integer arithmetic to write tests with;
+/// the numeric library lives in `schemer-library` which we can't depend on here.
+///
+pub fn test_environment() -> MutableRef<Environment> {
+    let environment = Environment::top();
+    {
+        let mut top = environment.borrow_mut();
+        top.import(standard_form_exports()).unwrap();
+        top.import(test_arithmetic_exports()).unwrap();
+    }
+    Environment::new_child_named(environment, "*test*")
+}
+
+pub fn eval_str(src: &str) -> Result<Expression, Error> {
+    eval_str_in(src, &mut test_environment())
+}
+
+pub fn eval_str_in(
+    src: &str,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    println!("<< {}", src);
+    let data = parse_data_str(src)?;
+    let mut result = Expression::Unspecified;
+    for datum in data {
+        result = datum.eval(environment)?;
+    }
+    println!(">> {}", result.to_repr_string());
+    Ok(result)
+}
+
+pub fn assert_eval_eq(src: &str, expected: Expression) {
+    match eval_str(src) {
+        Ok(result) => assert_eq!(result, expected),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+pub fn assert_eval_repr(src: &str, expected: &str) {
+    match eval_str(src) {
+        Ok(result) => assert_eq!(result.to_repr_string(), expected),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+pub fn assert_eval_err(src: &str) -> Error {
+    match eval_str(src) {
+        Ok(result) => panic!("expected an error, not {}", result.to_repr_string()),
+        Err(e) => {
+            println!("!! {}", e);
+            e
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Test builtins
+// ------------------------------------------------------------------------------------------------
+
+fn test_arithmetic_exports() -> Exports {
+    let mut exports = Exports::default();
+
+    export_builtin!(exports, "+" => plus "a" "b");
+    export_builtin!(exports, "-" => minus "a" "b");
+    export_builtin!(exports, "=" => equal "a" "b");
+    export_builtin!(exports, "<" => less "a" "b");
+
+    exports
+}
+
+fn integers(arguments: &[Expression]) -> Result<(Integer, Integer), Error> {
+    match (&arguments[0], &arguments[1]) {
+        (Expression::Number(Number::Integer(a)), Expression::Number(Number::Integer(b))) => {
+            Ok((a.clone(), b.clone()))
+        }
+        _ => Err(Error::from(ErrorKind::UnexpectedType {
+            expected: TYPE_NAME_INTEGER.to_string(),
+            actual: None,
+        })),
+    }
+}
+
+fn plus(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let (a, b) = integers(&arguments)?;
+    Ok(einteger!(a + b))
+}
+
+fn minus(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let (a, b) = integers(&arguments)?;
+    Ok(einteger!(a - b))
+}
+
+fn equal(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let (a, b) = integers(&arguments)?;
+    Ok(eboolean!(a == b))
+}
+
+fn less(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let (a, b) = integers(&arguments)?;
+    Ok(eboolean!(a < b))
+}
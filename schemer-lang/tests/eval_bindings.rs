@@ -0,0 +1,144 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, Integer, Number};
+use schemer_macros::{eboolean, einteger};
+
+#[test]
+fn test_let() {
+    assert_eval_eq("(let ((x 2) (y 3)) (+ x y))", einteger!(5));
+    assert_eval_eq("(let () 5)", einteger!(5));
+    // inits are evaluated in the outer environment
+    assert_eval_eq("(define x 10) (let ((x 2) (y x)) (+ x y))", einteger!(12));
+}
+
+#[test]
+fn test_let_does_not_leak() {
+    let _ = assert_eval_err("(let ((x 2)) x) x");
+}
+
+#[test]
+fn test_let_closure() {
+    assert_eval_eq(
+        r#"
+(define counter
+  (let ((count 0))
+    (lambda () (set! count (+ count 1)) count)))
+(counter)
+(counter)
+"#,
+        einteger!(2),
+    );
+}
+
+#[test]
+fn test_let_star() {
+    assert_eval_eq("(let* ((x 1) (y (+ x 1))) (+ x y))", einteger!(3));
+    assert_eval_eq("(let* ((x 1) (x (+ x 1))) x)", einteger!(2));
+    // each binding is a new scope, a closure sees only earlier bindings
+    assert_eval_eq("(let* ((x 1) (f (lambda () x)) (x 2)) (f))", einteger!(1));
+}
+
+#[test]
+fn test_letrec() {
+    assert_eval_eq(
+        r#"
+(letrec ((my-even? (lambda (n) (if (= n 0) #t (my-odd? (- n 1)))))
+         (my-odd? (lambda (n) (if (= n 0) #f (my-even? (- n 1))))))
+  (my-even? 88))
+"#,
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_letrec_restriction() {
+    let error = assert_eval_err("(letrec ((a 1) (b (+ a 1))) b)");
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::UninitializedVariable { .. }
+    ));
+    let error = assert_eval_err("(letrec ((a (lambda () b)) (b (a))) b)");
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::UninitializedVariable { .. }
+    ));
+}
+
+#[test]
+fn test_letrec_shadows_outer() {
+    let error = assert_eval_err("(define a 1) (letrec ((a a)) a)");
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::UninitializedVariable { .. }
+    ));
+}
+
+#[test]
+fn test_letrec_star() {
+    assert_eval_eq(
+        r#"
+(letrec* ((p (lambda (x) (+ 1 (q (- x 1)))))
+          (q (lambda (y) (if (= y 0) 0 (+ 1 (p (- y 1))))))
+          (x (p 5))
+          (y x))
+  y)
+"#,
+        einteger!(5),
+    );
+}
+
+#[test]
+fn test_named_let() {
+    assert_eval_eq(
+        r#"
+(let loop ((i 0) (total 0))
+  (if (= i 5)
+      total
+      (loop (+ i 1) (+ total i))))
+"#,
+        einteger!(10),
+    );
+}
+
+#[test]
+fn test_named_let_is_a_loop() {
+    assert_eval_repr(
+        r#"
+(let loop ((i 100000))
+  (if (= i 0)
+      'done
+      (loop (- i 1))))
+"#,
+        "'done",
+    );
+}
+
+#[test]
+fn test_named_let_name_not_visible_in_inits() {
+    let _ = assert_eval_err("(let loop ((f loop)) f)");
+}
+
+#[test]
+fn test_let_values() {
+    assert_eval_eq("(let-values (((a) 1) ((b) 2)) (+ a b))", einteger!(3));
+    assert_eval_eq(
+        "(let*-values (((a) 1) ((b) (+ a 1))) (+ a b))",
+        einteger!(3),
+    );
+    assert_eval_repr("(let-values ((all 1)) all)", "'(1)");
+}
+
+#[test]
+fn test_let_values_arity() {
+    let error = assert_eval_err("(let-values (((a b) 1)) a)");
+    assert!(matches!(error.kind(), ErrorKind::ValuesCardinality { .. }));
+}
+
+#[test]
+fn test_bad_bindings() {
+    let _ = assert_eval_err("(let ((x)) x)");
+    let _ = assert_eval_err("(let (x 1) x)");
+    let _ = assert_eval_err("(let x)");
+}
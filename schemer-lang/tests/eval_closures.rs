@@ -0,0 +1,143 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, Integer, Number};
+use schemer_macros::{eboolean, einteger};
+
+#[test]
+fn test_free_variable_is_lexical() {
+    assert_eval_repr(
+        r#"
+(define x 'outer)
+(define (get-x) x)
+(define (shadow x) (get-x))
+(shadow 'inner)
+"#,
+        "'outer",
+    );
+}
+
+#[test]
+fn test_curried_procedure() {
+    assert_eval_eq("(((lambda (x) (lambda (y) (+ x y))) 1) 2)", einteger!(3));
+    assert_eval_eq(
+        r#"
+(define (make-adder n) (lambda (x) (+ x n)))
+(define add-5 (make-adder 5))
+(define add-7 (make-adder 7))
+(+ (add-5 1) (add-7 1))
+"#,
+        einteger!(14),
+    );
+}
+
+#[test]
+fn test_returned_accumulator() {
+    assert_eval_eq(
+        r#"
+(define (make-accumulator total)
+  (lambda (n)
+    (set! total (+ total n))
+    total))
+(define acc (make-accumulator 10))
+(acc 5)
+(acc 10)
+"#,
+        einteger!(25),
+    );
+}
+
+#[test]
+fn test_accumulators_are_independent() {
+    assert_eval_eq(
+        r#"
+(define (make-accumulator total)
+  (lambda (n)
+    (set! total (+ total n))
+    total))
+(define a (make-accumulator 0))
+(define b (make-accumulator 100))
+(a 1)
+(b 1)
+(a 1)
+"#,
+        einteger!(2),
+    );
+}
+
+#[test]
+fn test_closure_over_loop_variable() {
+    assert_eval_eq(
+        r#"
+(define first #f)
+(define (loop i)
+  (if (= i 3)
+      'done
+      (begin
+        (if (= i 0) (set! first (lambda () i)))
+        (loop (+ i 1)))))
+(loop 0)
+(first)
+"#,
+        einteger!(0),
+    );
+}
+
+#[test]
+fn test_mutual_recursion() {
+    assert_eval_eq(
+        r#"
+(define (my-even? n) (if (= n 0) #t (my-odd? (- n 1))))
+(define (my-odd? n) (if (= n 0) #f (my-even? (- n 1))))
+(my-even? 10)
+"#,
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_internal_mutual_recursion() {
+    assert_eval_repr(
+        r#"
+(define (parity n)
+  (define (ev? n) (if (= n 0) 'even (od? (- n 1))))
+  (define (od? n) (if (= n 0) 'odd (ev? (- n 1))))
+  (ev? n))
+(parity 7)
+"#,
+        "'odd",
+    );
+}
+
+#[test]
+fn test_parameters_do_not_leak() {
+    let _ = assert_eval_err(
+        r#"
+(define (f secret) (lambda () secret))
+(define (g) secret)
+(f 1)
+(g)
+"#,
+    );
+}
+
+#[test]
+fn test_lambda_body_sequence() {
+    assert_eval_eq(
+        "((lambda () (define a 1) (define b 2) (+ a b)))",
+        einteger!(3),
+    );
+}
+
+#[test]
+fn test_non_procedure_operator() {
+    let _ = assert_eval_err("(1 2)");
+}
+
+#[test]
+fn test_lambda_value() {
+    assert!(matches!(
+        common::eval_str("(lambda (x) x)"),
+        Ok(Expression::Procedure(_))
+    ));
+}
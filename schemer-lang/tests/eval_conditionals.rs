@@ -0,0 +1,121 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, Integer, Number};
+use schemer_macros::{eboolean, einteger};
+
+#[test]
+fn test_cond() {
+    assert_eval_eq("(cond (#f 1) (#t 2) (#t 3))", einteger!(2));
+    assert_eval_eq("(cond ((= 1 2) 1) ((< 1 2) 2))", einteger!(2));
+    assert_eval_eq("(cond (#f 1) (else 2 3))", einteger!(3));
+    assert_eval_eq("(cond (#f 1) (#f 2))", Expression::Unspecified);
+}
+
+#[test]
+fn test_cond_test_only_clause() {
+    assert_eval_eq("(cond (#f) ((+ 1 1)))", einteger!(2));
+}
+
+#[test]
+fn test_cond_arrow() {
+    assert_eval_eq(
+        "(cond (#f 1) ((+ 1 1) => (lambda (x) (+ x 10))))",
+        einteger!(12),
+    );
+    let _ = assert_eval_err("(cond (#t => 1))");
+}
+
+#[test]
+fn test_cond_only_evaluates_selected_clause() {
+    assert_eval_eq(
+        r#"
+(define x 0)
+(cond ((= x 0) (set! x 1))
+      ((= x 1) (set! x 2))
+      (else (set! x 3)))
+x
+"#,
+        einteger!(1),
+    );
+}
+
+#[test]
+fn test_case() {
+    assert_eval_eq("(case (+ 1 1) ((1) 'one) ((2 3) 5) (else 6))", einteger!(5));
+    assert_eval_repr(
+        "(case 'b ((a) 'first) ((b c) 'second) (else 'third))",
+        "'second",
+    );
+    assert_eval_repr(
+        "(case 'z ((a) 'first) ((b c) 'second) (else 'third))",
+        "'third",
+    );
+    assert_eval_repr(
+        "(case #\\a ((#\\a #\\e) 'vowel) (else 'consonant))",
+        "'vowel",
+    );
+    assert_eval_eq("(case 9 ((1) 'one) (() 'none))", Expression::Unspecified);
+}
+
+#[test]
+fn test_case_arrow() {
+    assert_eval_eq(
+        "(case 5 ((1 2) 0) (else => (lambda (x) (+ x 1))))",
+        einteger!(6),
+    );
+    assert_eval_eq(
+        "(case 2 ((1 2) => (lambda (x) (+ x 10))) (else 0))",
+        einteger!(12),
+    );
+}
+
+#[test]
+fn test_and() {
+    assert_eval_eq("(and)", eboolean!(true));
+    assert_eval_eq("(and 1 2)", einteger!(2));
+    assert_eval_eq("(and 1 #f 2)", eboolean!(false));
+    // short-circuits, the unbound variable is never evaluated
+    assert_eval_eq("(and #f unbound-variable)", eboolean!(false));
+}
+
+#[test]
+fn test_or() {
+    assert_eval_eq("(or)", eboolean!(false));
+    assert_eval_eq("(or #f 3)", einteger!(3));
+    assert_eval_eq("(or #f #f)", eboolean!(false));
+    assert_eval_eq("(or 1 unbound-variable)", einteger!(1));
+}
+
+#[test]
+fn test_when_unless() {
+    assert_eval_eq("(when (= 1 1) 1 2)", einteger!(2));
+    assert_eval_eq("(when (= 1 2) 1 2)", Expression::Unspecified);
+    assert_eval_eq("(unless (= 1 2) 1 2)", einteger!(2));
+    assert_eval_eq("(unless (= 1 1) 1 2)", Expression::Unspecified);
+}
+
+#[test]
+fn test_bad_clause() {
+    let _ = assert_eval_err("(cond 1)");
+    let _ = assert_eval_err("(case 1 (1 2))");
+}
+
+#[test]
+fn test_tail_positions() {
+    assert_eval_eq(
+        r#"
+(define (loop n)
+  (cond ((= n 0) 'done)
+        ((and (< 0 n) (or #f #t)) (when #t (loop (- n 1))))
+        (else (unless #f (loop (- n 1))))))
+(loop 100000)
+(define (loop-case n)
+  (case n
+    ((0) 0)
+    (else (loop-case (- n 1)))))
+(loop-case 100000)
+"#,
+        einteger!(0),
+    );
+}
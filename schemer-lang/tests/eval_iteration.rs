@@ -0,0 +1,86 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err};
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Integer, Number};
+use schemer_macros::einteger;
+
+#[test]
+fn test_do() {
+    assert_eval_eq(
+        "(do ((i 0 (+ i 1)) (total 0 (+ total i))) ((= i 5) total))",
+        einteger!(10),
+    );
+}
+
+#[test]
+fn test_do_without_step() {
+    assert_eval_eq(
+        r#"
+(do ((i 0 (+ i 1))
+     (acc 0))
+    ((= i 3) acc)
+  (set! acc (+ acc 2)))
+"#,
+        einteger!(6),
+    );
+}
+
+#[test]
+fn test_do_result_sequence() {
+    assert_eval_eq("(do ((i 0 (+ i 1))) ((= i 3) 1 2 i))", einteger!(3));
+    assert_eval_eq("(do ((i 0 (+ i 1))) ((= i 3)))", Expression::Unspecified);
+    assert_eval_eq("(do () (#t 5))", einteger!(5));
+}
+
+#[test]
+fn test_do_fresh_bindings() {
+    assert_eval_eq(
+        r#"
+(define first #f)
+(do ((i 0 (+ i 1)))
+    ((= i 3))
+  (if (= i 0) (set! first (lambda () i))))
+(first)
+"#,
+        einteger!(0),
+    );
+}
+
+#[test]
+fn test_do_variables_do_not_leak() {
+    let _ = assert_eval_err("(do ((i 0 (+ i 1))) ((= i 3))) i");
+}
+
+#[test]
+fn test_nested_do() {
+    // the same shape as `each` in test/lib/example/grid.sr
+    assert_eval_eq(
+        r#"
+(define (each rows cols proc)
+  (do ((j 0 (+ j 1)))
+      ((= j rows))
+    (do ((k 0 (+ k 1)))
+        ((= k cols))
+      (proc j k))))
+(define count 0)
+(define total 0)
+(each 3 4 (lambda (j k)
+            (set! count (+ count 1))
+            (set! total (+ total (+ j k)))))
+(+ count total)
+"#,
+        einteger!(42),
+    );
+}
+
+#[test]
+fn test_do_long_loop() {
+    assert_eval_eq("(do ((i 0 (+ i 1))) ((= i 100000) i))", einteger!(100000));
+}
+
+#[test]
+fn test_do_bad_syntax() {
+    let _ = assert_eval_err("(do ((i)) (#t))");
+    let _ = assert_eval_err("(do ((i 0 1 2)) (#t))");
+    let _ = assert_eval_err("(do ((i 0)) #t)");
+}
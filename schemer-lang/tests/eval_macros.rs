@@ -0,0 +1,336 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, Integer, Number};
+use schemer_macros::{eboolean, einteger};
+
+#[test]
+fn test_simple_macro() {
+    assert_eval_eq(
+        r#"
+(define-syntax my-if
+  (syntax-rules ()
+    ((_ c t e) (cond (c t) (else e)))))
+(my-if #f 1 2)
+"#,
+        einteger!(2),
+    );
+}
+
+#[test]
+fn test_macro_value() {
+    assert_eval_repr(
+        "(define-syntax nothing (syntax-rules () ((_) #f))) nothing",
+        "#<macro:nothing>",
+    );
+}
+
+#[test]
+fn test_ellipsis() {
+    assert_eval_eq(
+        r#"
+(define-syntax my-or
+  (syntax-rules ()
+    ((_) #f)
+    ((_ e) e)
+    ((_ e r ...) (let ((t e)) (if t t (my-or r ...))))))
+(my-or #f #f 3)
+"#,
+        einteger!(3),
+    );
+    assert_eval_repr(
+        r#"
+(define-syntax my-list
+  (syntax-rules ()
+    ((_ x ...) '(x ... end))))
+(my-list a b c)
+"#,
+        "'(a b c end)",
+    );
+}
+
+#[test]
+fn test_ellipsis_followed_by_pattern() {
+    assert_eval_repr(
+        r#"
+(define-syntax last-of
+  (syntax-rules ()
+    ((_ x ... y) 'y)))
+(last-of a b c)
+"#,
+        "'c",
+    );
+}
+
+#[test]
+fn test_nested_ellipsis() {
+    assert_eval_eq(
+        r#"
+(define-syntax my-let*
+  (syntax-rules ()
+    ((_ () body ...) (let () body ...))
+    ((_ ((x v) rest ...) body ...) (let ((x v)) (my-let* (rest ...) body ...)))))
+(my-let* ((a 1) (b (+ a 1)) (c (+ b 1))) (+ a c))
+"#,
+        einteger!(4),
+    );
+    assert_eval_repr(
+        r#"
+(define-syntax flatten
+  (syntax-rules ()
+    ((_ (a b ...) ...) '(a ... b ... ...))))
+(flatten (1 2 3) (4 5) (6))
+"#,
+        "'(1 4 6 2 3 5)",
+    );
+}
+
+#[test]
+fn test_literals() {
+    assert_eval_eq(
+        r#"
+(define-syntax arrow
+  (syntax-rules (=>)
+    ((_ a => b) b)
+    ((_ a b) a)))
+(+ (arrow 1 => 2) (arrow 10 20))
+"#,
+        einteger!(12),
+    );
+}
+
+#[test]
+fn test_literals_match_by_binding() {
+    // a literal matches an identifier with the same binding, not just the same name.
+    let lit = r#"
+(define-syntax lit
+  (syntax-rules (=>)
+    ((_ a => b) 'literal)
+    ((_ a b c) 'other)))
+"#;
+    assert_eval_repr(&format!("{} (lit 1 => 2)", lit), "'literal");
+    assert_eval_repr(&format!("{} (let ((=> 1)) (lit 1 => 2))", lit), "'other");
+    assert_eval_repr(
+        r#"
+(let ((=> 1))
+  (let-syntax ((lit (syntax-rules (=>) ((_ a => b) 'literal) ((_ a b c) 'other))))
+    (lit 1 => 2)))
+"#,
+        "'literal",
+    );
+}
+
+#[test]
+fn test_underscore() {
+    assert_eval_eq(
+        "(define-syntax second (syntax-rules () ((_ _ b _) b))) (second 1 2 3)",
+        einteger!(2),
+    );
+}
+
+#[test]
+fn test_dotted_pattern() {
+    assert_eval_repr(
+        r#"
+(define-syntax rest-of
+  (syntax-rules ()
+    ((_ a . b) 'b)))
+(rest-of 1 2 3)
+"#,
+        "'(2 3)",
+    );
+}
+
+#[test]
+fn test_vector_pattern() {
+    assert_eval_eq(
+        r#"
+(define-syntax vector-sum
+  (syntax-rules ()
+    ((_ #(a b ...)) (+ a (vector-sum #(b ...))))
+    ((_ #()) 0)))
+(vector-sum #(1 2 3))
+"#,
+        einteger!(6),
+    );
+}
+
+#[test]
+fn test_custom_ellipsis() {
+    assert_eval_repr(
+        r#"
+(define-syntax my-quote-all
+  (syntax-rules ::: ()
+    ((_ x :::) '(x ::: ...))))
+(my-quote-all a b)
+"#,
+        "'(a b ...)",
+    );
+}
+
+#[test]
+fn test_escaped_ellipsis() {
+    assert_eval_repr(
+        r#"
+(define-syntax be-like-begin
+  (syntax-rules ()
+    ((be-like-begin name)
+     (define-syntax name
+       (syntax-rules ()
+         ((name expr (... ...))
+          (begin expr (... ...))))))))
+(be-like-begin sequence)
+(sequence 1 2 'three)
+"#,
+        "'three",
+    );
+}
+
+#[test]
+fn test_hygiene_introduced_binding() {
+    assert_eval_eq(
+        r#"
+(define-syntax swap!
+  (syntax-rules ()
+    ((_ a b) (let ((tmp a)) (set! a b) (set! b tmp)))))
+(define tmp 1)
+(define other 2)
+(swap! tmp other)
+(- tmp other)
+"#,
+        einteger!(1),
+    );
+}
+
+#[test]
+fn test_hygiene_repeated_expansion() {
+    // each expansion introduces its own alias for `tmp`.
+    assert_eval_eq(
+        r#"
+(define-syntax swap!
+  (syntax-rules ()
+    ((_ a b) (let ((tmp a)) (set! a b) (set! b tmp)))))
+(define tmp 1)
+(define other 2)
+(define (swap-n n) (if (= n 0) #t (begin (swap! tmp other) (swap-n (- n 1)))))
+(swap-n 3)
+(- tmp other)
+"#,
+        einteger!(1),
+    );
+}
+
+#[test]
+fn test_hygiene_free_identifier() {
+    // the template's `if` refers to the binding where the macro was defined.
+    assert_eval_eq(
+        r#"
+(define-syntax my-unless
+  (syntax-rules ()
+    ((_ c e) (if c #f e))))
+(let ((if (lambda (a b c) 'shadowed)))
+  (my-unless #f 1))
+"#,
+        einteger!(1),
+    );
+    assert_eval_eq(
+        r#"
+(define x 10)
+(define-syntax get-x (syntax-rules () ((_) x)))
+(let ((x 20)) (get-x))
+"#,
+        einteger!(10),
+    );
+}
+
+#[test]
+fn test_macro_assigns_free_variable() {
+    assert_eval_eq(
+        r#"
+(define counter 0)
+(define-syntax bump! (syntax-rules () ((_) (set! counter (+ counter 1)))))
+(bump!)
+(bump!)
+counter
+"#,
+        einteger!(2),
+    );
+}
+
+#[test]
+fn test_let_syntax() {
+    assert_eval_eq(
+        r#"
+(let-syntax ((double (syntax-rules () ((_ e) (+ e e)))))
+  (double 21))
+"#,
+        einteger!(42),
+    );
+    // the `x` in the template refers to the binding visible where `m` was defined.
+    assert_eval_repr(
+        r#"
+(let ((x 'outer))
+  (let-syntax ((m (syntax-rules () ((m) x))))
+    (let ((x 'inner))
+      (m))))
+"#,
+        "'outer",
+    );
+}
+
+#[test]
+fn test_letrec_syntax() {
+    assert_eval_eq(
+        r#"
+(letrec-syntax
+    ((my-or (syntax-rules ()
+              ((_) #f)
+              ((_ e) e)
+              ((_ e1 e2 ...)
+               (let ((temp e1))
+                 (if temp temp (my-or e2 ...)))))))
+  (let ((x #f)
+        (y 7)
+        (temp 8))
+    (my-or x
+           (let ((temp 1)) #f)
+           temp
+           y)))
+"#,
+        einteger!(8),
+    );
+}
+
+#[test]
+fn test_macro_in_loop() {
+    assert_eval_eq(
+        r#"
+(define-syntax inc (syntax-rules () ((_ x) (+ x 1))))
+(define (loop n) (if (= n 0) 'done (loop (- (inc n) 2))))
+(loop 10000)
+#t
+"#,
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_no_matching_rule() {
+    let _ = assert_eval_err("(define-syntax one (syntax-rules () ((_ a) a))) (one 1 2)");
+}
+
+#[test]
+fn test_syntax_error() {
+    let error = assert_eval_err(
+        r#"
+(define-syntax must-be-pair
+  (syntax-rules ()
+    ((_ (a . b)) 'ok)
+    ((_ other) (syntax-error "expected a pair, not" other))))
+(must-be-pair 1)
+"#,
+    );
+    assert!(matches!(error.kind(), ErrorKind::SyntaxError { .. }));
+    assert_eq!(error.to_string(), "Syntax error: expected a pair, not 1");
+}
@@ -0,0 +1,72 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Integer, Number, SchemeRepr};
+use schemer_macros::einteger;
+
+#[test]
+fn test_quasiquote_without_unquote() {
+    assert_eval_repr("`(a b c)", "'(a b c)");
+    assert_eval_repr("`a", "'a");
+    assert_eq!(
+        common::eval_str("`()").unwrap(),
+        common::eval_str("'()").unwrap()
+    );
+}
+
+#[test]
+fn test_unquote() {
+    assert_eval_repr("`(1 ,(+ 1 1) 3)", "'(1 2 3)");
+    assert_eval_repr("(define x 'b) `(a ,x c)", "'(a b c)");
+    assert_eval_eq("`,(+ 1 2)", einteger!(3));
+}
+
+#[test]
+fn test_unquote_splicing() {
+    assert_eval_repr("(define xs '(2 3)) `(1 ,@xs 4)", "'(1 2 3 4)");
+    assert_eval_repr("`(1 ,@'() 2)", "'(1 2)");
+    assert_eval_repr("(define xs '(2 3)) `(,@xs ,@xs)", "'(2 3 2 3)");
+}
+
+#[test]
+fn test_vector_template() {
+    assert_eval_repr("(define xs '(2 3)) `#(1 ,@xs ,(+ 2 2))", "'#(1 2 3 4)");
+}
+
+#[test]
+fn test_dotted_tail() {
+    assert_eval_repr("`(1 . ,(+ 1 1))", "'(1 . 2)");
+    assert_eval_repr("(define xs '(2 3)) `(1 . ,xs)", "'(1 2 3)");
+    assert_eval_repr("`(1 unquote (+ 1 1))", "'(1 . 2)");
+    assert_eval_repr("`(,(+ 0 1) . b)", "'(1 . b)");
+}
+
+#[test]
+fn test_long_form() {
+    assert_eval_repr("(quasiquote (1 (unquote (+ 1 1))))", "'(1 2)");
+    assert_eval_repr(
+        "(define xs '(2 3)) (quasiquote (1 (unquote-splicing xs)))",
+        "'(1 2 3)",
+    );
+}
+
+#[test]
+fn test_nested_quasiquote() {
+    assert_eval_repr("`(a `(b ,(c ,(+ 1 1))))", "'(a `(b ,(c 2)))");
+    assert_eval_repr("`(a `(b ,,(+ 1 1)))", "'(a `(b ,2))");
+    assert_eval_repr("`(1 '(,(+ 1 1)))", "'(1 '(2))");
+}
+
+#[test]
+fn test_procedure_in_template() {
+    let result = common::eval_str("(define (add a b) (+ a b)) `(,add 1 2)").unwrap();
+    assert!(result.to_repr_string().starts_with("'(#<procedure"));
+}
+
+#[test]
+fn test_unquote_outside_quasiquote() {
+    let _ = assert_eval_err(",(+ 1 1)");
+    let _ = assert_eval_err("(unquote 1)");
+    let _ = assert_eval_err("`,@'(1 2)");
+    let _ = assert_eval_err("`(1 ,@2)");
+}
@@ -0,0 +1,98 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_repr};
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, Integer, Number};
+use schemer_macros::{eboolean, einteger};
+
+const ITERATIONS: &str = "100000";
+
+#[test]
+fn test_tail_recursive_loop() {
+    assert_eval_repr(
+        &format!(
+            r#"
+(define (count-down n)
+  (if (= n 0)
+      'done
+      (count-down (- n 1))))
+(count-down {})
+"#,
+            ITERATIONS
+        ),
+        "'done",
+    );
+}
+
+#[test]
+fn test_tail_recursive_accumulator() {
+    assert_eval_eq(
+        &format!(
+            r#"
+(define (sum n total)
+  (if (= n 0)
+      total
+      (sum (- n 1) (+ total 1))))
+(sum {} 0)
+"#,
+            ITERATIONS
+        ),
+        einteger!(100000),
+    );
+}
+
+#[test]
+fn test_tail_call_in_begin() {
+    assert_eval_eq(
+        &format!(
+            r#"
+(define counter 0)
+(define (loop n)
+  (begin
+    (set! counter (+ counter 1))
+    (if (= n 0) counter (loop (- n 1)))))
+(loop {})
+"#,
+            ITERATIONS
+        ),
+        einteger!(100001),
+    );
+}
+
+#[test]
+fn test_tail_call_in_lambda_body() {
+    assert_eval_eq(
+        &format!(
+            r#"
+(define loop
+  (lambda (n)
+    (define next (- n 1))
+    (if (< n 1) #t (loop next))))
+(loop {})
+"#,
+            ITERATIONS
+        ),
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_mutual_tail_recursion() {
+    assert_eval_eq(
+        &format!(
+            r#"
+(define (my-even? n) (if (= n 0) #t (my-odd? (- n 1))))
+(define (my-odd? n) (if (= n 0) #f (my-even? (- n 1))))
+(my-even? {})
+"#,
+            ITERATIONS
+        ),
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_tail_call_result_is_resolved() {
+    assert_eval_repr("((lambda (x) (if x 'yes 'no)) #f)", "'no");
+    assert_eval_repr("(begin 1 2 'three)", "'three");
+    assert_eval_eq("(begin)", Expression::Unspecified);
+}
@@ -0,0 +1,107 @@
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::ports::current_output_port;
+use schemer_lang::eval::Port;
+use std::io::Cursor;
+
+fn text_input(s: &str) -> Port {
+    Port::new_textual_input("test", Cursor::new(s.as_bytes().to_vec()))
+}
+
+#[test]
+fn test_read_and_peek_char() {
+    let port = text_input("aλ");
+    assert_eq!(port.peek_char().unwrap(), Some('a'));
+    assert_eq!(port.read_char().unwrap(), Some('a'));
+    assert_eq!(port.peek_char().unwrap(), Some('λ'));
+    assert_eq!(port.read_char().unwrap(), Some('λ'));
+    assert_eq!(port.read_char().unwrap(), None);
+    assert_eq!(port.peek_char().unwrap(), None);
+}
+
+#[test]
+fn test_read_line() {
+    let port = text_input("one\r\ntwo\n\nthree");
+    assert_eq!(port.read_line().unwrap(), Some("one".to_string()));
+    assert_eq!(port.read_line().unwrap(), Some("two".to_string()));
+    assert_eq!(port.read_line().unwrap(), Some(String::new()));
+    assert_eq!(port.read_line().unwrap(), Some("three".to_string()));
+    assert_eq!(port.read_line().unwrap(), None);
+}
+
+#[test]
+fn test_read_string() {
+    let port = text_input("héllo");
+    assert_eq!(port.read_string(2).unwrap(), Some("hé".to_string()));
+    assert_eq!(port.read_string(10).unwrap(), Some("llo".to_string()));
+    assert_eq!(port.read_string(1).unwrap(), None);
+}
+
+#[test]
+fn test_read_bytes() {
+    let port = Port::new_binary_input("test", Cursor::new(vec![1, 2, 3]));
+    assert!(port.is_binary());
+    assert_eq!(port.peek_u8().unwrap(), Some(1));
+    assert_eq!(port.read_u8().unwrap(), Some(1));
+    assert_eq!(port.read_bytes(5).unwrap(), Some(vec![2, 3]));
+    assert_eq!(port.read_u8().unwrap(), None);
+    assert_eq!(port.read_bytes(1).unwrap(), None);
+    assert!(port.is_u8_ready().unwrap());
+}
+
+#[test]
+fn test_invalid_utf8() {
+    let port = Port::new_textual_input("test", Cursor::new(vec![b'a', 0xFF]));
+    assert_eq!(port.read_char().unwrap(), Some('a'));
+    let error = port.read_char().unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::Read));
+}
+
+#[test]
+fn test_port_kinds() {
+    let port = text_input("a");
+    assert!(port.read_u8().is_err());
+    assert!(port.write_str("a").is_err());
+    let error = Port::new_binary_input("test", Cursor::new(vec![]))
+        .read_char()
+        .unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_close_port() {
+    let port = text_input("abc");
+    assert!(port.is_open());
+    port.close().unwrap();
+    assert!(!port.is_open());
+    port.close().unwrap();
+    let error = port.read_char().unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::ClosedPort { .. }));
+}
+
+#[test]
+fn test_current_ports_are_shared() {
+    let port = current_output_port();
+    assert!(port.is_output() && port.is_textual());
+    assert!(port.ptr_eq(&current_output_port()));
+    assert!(!port.ptr_eq(&Port::stdout()));
+}
+
+#[test]
+fn test_in_memory_ports() {
+    let port = Port::new_output_string();
+    port.write_str("one ").unwrap();
+    port.write_char('λ').unwrap();
+    assert_eq!(port.output_string().unwrap(), "one λ");
+    assert!(port.output_bytes().is_err());
+
+    let port = Port::new_output_bytes();
+    port.write_bytes(&[1, 2]).unwrap();
+    assert_eq!(port.output_bytes().unwrap(), vec![1, 2]);
+
+    let port = Port::new_input_string("ab");
+    assert_eq!(port.read_string(5).unwrap(), Some("ab".to_string()));
+    assert!(port.output_string().is_err());
+
+    let port = Port::new_input_bytes(vec![9]);
+    assert_eq!(port.read_u8().unwrap(), Some(9));
+}
@@ -32,7 +32,9 @@ use crate::schemer::file::{schemer_file_exports, schemer_file_name};
 use crate::schemer::lists::{schemer_lists_exports, schemer_lists_name};
 use crate::schemer::load::{schemer_load_exports, schemer_load_name};
 use crate::schemer::repl::{schemer_repl_exports, schemer_repl_name};
+use crate::schemer::symbols::{schemer_symbols_exports, schemer_symbols_name};
 use crate::srfi::srfi_112::{srfi_112_exports, srfi_112_name};
+use crate::srfi::srfi_8::{srfi_8_exports, srfi_8_name};
 use schemer_lang::error::{Error, ErrorKind};
 use schemer_lang::eval::environment::Exports;
 use schemer_lang::eval::{Environment, Expression};
@@ -138,10 +140,10 @@ pub(super) fn import(
 fn import_set(argument: &Pair, env: &mut MutableRef<Environment>) -> Result<Expression, Error> {
     if let Datum::Symbol(id) = argument.car().deref() {
         let imports = match id.deref() {
-            FORM_PART_ONLY => import_only(argument.cdr())?,
-            FORM_PART_EXCEPT => import_except(argument.cdr())?,
-            FORM_PART_PREFIX => import_prefix(argument.cdr())?,
-            FORM_PART_RENAME => import_rename(argument.cdr())?,
+            FORM_PART_ONLY => import_only(&argument.cdr())?,
+            FORM_PART_EXCEPT => import_except(&argument.cdr())?,
+            FORM_PART_PREFIX => import_prefix(&argument.cdr())?,
+            FORM_PART_RENAME => import_rename(&argument.cdr())?,
             _ => import_all(argument)?,
         };
         env.borrow_mut().import(imports)?;
@@ -160,7 +162,7 @@ fn list_to_library_name(name: &Pair) -> Result<LibraryName, Error> {
         .iter()
         .map(|d| match d.deref() {
             Datum::Symbol(id) => Ok(LibraryNamePart::Identifier(id.clone())),
-            Datum::Number(Number::Integer(n)) => Ok(LibraryNamePart::Number(*n)),
+            Datum::Number(Number::Integer(n)) => Ok(LibraryNamePart::Number(n.clone())),
             _ => unexpected_type!(
                 format!("(or {} {})", TYPE_NAME_SYMBOL, TYPE_NAME_INTEGER),
                 d
@@ -279,6 +281,10 @@ fn reserved_libraries() -> RwLock<HashMap<String, fn() -> Exports>> {
                 scheme_write_exports as fn() -> Exports,
             ),
             // ----------------------------------------------------------------------------------------
+            (
+                srfi_8_name().to_repr_string(),
+                srfi_8_exports as fn() -> Exports,
+            ),
             (
                 srfi_112_name().to_repr_string(),
                 srfi_112_exports as fn() -> Exports,
@@ -316,6 +322,10 @@ fn reserved_libraries() -> RwLock<HashMap<String, fn() -> Exports>> {
                 schemer_repl_name().to_repr_string(),
                 schemer_repl_exports as fn() -> Exports,
             ),
+            (
+                schemer_symbols_name().to_repr_string(),
+                schemer_symbols_exports as fn() -> Exports,
+            ),
         ]
         .iter()
         .cloned()
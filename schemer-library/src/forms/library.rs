@@ -105,10 +105,10 @@ impl LibraryName {
         ]))
     }
 
-    pub fn srfi(id: Integer) -> Result<Self, Error> {
+    pub fn srfi(id: u32) -> Result<Self, Error> {
         Ok(Self::from(vec![
             LibraryNamePart::srfi(),
-            LibraryNamePart::from(id),
+            LibraryNamePart::from(Integer::from(id)),
         ]))
     }
 
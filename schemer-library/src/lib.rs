@@ -36,7 +36,7 @@ pub enum PresetEnvironmentKind {
     SchemeBase,
 }
 
-pub const DEFAULT_SCHEME_ENVIRONMENT_VERSION: Integer = 5;
+pub const DEFAULT_SCHEME_ENVIRONMENT_VERSION: u32 = 5;
 
 pub const INTERACTION_ENVIRONMENT_NAME: &str = "*interaction*";
 pub const SCHEME_BASE_ENVIRONMENT_NAME: &str = "*scheme-base*";
@@ -54,9 +54,9 @@ pub fn make_preset_environment(
 ) -> Result<MutableRef<Environment>, Error> {
     match preset {
         PresetEnvironmentKind::Interaction => {
-            let base = make_preset_environment(PresetEnvironmentKind::Report(
+            let base = make_preset_environment(PresetEnvironmentKind::Report(Integer::from(
                 DEFAULT_SCHEME_ENVIRONMENT_VERSION,
-            ))?;
+            )))?;
             base.borrow_mut().make_immutable();
             let interaction = Environment::new_child_named(base, INTERACTION_ENVIRONMENT_NAME);
             interaction.borrow_mut().import(schemer_repl_exports())?;
@@ -68,7 +68,7 @@ pub fn make_preset_environment(
             Ok(interaction)
         }
         PresetEnvironmentKind::Null(v) => {
-            if v == 5 {
+            if v == Integer::from(DEFAULT_SCHEME_ENVIRONMENT_VERSION) {
                 let top = Environment::top();
                 top.borrow_mut().import(standard_form_exports())?;
                 top.borrow_mut().make_immutable();
@@ -82,7 +82,7 @@ pub fn make_preset_environment(
             }
         }
         PresetEnvironmentKind::Report(v) => {
-            let base = make_preset_environment(PresetEnvironmentKind::Null(v))?;
+            let base = make_preset_environment(PresetEnvironmentKind::Null(v.clone()))?;
             base.borrow_mut().make_immutable();
             let report = Environment::new_child_named(base, &format!("*r{}rs*", v));
             report.borrow_mut().import(scheme_r5rs_exports())?;
@@ -90,9 +90,9 @@ pub fn make_preset_environment(
             Ok(report)
         }
         PresetEnvironmentKind::SchemeBase => {
-            let base = make_preset_environment(PresetEnvironmentKind::Null(
+            let base = make_preset_environment(PresetEnvironmentKind::Null(Integer::from(
                 DEFAULT_SCHEME_ENVIRONMENT_VERSION,
-            ))?;
+            )))?;
             base.borrow_mut().make_immutable();
             let scheme_base = Environment::new_child_named(base, SCHEME_BASE_ENVIRONMENT_NAME);
             scheme_base.borrow_mut().import(scheme_base_exports())?;
@@ -0,0 +1,72 @@
+/*!
+Booleans, from R7RS §6.3.
+
+# Example
+
+```scheme
+(not 3)                ;; => #f
+(not '())              ;; => #f
+(not #f)               ;; => #t
+(boolean=? #t #t #t)   ;; => #t
+```
+
+*/
+
+use schemer_lang::error::{Error, ErrorKind};
+use schemer_lang::eval::environment::Exports;
+use schemer_lang::eval::{Environment, Expression, Procedure};
+use schemer_lang::types::booleans::TYPE_NAME_BOOLEAN;
+use schemer_lang::types::{Boolean, Identifier, MutableRef, SchemeValue};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn scheme_base_boolean_exports() -> Exports {
+    let mut exports = Exports::default();
+
+    export_builtin!(exports, "not" => not "obj");
+    export_builtin!(exports, "boolean=?" => boolean_equal "boolean1" "boolean2" ; "boolean");
+
+    exports
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn not(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    Ok(eboolean!(arguments[0].is_false()))
+}
+
+fn boolean_equal(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let booleans = arguments
+        .iter()
+        .map(|argument| match argument {
+            Expression::Boolean(v) => Ok(**v),
+            _ => unexpected_type!(TYPE_NAME_BOOLEAN, argument),
+        })
+        .collect::<Result<Vec<bool>, Error>>()?;
+    Ok(eboolean!(booleans
+        .windows(2)
+        .all(|pair| pair[0] == pair[1])))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
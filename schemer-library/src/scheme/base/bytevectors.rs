@@ -0,0 +1,233 @@
+/*!
+Bytevectors, from R7RS §6.9, including the UTF-8 conversions to and from strings.
+
+As with strings and vectors a bytevector is shared rather than copied, so a mutation made by
+`bytevector-u8-set!` or `bytevector-copy!` is visible through every reference to the same
+bytevector.
+
+# Example
+
+```scheme
+(define bv (make-bytevector 3 0))
+(bytevector-u8-set! bv 1 255)
+bv                               ;; => #u8(0 255 0)
+(bytevector-copy #u8(1 2 3 4) 2) ;; => #u8(3 4)
+(utf8->string #u8(65))           ;; => "A"
+(string->utf8 "λ")               ;; => #u8(206 187)
+```
+
+*/
+
+use crate::scheme::base::numbers::{
+    element_index_out_of_range, index, index_out_of_range, index_range,
+};
+use crate::scheme::base::strings::{chars_in_range, new_string_value};
+use schemer_lang::error::{Error, ErrorKind};
+use schemer_lang::eval::environment::Exports;
+use schemer_lang::eval::{Environment, Expression, Procedure};
+use schemer_lang::types::strings::{TYPE_NAME_BYTE, TYPE_NAME_BYTE_VECTOR};
+use schemer_lang::types::{
+    ByteVector, Identifier, Integer, MutableRef, Number, SchemeRepr, SchemeValue,
+};
+use std::convert::TryFrom;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn scheme_base_byte_vector_exports() -> Exports {
+    let mut exports = Exports::default();
+
+    export_builtin!(exports, "bytevector" => new_byte_vector ; "byte");
+    export_builtin!(exports, "make-bytevector" => make_byte_vector "k" ; "byte");
+    export_builtin!(exports, "bytevector-length" => byte_vector_length "bytevector");
+    export_builtin!(exports, "bytevector-u8-ref" => byte_vector_u8_ref "bytevector" "k");
+    export_builtin!(exports, "bytevector-u8-set!" => byte_vector_u8_set "bytevector" "k" "byte");
+    export_builtin!(exports, "bytevector-copy" => byte_vector_copy "bytevector" ; "start-end");
+    export_builtin!(exports, "bytevector-copy!" => byte_vector_copy_into "to" "at" "from" ; "start-end");
+    export_builtin!(exports, "bytevector-append" => byte_vector_append ; "bytevector");
+    export_builtin!(exports, "utf8->string" => utf8_to_string "bytevector" ; "start-end");
+    export_builtin!(exports, "string->utf8" => string_to_utf8 "string" ; "start-end");
+
+    exports
+}
+
+pub fn byte_vector_length(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Number(Number::from(Integer::from(
+        byte_vector(&arguments[0])?.len(),
+    ))))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn new_byte_vector(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_byte_vector_value(
+        arguments
+            .iter()
+            .map(byte)
+            .collect::<Result<Vec<u8>, Error>>()?,
+    ))
+}
+
+fn make_byte_vector(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let k = index(&arguments[0])?;
+    let fill = match arguments.get(1) {
+        Some(fill) => byte(fill)?,
+        None => 0,
+    };
+    Ok(new_byte_vector_value(vec![fill; k]))
+}
+
+fn byte_vector_u8_ref(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let bv = byte_vector(&arguments[0])?;
+    let k = index(&arguments[1])?;
+    let value = bv
+        .as_bytes()
+        .get(k)
+        .copied()
+        .ok_or_else(|| element_index_out_of_range(k, bv.len()))?;
+    Ok(Expression::Number(Number::from(Integer::from(value))))
+}
+
+fn byte_vector_u8_set(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let bv = byte_vector(&arguments[0])?;
+    let k = index(&arguments[1])?;
+    let value = byte(&arguments[2])?;
+    let length = bv.len();
+    match bv.as_bytes_mut().get_mut(k) {
+        Some(element) => *element = value,
+        None => return Err(element_index_out_of_range(k, length)),
+    }
+    Ok(Expression::Unspecified)
+}
+
+fn byte_vector_copy(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_byte_vector_value(bytes_in_range(&arguments, 0)?))
+}
+
+fn byte_vector_copy_into(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let to = byte_vector(&arguments[0])?;
+    let at = index(&arguments[1])?;
+    // collected first, as `from` may be the same bytevector as `to`.
+    let from = bytes_in_range(&arguments, 2)?;
+    let length = to.len();
+    if at + from.len() > length {
+        return Err(index_out_of_range(at + from.len(), length));
+    }
+    to.as_bytes_mut()[at..at + from.len()].copy_from_slice(&from);
+    Ok(Expression::Unspecified)
+}
+
+fn byte_vector_append(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let mut result: Vec<u8> = Vec::default();
+    for argument in &arguments {
+        result.extend_from_slice(&byte_vector(argument)?.as_bytes());
+    }
+    Ok(new_byte_vector_value(result))
+}
+
+fn utf8_to_string(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let bytes = bytes_in_range(&arguments, 0)?;
+    match String::from_utf8(bytes) {
+        Ok(s) => Ok(new_string_value(s)),
+        Err(e) => Err(Error::from(ErrorKind::UnexpectedValue {
+            type_name: TYPE_NAME_BYTE_VECTOR.to_string(),
+            expected: "a valid UTF-8 encoding".to_string(),
+            actual: ByteVector::from(e.into_bytes()).to_repr_string(),
+        })),
+    }
+}
+
+fn string_to_utf8(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_byte_vector_value(
+        chars_in_range(&arguments, 0)?
+            .collect::<String>()
+            .into_bytes(),
+    ))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+pub(crate) fn byte_vector(value: &Expression) -> Result<&ByteVector, Error> {
+    match value {
+        Expression::ByteVector(bv) => Ok(bv),
+        _ => unexpected_type!(TYPE_NAME_BYTE_VECTOR, value),
+    }
+}
+
+///
+/// An exact integer between 0 and 255.
+///
+pub(crate) fn byte(value: &Expression) -> Result<u8, Error> {
+    let k = index(value)?;
+    u8::try_from(k).map_err(|_| {
+        Error::from(ErrorKind::UnexpectedValue {
+            type_name: TYPE_NAME_BYTE.to_string(),
+            expected: "an exact integer between 0 and 255".to_string(),
+            actual: k.to_string(),
+        })
+    })
+}
+
+#[inline]
+pub(crate) fn new_byte_vector_value(bytes: Vec<u8>) -> Expression {
+    Expression::ByteVector(ByteVector::from(bytes))
+}
+
+///
+/// The bytes of the bytevector `arguments[at]` in the optional range that follows it.
+///
+fn bytes_in_range(arguments: &[Expression], at: usize) -> Result<Vec<u8>, Error> {
+    let bv = byte_vector(&arguments[at])?;
+    let (start, end) = index_range(arguments, at + 1, bv.len())?;
+    let bytes = bv.as_bytes()[start..end].to_vec();
+    Ok(bytes)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
@@ -0,0 +1,254 @@
+/*!
+Control features, from R7RS §6.10; `apply`, the mapping procedures, multiple values,
+continuations and `dynamic-wind`.
+
+The mapping procedures accept one or more sequences and stop at the end of the shortest, so that
+for `map` and `for-each` only one of the lists need be finite. The continuations captured by
+`call/cc` are escaping only, see `schemer_lang::eval::continuations` for details.
+
+*/
+
+use crate::scheme::base::lists::{list_datum, list_elements};
+use crate::scheme::base::vectors::vector_elements;
+use schemer_lang::error::{Error, ErrorKind};
+use schemer_lang::eval::continuations;
+use schemer_lang::eval::environment::Exports;
+use schemer_lang::eval::{Environment, Expression, Procedure};
+use schemer_lang::read::datum::Datum;
+use schemer_lang::types::chars::TYPE_NAME_CHAR;
+use schemer_lang::types::strings::TYPE_NAME_STRING;
+use schemer_lang::types::{Identifier, MutableRef, Ref, SchemeString, SchemeValue, Vector};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn scheme_base_control_exports() -> Exports {
+    let mut exports = Exports::default();
+
+    export_builtin!(exports, "apply" => apply "proc" "args" ; "arg");
+    export_builtin!(exports, "map" => map "proc" "list1" ; "list");
+    export_builtin!(exports, "for-each" => for_each "proc" "list1" ; "list");
+    export_builtin!(exports, "vector-map" => vector_map "proc" "vector1" ; "vector");
+    export_builtin!(exports, "vector-for-each" => vector_for_each "proc" "vector1" ; "vector");
+    export_builtin!(exports, "string-map" => string_map "proc" "string1" ; "string");
+    export_builtin!(exports, "string-for-each" => string_for_each "proc" "string1" ; "string");
+
+    export_builtin!(exports, "values" => values ; "obj");
+    export_builtin!(exports, "call-with-values" => call_with_values "producer" "consumer");
+    export_builtin!(exports, "call-with-current-continuation" => call_with_current_continuation "proc");
+    export_builtin!(exports, "call/cc" => call_with_current_continuation "proc");
+    export_builtin!(exports, "dynamic-wind" => dynamic_wind "before" "thunk" "after");
+
+    exports
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn apply(
+    mut arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let procedure = arguments.remove(0);
+    let last = arguments.pop().unwrap();
+    arguments.extend(
+        list_elements(&last)?
+            .into_iter()
+            .map(Expression::from_datum),
+    );
+    procedure.call_tail(arguments, environment)
+}
+
+fn map(
+    mut arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let procedure = arguments.remove(0);
+    Ok(Expression::from_list(call_for_each(
+        &procedure,
+        list_rows(&arguments)?,
+        environment,
+    )?))
+}
+
+fn for_each(
+    mut arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let procedure = arguments.remove(0);
+    let _ = call_for_each(&procedure, list_rows(&arguments)?, environment)?;
+    Ok(Expression::Unspecified)
+}
+
+fn vector_map(
+    mut arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let procedure = arguments.remove(0);
+    let columns = arguments
+        .iter()
+        .map(vector_elements)
+        .collect::<Result<Vec<Vec<Expression>>, Error>>()?;
+    let results = call_for_each(&procedure, rows(columns), environment)?;
+    Ok(Expression::Vector(Vector::from(
+        results
+            .into_iter()
+            .map(Expression::into_datum)
+            .collect::<Vec<Ref<Datum>>>(),
+    )))
+}
+
+fn vector_for_each(
+    mut arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let procedure = arguments.remove(0);
+    let columns = arguments
+        .iter()
+        .map(vector_elements)
+        .collect::<Result<Vec<Vec<Expression>>, Error>>()?;
+    let _ = call_for_each(&procedure, rows(columns), environment)?;
+    Ok(Expression::Unspecified)
+}
+
+fn string_map(
+    mut arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let procedure = arguments.remove(0);
+    let columns = arguments
+        .iter()
+        .map(string_elements)
+        .collect::<Result<Vec<Vec<Expression>>, Error>>()?;
+    let results = call_for_each(&procedure, rows(columns), environment)?;
+    let mut string = String::with_capacity(results.len());
+    for result in results {
+        match result {
+            Expression::Character(c) => string.push(*c),
+            value => unexpected_type!(=> TYPE_NAME_CHAR, value),
+        }
+    }
+    Ok(Expression::String(SchemeString::from(string)))
+}
+
+fn string_for_each(
+    mut arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let procedure = arguments.remove(0);
+    let columns = arguments
+        .iter()
+        .map(string_elements)
+        .collect::<Result<Vec<Vec<Expression>>, Error>>()?;
+    let _ = call_for_each(&procedure, rows(columns), environment)?;
+    Ok(Expression::Unspecified)
+}
+
+fn values(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::from_values(arguments))
+}
+
+fn call_with_values(
+    arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let values = arguments[0]
+        .call(Vec::default(), environment)?
+        .into_values();
+    arguments[1].call_tail(values, environment)
+}
+
+fn call_with_current_continuation(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    continuations::call_with_current_continuation(&arguments[0])
+}
+
+fn dynamic_wind(
+    arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    continuations::dynamic_wind(&arguments[0], &arguments[1], &arguments[2], environment)
+}
+
+// ------------------------------------------------------------------------------------------------
+
+fn call_for_each(
+    procedure: &Expression,
+    rows: Vec<Vec<Expression>>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Vec<Expression>, Error> {
+    rows.into_iter()
+        .map(|row| procedure.call(row, environment))
+        .collect()
+}
+
+///
+/// The arguments for each call across `lists`; the lists are walked together so that only the
+/// shortest need be finite.
+///
+fn list_rows(lists: &[Expression]) -> Result<Vec<Vec<Expression>>, Error> {
+    let mut current = lists
+        .iter()
+        .map(list_datum)
+        .collect::<Result<Vec<Ref<Datum>>, Error>>()?;
+    let mut rows = Vec::default();
+    while current.iter().all(|datum| datum.is_pair()) {
+        let pairs: Vec<_> = current
+            .iter()
+            .map(|datum| datum.as_pair().unwrap())
+            .collect();
+        let row = pairs
+            .iter()
+            .map(|pair| Expression::from_datum(pair.car()))
+            .collect();
+        let next = pairs.iter().map(|pair| pair.cdr()).collect();
+        rows.push(row);
+        current = next;
+    }
+    Ok(rows)
+}
+
+///
+/// The arguments for each call across the finite sequences `columns`, up to the length of the
+/// shortest.
+///
+fn rows(columns: Vec<Vec<Expression>>) -> Vec<Vec<Expression>> {
+    let length = columns.iter().map(Vec::len).min().unwrap_or_default();
+    (0..length)
+        .map(|i| columns.iter().map(|column| column[i].clone()).collect())
+        .collect()
+}
+
+fn string_elements(value: &Expression) -> Result<Vec<Expression>, Error> {
+    match value {
+        Expression::String(string) => Ok(string
+            .as_string()
+            .chars()
+            .map(|c| Expression::Character(c.into()))
+            .collect()),
+        _ => unexpected_type!(TYPE_NAME_STRING, value),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
@@ -0,0 +1,182 @@
+/*!
+The equivalence predicates `eq?`, `eqv?`, and `equal?` from R7RS §6.1.
+
+Pairs, strings, vectors, bytevectors, and procedures have identity; they are only `eqv?` if
+they are the same object. In this implementation `eq?` is the same as `eqv?`, which R7RS allows,
+as numbers and characters are compared by value. `equal?` compares pairs, vectors, strings, and
+bytevectors by their contents and terminates on circular data.
+
+# Example
+
+```scheme
+(define x (list 'a))
+(eq? x x)                          ;; => #t
+(eq? (list 'a) (list 'a))          ;; => #f
+(equal? (list 'a) (list 'a))       ;; => #t
+(eqv? 2 (inexact 2))              ;; => #f
+(equal? (make-vector 2 'a) #(a a)) ;; => #t
+```
+
+*/
+
+use schemer_lang::error::Error;
+use schemer_lang::eval::environment::Exports;
+use schemer_lang::eval::{Environment, Expression, Procedure};
+use schemer_lang::read::datum::Datum;
+use schemer_lang::types::{Boolean, Identifier, MutableRef, Number, Ref};
+use std::collections::HashSet;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The pairs of compound data, by address, already being compared by `equal?`.
+///
+type Visited = HashSet<(*const Datum, *const Datum)>;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn scheme_base_equivalence_exports() -> Exports {
+    let mut exports = Exports::default();
+
+    export_builtin!(exports, "eq?" => eq "obj1" "obj2");
+    export_builtin!(exports, "eqv?" => eqv "obj1" "obj2");
+    export_builtin!(exports, "equal?" => equal "obj1" "obj2");
+
+    exports
+}
+
+///
+/// Whether `lhs` and `rhs` are the same object, see `eqv?`.
+///
+pub fn is_eqv(lhs: &Expression, rhs: &Expression) -> bool {
+    datum_eqv(&lhs.clone().into_datum(), &rhs.clone().into_datum())
+}
+
+///
+/// Whether `lhs` and `rhs` have the same structure and contents, see `equal?`.
+///
+pub fn is_equal(lhs: &Expression, rhs: &Expression) -> bool {
+    datum_equal(
+        lhs.clone().into_datum(),
+        rhs.clone().into_datum(),
+        &mut Visited::default(),
+    )
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn eq(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    Ok(eboolean!(is_eqv(&arguments[0], &arguments[1])))
+}
+
+fn eqv(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    Ok(eboolean!(is_eqv(&arguments[0], &arguments[1])))
+}
+
+fn equal(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    Ok(eboolean!(is_equal(&arguments[0], &arguments[1])))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+fn datum_eqv(lhs: &Ref<Datum>, rhs: &Ref<Datum>) -> bool {
+    Ref::ptr_eq(lhs, rhs)
+        || match (&**lhs, &**rhs) {
+            (Datum::Symbol(lhs), Datum::Symbol(rhs)) => lhs == rhs,
+            (Datum::Boolean(lhs), Datum::Boolean(rhs)) => lhs == rhs,
+            (Datum::Number(lhs), Datum::Number(rhs)) => number_eqv(lhs, rhs),
+            (Datum::Character(lhs), Datum::Character(rhs)) => lhs == rhs,
+            (Datum::String(lhs), Datum::String(rhs)) => lhs.ptr_eq(rhs),
+            (Datum::ByteVector(lhs), Datum::ByteVector(rhs)) => lhs.ptr_eq(rhs),
+            (Datum::Vector(lhs), Datum::Vector(rhs)) => lhs.ptr_eq(rhs),
+            (Datum::Null, Datum::Null) => true,
+            (Datum::Object(lhs), Datum::Object(rhs)) => object_eqv(lhs, rhs),
+            _ => false,
+        }
+}
+
+///
+/// Numbers are equivalent if they are both exact, or both inexact, and are numerically equal.
+///
+fn number_eqv(lhs: &Number, rhs: &Number) -> bool {
+    lhs.is_exact() == rhs.is_exact() && lhs.numeric_eq(rhs)
+}
+
+///
+/// Values that have no datum representation, procedures and environments have identity while
+/// the remainder are compared by value.
+///
+fn object_eqv(lhs: &Expression, rhs: &Expression) -> bool {
+    match (lhs, rhs) {
+        (Expression::Procedure(lhs), Expression::Procedure(rhs)) => lhs.ptr_eq(rhs),
+        (Expression::Environment(lhs), Expression::Environment(rhs)) => Ref::ptr_eq(lhs, rhs),
+        _ => lhs == rhs,
+    }
+}
+
+///
+/// Compound data are compared element-wise; a pair of compound data already being compared is
+/// assumed to be equal, so that the comparison of circular data terminates. The `cdr` of a list
+/// is followed in a loop, not recursively, so that long lists do not exhaust the stack.
+///
+fn datum_equal(mut lhs: Ref<Datum>, mut rhs: Ref<Datum>, visited: &mut Visited) -> bool {
+    loop {
+        if Ref::ptr_eq(&lhs, &rhs) {
+            return true;
+        }
+        let (next_lhs, next_rhs) = match (&*lhs, &*rhs) {
+            (Datum::List(lhs_pair), Datum::List(rhs_pair)) => {
+                if !visited.insert((Ref::as_ptr(&lhs), Ref::as_ptr(&rhs))) {
+                    return true;
+                }
+                if !datum_equal(lhs_pair.car(), rhs_pair.car(), visited) {
+                    return false;
+                }
+                (lhs_pair.cdr(), rhs_pair.cdr())
+            }
+            (Datum::Vector(lhs_vector), Datum::Vector(rhs_vector)) => {
+                if !visited.insert((Ref::as_ptr(&lhs), Ref::as_ptr(&rhs))) {
+                    return true;
+                }
+                let (lhs_vector, rhs_vector) = (lhs_vector.as_vec(), rhs_vector.as_vec());
+                return lhs_vector.len() == rhs_vector.len()
+                    && lhs_vector
+                        .iter()
+                        .zip(rhs_vector.iter())
+                        .all(|(lhs, rhs)| datum_equal(lhs.clone(), rhs.clone(), visited));
+            }
+            (
+                Datum::Abbreviation(lhs_abbreviation, lhs_datum),
+                Datum::Abbreviation(rhs_abbreviation, rhs_datum),
+            ) => {
+                if lhs_abbreviation != rhs_abbreviation {
+                    return false;
+                }
+                (lhs_datum.clone(), rhs_datum.clone())
+            }
+            (Datum::String(lhs), Datum::String(rhs)) => return lhs == rhs,
+            (Datum::ByteVector(lhs), Datum::ByteVector(rhs)) => return lhs == rhs,
+            _ => return datum_eqv(&lhs, &rhs),
+        };
+        lhs = next_lhs;
+        rhs = next_rhs;
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
@@ -0,0 +1,131 @@
+/*!
+Exceptions, from R7RS §6.11; raising and handling exceptions, and error objects.
+
+Any value may be raised, errors reported by the interpreter itself are raised as error objects
+and so may be handled in the same way as those created by `error`.
+
+*/
+
+use schemer_lang::error::{Error, ErrorKind};
+use schemer_lang::eval::environment::Exports;
+use schemer_lang::eval::exceptions::{self, TYPE_NAME_ERROR_OBJECT};
+use schemer_lang::eval::{Environment, ErrorObject, Expression, Procedure};
+use schemer_lang::types::strings::TYPE_NAME_STRING;
+use schemer_lang::types::{Boolean, Identifier, MutableRef, SchemeValue};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn scheme_base_exception_exports() -> Exports {
+    let mut exports = Exports::default();
+
+    export_builtin!(exports, "with-exception-handler" => with_exception_handler "handler" "thunk");
+    export_builtin!(exports, "raise" => raise "obj");
+    export_builtin!(exports, "raise-continuable" => raise_continuable "obj");
+    export_builtin!(exports, "error" => error "message" ; "obj");
+    export_builtin!(exports, "error-object?" => is_error_object "obj");
+    export_builtin!(exports, "error-object-message" => error_object_message "error-object");
+    export_builtin!(exports, "error-object-irritants" => error_object_irritants "error-object");
+    export_builtin!(exports, "read-error?" => is_read_error "obj");
+    export_builtin!(exports, "file-error?" => is_file_error "obj");
+
+    exports
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn with_exception_handler(
+    arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    exceptions::with_exception_handler(&arguments[0], &arguments[1], environment)
+}
+
+fn raise(
+    mut arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Err(exceptions::raise(arguments.remove(0), environment))
+}
+
+fn raise_continuable(
+    mut arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    exceptions::raise_continuable(arguments.remove(0), environment)
+}
+
+fn error(
+    mut arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    match arguments.remove(0) {
+        Expression::String(message) => Err(exceptions::raise(
+            Expression::ErrorObject(ErrorObject::new(message, arguments)),
+            environment,
+        )),
+        e => unexpected_type!(TYPE_NAME_STRING, e),
+    }
+}
+
+is_a!(is_error_object, ErrorObject);
+
+fn error_object_message(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    match arguments.remove(0) {
+        Expression::ErrorObject(error) => Ok(Expression::String(error.message().clone())),
+        e => unexpected_type!(TYPE_NAME_ERROR_OBJECT, e),
+    }
+}
+
+fn error_object_irritants(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    match arguments.remove(0) {
+        Expression::ErrorObject(error) if error.irritants().is_empty() => Ok(Expression::Null),
+        Expression::ErrorObject(error) => Ok(Expression::List(error.irritants().clone())),
+        e => unexpected_type!(TYPE_NAME_ERROR_OBJECT, e),
+    }
+}
+
+fn is_read_error(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(eboolean!(matches!(
+        arguments.remove(0),
+        Expression::ErrorObject(error) if error.is_read_error()
+    )))
+}
+
+fn is_file_error(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(eboolean!(matches!(
+        arguments.remove(0),
+        Expression::ErrorObject(error) if error.is_file_error()
+    )))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
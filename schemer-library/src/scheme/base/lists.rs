@@ -0,0 +1,511 @@
+/*!
+Pairs and lists, from R7RS §6.4.
+
+Pairs are shared, not copied, so a mutation made by `set-car!`, `set-cdr!`, or `list-set!` is
+visible through every reference to the same pair. Procedures that construct lists, such as `list`,
+`append`, `reverse`, and `list-copy`, always return newly allocated pairs although `append` shares
+its last argument.
+
+# Example
+
+```scheme
+(define x (list 'a 'b 'c))
+(define y x)
+(set-car! (cdr y) 'z)
+x                           ;; => (a z c)
+(member 2.0 '(1 2 3) =)     ;; => (2 3)
+(assoc 2.0 '((1 1) (2 4)) =) ;; => (2 4)
+```
+
+*/
+
+use crate::scheme::base::equivalence::{is_equal, is_eqv};
+use crate::scheme::base::numbers::index;
+use schemer_lang::error::{Error, ErrorKind};
+use schemer_lang::eval::environment::Exports;
+use schemer_lang::eval::procedures::TYPE_NAME_PROCEDURE;
+use schemer_lang::eval::{Environment, Expression, Procedure};
+use schemer_lang::read::datum::Datum;
+use schemer_lang::types::lists::{vec_to_improper_list, TYPE_NAME_LIST, TYPE_NAME_PAIR};
+use schemer_lang::types::{Identifier, Integer, MutableRef, Number, Pair, Ref, SchemeValue};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+macro_rules! cxr {
+    ($fn_name:ident, $path:expr) => {
+        pub(crate) fn $fn_name(
+            arguments: Vec<Expression>,
+            _: &mut MutableRef<Environment>,
+        ) -> Result<Expression, Error> {
+            cxr_path(&arguments[0], $path)
+        }
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn scheme_base_list_exports() -> Exports {
+    let mut exports = Exports::default();
+
+    export_builtin!(exports, "cons" => cons "obj1" "obj2");
+    export_builtin!(exports, "car" => car "pair");
+    export_builtin!(exports, "cdr" => cdr "pair");
+    export_builtin!(exports, "set-car!" => set_car "pair" "obj");
+    export_builtin!(exports, "set-cdr!" => set_cdr "pair" "obj");
+
+    export_builtin!(exports, "caar" => caar "pair");
+    export_builtin!(exports, "cadr" => cadr "pair");
+    export_builtin!(exports, "cdar" => cdar "pair");
+    export_builtin!(exports, "cddr" => cddr "pair");
+
+    export_builtin!(exports, "list" => list ; "obj");
+    export_builtin!(exports, "make-list" => make_list "k" ; "fill");
+    export_builtin!(exports, "length" => length "list");
+    export_builtin!(exports, "append" => append ; "list");
+    export_builtin!(exports, "reverse" => reverse "list");
+    export_builtin!(exports, "list-tail" => list_tail "list" "k");
+    export_builtin!(exports, "list-ref" => list_ref "list" "k");
+    export_builtin!(exports, "list-set!" => list_set "list" "k" "obj");
+    export_builtin!(exports, "list-copy" => list_copy "obj");
+
+    export_builtin!(exports, "memq" => memq "obj" "list");
+    export_builtin!(exports, "memv" => memv "obj" "list");
+    export_builtin!(exports, "member" => member "obj" "list" ; "compare");
+    export_builtin!(exports, "assq" => assq "obj" "alist");
+    export_builtin!(exports, "assv" => assv "obj" "alist");
+    export_builtin!(exports, "assoc" => assoc "obj" "alist" ; "compare");
+
+    exports
+}
+
+///
+/// The number of pairs in `datum` if it is a proper list, or `None` if it is either improper or
+/// circular.
+///
+pub(crate) fn proper_length(datum: &Ref<Datum>) -> Option<usize> {
+    let mut slow = datum.clone();
+    let mut fast = datum.clone();
+    let mut length = 0;
+    loop {
+        for _ in 0..2 {
+            let next = match &*fast {
+                Datum::Null => return Some(length),
+                Datum::List(pair) => pair.cdr(),
+                _ => return None,
+            };
+            fast = next;
+            length += 1;
+        }
+        slow = slow.as_pair().unwrap().cdr();
+        if Ref::ptr_eq(&slow, &fast) {
+            return None;
+        }
+    }
+}
+
+///
+/// The list datum denoted by `value`; a quoted list is returned as-is so that it may be mutated
+/// but a list of values, such as error object irritants, is copied into new pairs.
+///
+pub(crate) fn list_datum(value: &Expression) -> Result<Ref<Datum>, Error> {
+    match value {
+        Expression::Quotation(datum) if datum.is_pair() => Ok(datum.clone()),
+        Expression::Null => Ok(Ref::new(Datum::Null)),
+        Expression::List(_) => Ok(value.clone().into_datum()),
+        _ => unexpected_type!(TYPE_NAME_LIST, value),
+    }
+}
+
+///
+/// The elements of the proper list `value`.
+///
+pub(crate) fn list_elements(value: &Expression) -> Result<Vec<Ref<Datum>>, Error> {
+    let datum = list_datum(value)?;
+    match (proper_length(&datum), datum.as_pair()) {
+        (Some(0), _) => Ok(Vec::default()),
+        (Some(_), Some(pair)) => Ok(pair.iter().collect()),
+        _ => Err(Error::from(ErrorKind::ImproperList)),
+    }
+}
+
+cxr!(caar, "aa");
+cxr!(cadr, "ad");
+cxr!(cdar, "da");
+cxr!(cddr, "dd");
+cxr!(caaar, "aaa");
+cxr!(caadr, "aad");
+cxr!(cadar, "ada");
+cxr!(caddr, "add");
+cxr!(cdaar, "daa");
+cxr!(cdadr, "dad");
+cxr!(cddar, "dda");
+cxr!(cdddr, "ddd");
+cxr!(caaaar, "aaaa");
+cxr!(caaadr, "aaad");
+cxr!(caadar, "aada");
+cxr!(caaddr, "aadd");
+cxr!(cadaar, "adaa");
+cxr!(cadadr, "adad");
+cxr!(caddar, "adda");
+cxr!(cadddr, "addd");
+cxr!(cdaaar, "daaa");
+cxr!(cdaadr, "daad");
+cxr!(cdadar, "dada");
+cxr!(cdaddr, "dadd");
+cxr!(cddaar, "ddaa");
+cxr!(cddadr, "ddad");
+cxr!(cdddar, "ddda");
+cxr!(cddddr, "dddd");
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn cons(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let cdr = arguments.remove(1).into_datum();
+    let car = arguments.remove(0).into_datum();
+    Ok(Expression::from_datum(Ref::new(Datum::List(Pair::cons(
+        car, cdr,
+    )))))
+}
+
+fn car(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    cxr_path(&arguments[0], "a")
+}
+
+fn cdr(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    cxr_path(&arguments[0], "d")
+}
+
+fn set_car(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let value = arguments.remove(1).into_datum();
+    mutable_pair("set-car!", &arguments[0])?
+        .as_pair()
+        .unwrap()
+        .set_car(value);
+    Ok(Expression::Unspecified)
+}
+
+fn set_cdr(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let value = arguments.remove(1).into_datum();
+    mutable_pair("set-cdr!", &arguments[0])?
+        .as_pair()
+        .unwrap()
+        .set_cdr(value);
+    Ok(Expression::Unspecified)
+}
+
+fn list(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    Ok(Expression::from_list(arguments))
+}
+
+fn make_list(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let k = index(&arguments[0])?;
+    let fill = if arguments.len() > 1 {
+        arguments.remove(1)
+    } else {
+        Expression::Boolean(false.into())
+    };
+    Ok(Expression::from_list(vec![fill; k]))
+}
+
+fn length(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let length = proper_length(&list_datum(&arguments[0])?)
+        .ok_or_else(|| Error::from(ErrorKind::ImproperList))?;
+    Ok(Expression::Number(Number::from(Integer::from(length))))
+}
+
+fn append(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let mut result = match arguments.pop() {
+        None => return Ok(Expression::Null),
+        Some(last) => last.into_datum(),
+    };
+    for argument in arguments.iter().rev() {
+        result = vec_to_improper_list(list_elements(argument)?, result);
+    }
+    Ok(Expression::from_datum(result))
+}
+
+fn reverse(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let mut elements = list_elements(&arguments[0])?;
+    elements.reverse();
+    Ok(Expression::from_datum(vec_to_improper_list(
+        elements,
+        Ref::new(Datum::Null),
+    )))
+}
+
+fn list_tail(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::from_datum(tail_after(
+        list_datum(&arguments[0])?,
+        index(&arguments[1])?,
+    )?))
+}
+
+fn list_ref(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let tail = tail_after(list_datum(&arguments[0])?, index(&arguments[1])?)?;
+    match tail.as_pair() {
+        Some(pair) => Ok(Expression::from_datum(pair.car())),
+        None => unexpected_type!(TYPE_NAME_PAIR, tail),
+    }
+}
+
+fn list_set(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let value = arguments.remove(2).into_datum();
+    let list = mutable_pair("list-set!", &arguments[0])?;
+    let tail = tail_after(list, index(&arguments[1])?)?;
+    match tail.as_pair() {
+        Some(pair) => pair.set_car(value),
+        None => unexpected_type!(=> TYPE_NAME_PAIR, tail),
+    }
+    Ok(Expression::Unspecified)
+}
+
+fn list_copy(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    match arguments.remove(0) {
+        Expression::Quotation(datum) if datum.is_pair() => {
+            let mut elements = Vec::default();
+            let mut current = datum;
+            while let Datum::List(pair) = &*current {
+                elements.push(pair.car());
+                let next = pair.cdr();
+                current = next;
+            }
+            Ok(Expression::from_datum(vec_to_improper_list(
+                elements, current,
+            )))
+        }
+        value => Ok(value),
+    }
+}
+
+fn memq(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let list = arguments.remove(1);
+    find_member(&list, |value| Ok(is_eqv(&arguments[0], value)))
+}
+
+fn memv(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let list = arguments.remove(1);
+    find_member(&list, |value| Ok(is_eqv(&arguments[0], value)))
+}
+
+fn member(
+    mut arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let compare = optional_compare("member", &mut arguments)?;
+    let list = arguments.remove(1);
+    let obj = arguments.remove(0);
+    find_member(&list, |value| {
+        compare_with(&compare, &obj, value, environment)
+    })
+}
+
+fn assq(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let alist = arguments.remove(1);
+    find_association(&alist, |key| Ok(is_eqv(&arguments[0], key)))
+}
+
+fn assv(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let alist = arguments.remove(1);
+    find_association(&alist, |key| Ok(is_eqv(&arguments[0], key)))
+}
+
+fn assoc(
+    mut arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let compare = optional_compare("assoc", &mut arguments)?;
+    let alist = arguments.remove(1);
+    let obj = arguments.remove(0);
+    find_association(&alist, |key| compare_with(&compare, &obj, key, environment))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Apply `path`, a sequence of `a` (car) and `d` (cdr) as in the procedure name, to `value` from
+/// right to left.
+///
+fn cxr_path(value: &Expression, path: &str) -> Result<Expression, Error> {
+    let mut current = pair_datum(value)?;
+    for step in path.chars().rev() {
+        let next = match current.as_pair() {
+            Some(pair) if step == 'a' => pair.car(),
+            Some(pair) => pair.cdr(),
+            None => unexpected_type!(=> TYPE_NAME_PAIR, current),
+        };
+        current = next;
+    }
+    Ok(Expression::from_datum(current))
+}
+
+fn pair_datum(value: &Expression) -> Result<Ref<Datum>, Error> {
+    match list_datum(value) {
+        Ok(datum) if datum.is_pair() => Ok(datum),
+        _ => unexpected_type!(TYPE_NAME_PAIR, value),
+    }
+}
+
+fn mutable_pair(name: &str, value: &Expression) -> Result<Ref<Datum>, Error> {
+    match value {
+        Expression::Quotation(datum) if datum.is_pair() => Ok(datum.clone()),
+        Expression::List(_) => Err(Error::from(ErrorKind::ImmutableValue {
+            name: Identifier::from_str_unchecked(name),
+            type_name: value.type_name().to_string(),
+        })),
+        _ => unexpected_type!(TYPE_NAME_PAIR, value),
+    }
+}
+
+fn tail_after(list: Ref<Datum>, k: usize) -> Result<Ref<Datum>, Error> {
+    let mut current = list;
+    for _ in 0..k {
+        let next = match current.as_pair() {
+            Some(pair) => pair.cdr(),
+            None => unexpected_type!(=> TYPE_NAME_PAIR, current),
+        };
+        current = next;
+    }
+    Ok(current)
+}
+
+///
+/// The sub-list of `list` starting with the first element for which `matches` is true, or `#f`.
+///
+fn find_member<F>(list: &Expression, mut matches: F) -> Result<Expression, Error>
+where
+    F: FnMut(&Expression) -> Result<bool, Error>,
+{
+    let mut current = list_datum(list)?;
+    while let Some(pair) = current.as_pair() {
+        if matches(&Expression::from_datum(pair.car()))? {
+            return Ok(Expression::from_datum(current));
+        }
+        let next = pair.cdr();
+        current = next;
+    }
+    Ok(Expression::Boolean(false.into()))
+}
+
+///
+/// The first pair in `alist` whose car `matches`, or `#f`.
+///
+fn find_association<F>(alist: &Expression, mut matches: F) -> Result<Expression, Error>
+where
+    F: FnMut(&Expression) -> Result<bool, Error>,
+{
+    let mut current = list_datum(alist)?;
+    while let Some(pair) = current.as_pair() {
+        let association = pair.car();
+        match association.as_pair() {
+            Some(entry) => {
+                if matches(&Expression::from_datum(entry.car()))? {
+                    return Ok(Expression::from_datum(association));
+                }
+            }
+            None => unexpected_type!(=> TYPE_NAME_PAIR, association),
+        }
+        let next = pair.cdr();
+        current = next;
+    }
+    Ok(Expression::Boolean(false.into()))
+}
+
+fn optional_compare(
+    name: &str,
+    arguments: &mut Vec<Expression>,
+) -> Result<Option<Expression>, Error> {
+    match arguments.len() {
+        2 => Ok(None),
+        3 => {
+            let compare = arguments.remove(2);
+            if compare.is_callable() {
+                Ok(Some(compare))
+            } else {
+                unexpected_type!(TYPE_NAME_PROCEDURE, compare)
+            }
+        }
+        given => Err(Error::from(ErrorKind::ProcedureArgumentCardinality {
+            name: Identifier::from_str_unchecked(name),
+            min: 2,
+            max: Some(3),
+            given,
+        })),
+    }
+}
+
+fn compare_with(
+    compare: &Option<Expression>,
+    obj: &Expression,
+    value: &Expression,
+    environment: &mut MutableRef<Environment>,
+) -> Result<bool, Error> {
+    match compare {
+        Some(compare) => Ok(compare
+            .call(vec![obj.clone(), value.clone()], environment)?
+            .is_true()),
+        None => Ok(is_equal(obj, value)),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
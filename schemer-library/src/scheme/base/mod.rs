@@ -9,10 +9,18 @@ More detailed description, with
 
 use crate::forms::library::LibraryName;
 use crate::forms::standard_form_exports;
+use crate::scheme::base::booleans::scheme_base_boolean_exports;
+use crate::scheme::base::bytevectors::scheme_base_byte_vector_exports;
+use crate::scheme::base::control::scheme_base_control_exports;
+use crate::scheme::base::equivalence::scheme_base_equivalence_exports;
+use crate::scheme::base::exceptions::scheme_base_exception_exports;
+use crate::scheme::base::lists::scheme_base_list_exports;
 use crate::scheme::base::numbers::scheme_base_number_exports;
 use crate::scheme::base::ports::scheme_base_ports_exports;
 use crate::scheme::base::strings::scheme_base_string_exports;
+use crate::scheme::base::symbols::scheme_base_symbol_exports;
 use crate::scheme::base::types::scheme_base_type_predicates_exports;
+use crate::scheme::base::vectors::scheme_base_vector_exports;
 use crate::scheme::base::write::scheme_base_write_exports;
 use crate::scheme::ID_LIB_SCHEME;
 use schemer_lang::error::Error;
@@ -44,10 +52,18 @@ pub fn scheme_base_exports() -> Exports {
     export_builtin!(exports, "features" => features);
 
     exports.import(standard_form_exports());
+    exports.import(scheme_base_boolean_exports());
+    exports.import(scheme_base_byte_vector_exports());
+    exports.import(scheme_base_control_exports());
+    exports.import(scheme_base_equivalence_exports());
+    exports.import(scheme_base_exception_exports());
+    exports.import(scheme_base_list_exports());
     exports.import(scheme_base_number_exports());
     exports.import(scheme_base_ports_exports());
     exports.import(scheme_base_string_exports());
+    exports.import(scheme_base_symbol_exports());
     exports.import(scheme_base_type_predicates_exports());
+    exports.import(scheme_base_vector_exports());
     exports.import(scheme_base_write_exports());
 
     exports
@@ -84,7 +100,6 @@ fn features(
                 )),
                 #[cfg(feature = "char-names")]
                 id_from_str!("unicode-char-names"),
-                #[cfg(feature = "big-num-x")]
                 id_from_str!("big-numbers"),
             ]
             .into_iter()
@@ -122,6 +137,18 @@ fn byte_order() -> Identifier {
 // Modules
 // ------------------------------------------------------------------------------------------------
 
+pub mod booleans;
+
+pub mod bytevectors;
+
+pub mod control;
+
+pub mod equivalence;
+
+pub mod exceptions;
+
+pub mod lists;
+
 pub mod numbers;
 
 pub mod ports;
@@ -130,4 +157,8 @@ pub mod types;
 
 pub mod strings;
 
+pub mod symbols;
+
+pub mod vectors;
+
 pub mod write;
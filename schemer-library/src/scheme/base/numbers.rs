@@ -1,18 +1,36 @@
 /*!
-One-line description.
+Numerical operations, from R7RS §6.2.6.
 
-More detailed description, with
+Arithmetic follows the exactness contagion rules implemented by the `Number` operators; an
+operation on exact arguments has an exact result and any inexact argument makes the result
+inexact. Division by an exact zero is reported as an error rather than returning an infinity.
 
 # Example
 
+```scheme
+(+ 1 1/2)           ;; => 3/2
+(* 2 (inexact 1/4)) ;; => 0.5
+(floor/ -5 2)       ;; => -3 1
+```
+
  */
 
-use num::Zero;
+use num::{Integer as _, One, Signed, ToPrimitive, Zero};
 use schemer_lang::error::{Error, ErrorKind};
 use schemer_lang::eval::environment::Exports;
 use schemer_lang::eval::{Environment, Expression, Procedure};
-use schemer_lang::types::numbers::TYPE_NAME_NUMBER;
-use schemer_lang::types::{Boolean, Identifier, MutableRef, Number, SchemeValue};
+use schemer_lang::types::numbers::conv::{exact_to_inexact, inexact_to_exact};
+use schemer_lang::types::numbers::{
+    TYPE_NAME_INTEGER, TYPE_NAME_NUMBER, TYPE_NAME_RATIONAL, TYPE_NAME_REAL,
+};
+use schemer_lang::types::strings::TYPE_NAME_STRING;
+use schemer_lang::types::{
+    Boolean, Identifier, InexactComplex, InexactReal, Integer, MutableRef, Number, Rational,
+    SchemeRepr, SchemeString, SchemeValue,
+};
+use schemer_parse::from_str::string_to_number_radix;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -43,6 +61,55 @@ pub fn scheme_base_number_exports() -> Exports {
     export_builtin!(exports, "positive?" => is_positive "num");
     export_builtin!(exports, "zero?" => is_zero "num");
 
+    export_builtin!(exports, "=" => numeric_equal "z1" "z2" ; "z");
+    export_builtin!(exports, "<" => numeric_less "x1" "x2" ; "x");
+    export_builtin!(exports, ">" => numeric_greater "x1" "x2" ; "x");
+    export_builtin!(exports, "<=" => numeric_less_equal "x1" "x2" ; "x");
+    export_builtin!(exports, ">=" => numeric_greater_equal "x1" "x2" ; "x");
+
+    export_builtin!(exports, "max" => max "x" ; "x");
+    export_builtin!(exports, "min" => min "x" ; "x");
+
+    export_builtin!(exports, "+" => add ; "z");
+    export_builtin!(exports, "*" => multiply ; "z");
+    export_builtin!(exports, "-" => subtract "z" ; "z");
+    export_builtin!(exports, "/" => divide "z" ; "z");
+
+    export_builtin!(exports, "abs" => abs "x");
+
+    export_builtin!(exports, "floor/" => floor_divide "n1" "n2");
+    export_builtin!(exports, "floor-quotient" => floor_quotient "n1" "n2");
+    export_builtin!(exports, "floor-remainder" => floor_remainder "n1" "n2");
+    export_builtin!(exports, "truncate/" => truncate_divide "n1" "n2");
+    export_builtin!(exports, "truncate-quotient" => truncate_quotient "n1" "n2");
+    export_builtin!(exports, "truncate-remainder" => truncate_remainder "n1" "n2");
+    export_builtin!(exports, "quotient" => truncate_quotient "n1" "n2");
+    export_builtin!(exports, "remainder" => truncate_remainder "n1" "n2");
+    export_builtin!(exports, "modulo" => floor_remainder "n1" "n2");
+
+    export_builtin!(exports, "gcd" => gcd ; "n");
+    export_builtin!(exports, "lcm" => lcm ; "n");
+
+    export_builtin!(exports, "numerator" => numerator "q");
+    export_builtin!(exports, "denominator" => denominator "q");
+
+    export_builtin!(exports, "floor" => floor "x");
+    export_builtin!(exports, "ceiling" => ceiling "x");
+    export_builtin!(exports, "round" => round "x");
+    export_builtin!(exports, "truncate" => truncate "x");
+
+    export_builtin!(exports, "rationalize" => rationalize "x" "y");
+
+    export_builtin!(exports, "square" => square "z");
+    export_builtin!(exports, "exact-integer-sqrt" => exact_integer_sqrt "k");
+    export_builtin!(exports, "expt" => expt "z1" "z2");
+
+    export_builtin!(exports, "exact" => exact "z");
+    export_builtin!(exports, "inexact" => inexact "z");
+
+    export_builtin!(exports, "number->string" => number_to_string "z" ; "radix");
+    export_builtin!(exports, "string->number" => string_to_number "string" ; "radix");
+
     exports
 }
 
@@ -68,6 +135,576 @@ is_number_a!(is_zero);
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+fn numeric_equal(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let numbers = numbers(arguments)?;
+    Ok(eboolean!(numbers
+        .windows(2)
+        .all(|pair| pair[0].numeric_eq(&pair[1]))))
+}
+
+fn numeric_less(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    compare_all(arguments, |ordering| ordering == Ordering::Less)
+}
+
+fn numeric_greater(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    compare_all(arguments, |ordering| ordering == Ordering::Greater)
+}
+
+fn numeric_less_equal(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    compare_all(arguments, |ordering| ordering != Ordering::Greater)
+}
+
+fn numeric_greater_equal(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    compare_all(arguments, |ordering| ordering != Ordering::Less)
+}
+
+fn max(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    select_extreme(arguments, Ordering::Greater)
+}
+
+fn min(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    select_extreme(arguments, Ordering::Less)
+}
+
+fn add(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    Ok(Expression::Number(
+        numbers(arguments)?
+            .into_iter()
+            .fold(Number::zero(), |lhs, rhs| lhs + rhs),
+    ))
+}
+
+fn multiply(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Number(
+        numbers(arguments)?
+            .into_iter()
+            .fold(Number::one(), |lhs, rhs| lhs * rhs),
+    ))
+}
+
+fn subtract(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let mut numbers = numbers(arguments)?.into_iter();
+    let first = numbers.next().unwrap();
+    if numbers.len() == 0 {
+        Ok(Expression::Number(-first))
+    } else {
+        Ok(Expression::Number(
+            numbers.fold(first, |lhs, rhs| lhs - rhs),
+        ))
+    }
+}
+
+fn divide(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let mut numbers = numbers(arguments)?.into_iter();
+    let first = numbers.next().unwrap();
+    if numbers.len() == 0 {
+        check_exact_divisor(&first)?;
+        Ok(Expression::Number(Number::one() / first))
+    } else {
+        let mut result = first;
+        for divisor in numbers {
+            check_exact_divisor(&divisor)?;
+            result = result / divisor;
+        }
+        Ok(Expression::Number(result))
+    }
+}
+
+fn abs(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    Ok(Expression::Number(number(&arguments[0])?.abs()?))
+}
+
+fn floor_divide(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let (quotient, remainder) = integer_divide(&arguments, Number::floor)?;
+    Ok(Expression::Values(vec![
+        Expression::Number(quotient),
+        Expression::Number(remainder),
+    ]))
+}
+
+fn floor_quotient(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Number(
+        integer_divide(&arguments, Number::floor)?.0,
+    ))
+}
+
+fn floor_remainder(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Number(
+        integer_divide(&arguments, Number::floor)?.1,
+    ))
+}
+
+fn truncate_divide(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let (quotient, remainder) = integer_divide(&arguments, Number::truncate)?;
+    Ok(Expression::Values(vec![
+        Expression::Number(quotient),
+        Expression::Number(remainder),
+    ]))
+}
+
+fn truncate_quotient(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Number(
+        integer_divide(&arguments, Number::truncate)?.0,
+    ))
+}
+
+fn truncate_remainder(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Number(
+        integer_divide(&arguments, Number::truncate)?.1,
+    ))
+}
+
+fn gcd(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    fold_integers(arguments, Integer::zero(), |lhs, rhs| lhs.gcd(&rhs))
+}
+
+fn lcm(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    fold_integers(arguments, Integer::one(), |lhs, rhs| lhs.lcm(&rhs))
+}
+
+fn numerator(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let value = number(&arguments[0])?;
+    let rational = to_rational(value)?;
+    with_exactness(Number::from(rational.numer().clone()), value.is_inexact())
+}
+
+fn denominator(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let value = number(&arguments[0])?;
+    let rational = to_rational(value)?;
+    with_exactness(Number::from(rational.denom().clone()), value.is_inexact())
+}
+
+fn floor(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    Ok(Expression::Number(number(&arguments[0])?.floor()?))
+}
+
+fn ceiling(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Number(number(&arguments[0])?.ceiling()?))
+}
+
+fn round(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    Ok(Expression::Number(number(&arguments[0])?.round()?))
+}
+
+fn truncate(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Number(number(&arguments[0])?.truncate()?))
+}
+
+fn rationalize(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let x = number(&arguments[0])?;
+    let y = number(&arguments[1])?;
+    let (x_rational, y_rational) = (to_rational(x)?, to_rational(y)?.abs());
+    let simplest = simplest_rational(&x_rational - &y_rational, &x_rational + &y_rational);
+    let simplest = if simplest.is_integer() {
+        Number::from(simplest.to_integer())
+    } else {
+        Number::from(simplest)
+    };
+    with_exactness(simplest, x.is_inexact() || y.is_inexact())
+}
+
+fn square(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let z = number(&arguments[0])?;
+    Ok(Expression::Number(z.clone() * z.clone()))
+}
+
+fn exact_integer_sqrt(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    match number(&arguments[0])? {
+        Number::Integer(k) if !k.is_negative() => {
+            let root = k.sqrt();
+            Ok(Expression::Values(vec![
+                Expression::Number(Number::from(root.clone())),
+                Expression::Number(Number::from(k - &root * &root)),
+            ]))
+        }
+        Number::Integer(k) => Err(Error::from(ErrorKind::UnexpectedValue {
+            type_name: TYPE_NAME_INTEGER.to_string(),
+            expected: "a non-negative integer".to_string(),
+            actual: k.to_repr_string(),
+        })),
+        value => unexpected_type!(TYPE_NAME_INTEGER, value),
+    }
+}
+
+fn expt(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let base = number(&arguments[0])?;
+    let exponent = number(&arguments[1])?;
+    if let Number::Integer(exponent) = exponent {
+        if exponent.is_negative() {
+            check_exact_divisor(base)?;
+        }
+        Ok(Expression::Number(expt_integer(base.clone(), exponent)))
+    } else if is_non_real(base)
+        || is_non_real(exponent)
+        || (base.is_negative().unwrap_or_default() && !exponent.is_integer_representation())
+    {
+        let base = InexactComplex::try_from(base.clone())?;
+        let exponent = InexactComplex::try_from(exponent.clone())?;
+        Ok(Expression::Number(Number::from(base.powc(exponent))))
+    } else {
+        let base = InexactReal::try_from(base.clone())?;
+        let exponent = InexactReal::try_from(exponent.clone())?;
+        Ok(Expression::Number(Number::from(base.powf(exponent))))
+    }
+}
+
+fn exact(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    Ok(Expression::Number(inexact_to_exact(
+        number(&arguments[0])?.clone(),
+    )?))
+}
+
+fn inexact(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Number(exact_to_inexact(
+        number(&arguments[0])?.clone(),
+    )?))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+fn number_to_string(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let radix = optional_radix("number->string", &arguments)?;
+    let z = number(&arguments[0])?;
+    Ok(Expression::String(SchemeString::from(
+        z.to_string_radix(radix)?,
+    )))
+}
+
+fn string_to_number(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let radix = optional_radix("string->number", &arguments)?;
+    match &arguments[0] {
+        Expression::String(v) => Ok(match string_to_number_radix(&v.as_string(), radix) {
+            Ok(v) => Expression::Number(v),
+            Err(_) => eboolean!(false),
+        }),
+        value => unexpected_type!(TYPE_NAME_STRING, value),
+    }
+}
+
+pub(crate) fn number(argument: &Expression) -> Result<&Number, Error> {
+    match argument {
+        Expression::Number(v) => Ok(v),
+        _ => unexpected_type!(TYPE_NAME_NUMBER, argument),
+    }
+}
+
+pub(crate) fn index(value: &Expression) -> Result<usize, Error> {
+    match number(value)? {
+        Number::Integer(k) => k.to_usize().ok_or_else(|| {
+            Error::from(ErrorKind::UnexpectedValue {
+                type_name: TYPE_NAME_INTEGER.to_string(),
+                expected: "a non-negative integer".to_string(),
+                actual: k.to_repr_string(),
+            })
+        }),
+        value => unexpected_type!(TYPE_NAME_INTEGER, value),
+    }
+}
+
+///
+/// The optional `start` and `end` arguments at `arguments[from]` and `arguments[from + 1]`, which
+/// default to `0` and `length`, with `start <= end <= length`.
+///
+pub(crate) fn index_range(
+    arguments: &[Expression],
+    from: usize,
+    length: usize,
+) -> Result<(usize, usize), Error> {
+    let start = match arguments.get(from) {
+        Some(start) => index(start)?,
+        None => 0,
+    };
+    let end = match arguments.get(from + 1) {
+        Some(end) => index(end)?,
+        None => length,
+    };
+    if end > length {
+        Err(index_out_of_range(end, length))
+    } else if start > end {
+        Err(index_out_of_range(start, end))
+    } else {
+        Ok((start, end))
+    }
+}
+
+///
+/// The error for the index `k` of an element in a sequence of `length` elements.
+///
+pub(crate) fn element_index_out_of_range(k: usize, length: usize) -> Error {
+    Error::from(ErrorKind::UnexpectedValue {
+        type_name: TYPE_NAME_INTEGER.to_string(),
+        expected: format!("an index less than {}", length),
+        actual: k.to_string(),
+    })
+}
+
+///
+/// The error for the index `k` of the start, or end, of a range; these may be equal to `limit`.
+///
+pub(crate) fn index_out_of_range(k: usize, limit: usize) -> Error {
+    Error::from(ErrorKind::UnexpectedValue {
+        type_name: TYPE_NAME_INTEGER.to_string(),
+        expected: format!("an index no greater than {}", limit),
+        actual: k.to_string(),
+    })
+}
+
+fn numbers(arguments: Vec<Expression>) -> Result<Vec<Number>, Error> {
+    arguments
+        .iter()
+        .map(|argument| number(argument).cloned())
+        .collect()
+}
+
+pub(crate) fn real(argument: &Expression) -> Result<&Number, Error> {
+    let value = number(argument)?;
+    if value.is_real() {
+        Ok(value)
+    } else {
+        unexpected_type!(TYPE_NAME_REAL, value)
+    }
+}
+
+fn integer(argument: &Expression) -> Result<&Number, Error> {
+    let value = number(argument)?;
+    if value.is_real() && value.is_integer_representation() {
+        Ok(value)
+    } else {
+        unexpected_type!(TYPE_NAME_INTEGER, value)
+    }
+}
+
+fn optional_radix(name: &str, arguments: &[Expression]) -> Result<u32, Error> {
+    match arguments.len() {
+        1 => Ok(10),
+        2 => match number(&arguments[1])? {
+            Number::Integer(v) => Ok(v.to_u32().unwrap_or_default()),
+            value => unexpected_type!(TYPE_NAME_INTEGER, value),
+        },
+        given => Err(Error::from(ErrorKind::ProcedureArgumentCardinality {
+            name: Identifier::from_str_unchecked(name),
+            min: 1,
+            max: Some(2),
+            given,
+        })),
+    }
+}
+
+#[inline]
+pub(crate) fn is_non_real(value: &Number) -> bool {
+    matches!(value, Number::ExactComplex(_) | Number::InexactComplex(_))
+}
+
+pub(crate) fn check_exact_divisor(divisor: &Number) -> Result<(), Error> {
+    if divisor.is_exact() && divisor.is_zero() {
+        Err(Error::from(ErrorKind::DivisionByZero))
+    } else {
+        Ok(())
+    }
+}
+
+fn with_exactness(value: Number, inexact: bool) -> Result<Expression, Error> {
+    Ok(Expression::Number(if inexact {
+        exact_to_inexact(value)?
+    } else {
+        value
+    }))
+}
+
+fn compare_all(
+    arguments: Vec<Expression>,
+    test: impl Fn(Ordering) -> bool,
+) -> Result<Expression, Error> {
+    let numbers = arguments
+        .iter()
+        .map(real)
+        .collect::<Result<Vec<&Number>, Error>>()?;
+    Ok(eboolean!(numbers.windows(2).all(|pair| pair[0]
+        .numeric_cmp(pair[1])
+        .map(&test)
+        .unwrap_or_default())))
+}
+
+fn select_extreme(arguments: Vec<Expression>, wanted: Ordering) -> Result<Expression, Error> {
+    let numbers = arguments
+        .iter()
+        .map(real)
+        .collect::<Result<Vec<&Number>, Error>>()?;
+    let inexact = numbers.iter().any(|value| value.is_inexact());
+    let mut result = numbers[0];
+    for value in &numbers[1..] {
+        if value.numeric_cmp(result) == Some(wanted) {
+            result = value;
+        }
+    }
+    with_exactness(result.clone(), inexact)
+}
+
+///
+/// The quotient and remainder of dividing the first argument by the second, where `integral_fn`
+/// determines how the quotient is rounded to an integer.
+///
+fn integer_divide(
+    arguments: &[Expression],
+    integral_fn: impl Fn(&Number) -> Result<Number, Error>,
+) -> Result<(Number, Number), Error> {
+    let dividend = integer(&arguments[0])?;
+    let divisor = integer(&arguments[1])?;
+    if divisor.is_zero() {
+        return Err(Error::from(ErrorKind::DivisionByZero));
+    }
+    let quotient = integral_fn(&(dividend.clone() / divisor.clone()))?;
+    let remainder = dividend.clone() - divisor.clone() * quotient.clone();
+    Ok((quotient, remainder))
+}
+
+fn fold_integers(
+    arguments: Vec<Expression>,
+    init: Integer,
+    fold_fn: impl Fn(Integer, Integer) -> Integer,
+) -> Result<Expression, Error> {
+    let mut inexact = false;
+    let mut result = init;
+    for argument in &arguments {
+        let value = integer(argument)?;
+        inexact = inexact || value.is_inexact();
+        result = fold_fn(result, Integer::try_from(value.clone())?);
+    }
+    with_exactness(Number::from(result), inexact)
+}
+
+fn to_rational(value: &Number) -> Result<Rational, Error> {
+    if value.is_real() {
+        Rational::try_from(value.clone())
+    } else {
+        unexpected_type!(TYPE_NAME_RATIONAL, value)
+    }
+}
+
+///
+/// The simplest rational number in the closed interval `lower` to `upper`, as described by
+/// R7RS for `rationalize`.
+///
+fn simplest_rational(lower: Rational, upper: Rational) -> Rational {
+    if lower > upper {
+        simplest_rational(upper, lower)
+    } else if lower.is_positive() {
+        simplest_positive_rational(lower, upper)
+    } else if upper.is_negative() {
+        -simplest_positive_rational(-upper, -lower)
+    } else {
+        Rational::zero()
+    }
+}
+
+fn simplest_positive_rational(lower: Rational, upper: Rational) -> Rational {
+    let floor = lower.floor();
+    if floor == lower {
+        floor
+    } else if floor < upper.floor() {
+        floor + Rational::one()
+    } else {
+        &floor
+            + simplest_positive_rational((upper - &floor).recip(), (lower - &floor).recip()).recip()
+    }
+}
+
+///
+/// Raise `base` to an exact integer power by repeated squaring, so that an exact base has an
+/// exact result.
+///
+fn expt_integer(base: Number, exponent: &Integer) -> Number {
+    let mut result = Number::one();
+    let mut square = base;
+    let mut remaining = exponent.magnitude().clone();
+    while !remaining.is_zero() {
+        if remaining.is_odd() {
+            result = result * square.clone();
+        }
+        remaining >>= 1;
+        if !remaining.is_zero() {
+            square = square.clone() * square;
+        }
+    }
+    if exponent.is_negative() {
+        Number::one() / result
+    } else {
+        result
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
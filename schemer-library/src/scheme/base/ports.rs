@@ -1,16 +1,32 @@
 /*!
-One-line description.
+Ports and input, from R7RS §6.13; the output procedures are in the `write` module.
 
-More detailed description, with
+The input procedures take an optional port which defaults to the current input port, and return
+the end-of-file object once the end of input has been reached.
 
 # Example
 
- */
+```scheme
+(define out (open-output-string))
+(write-string "abc" out)
+(get-output-string out)                    ;; => "abc"
+(read-line (open-input-string "one\ntwo")) ;; => "one"
+(input-port? (current-input-port))         ;; => #t
+(textual-port? (current-output-port))      ;; => #t
+(eof-object? (eof-object))                 ;; => #t
+(read-line)                                ;; => "the next line of standard input"
+```
 
-use schemer_lang::error::Error;
+*/
+
+use crate::scheme::base::bytevectors::{byte_vector, new_byte_vector_value};
+use crate::scheme::base::numbers::index;
+use crate::scheme::base::strings::{new_string_value, string};
+use schemer_lang::error::{Error, ErrorKind};
 use schemer_lang::eval::environment::Exports;
-use schemer_lang::eval::{Environment, Expression, Procedure};
-use schemer_lang::types::{Identifier, MutableRef};
+use schemer_lang::eval::ports::{self, TYPE_NAME_PORT};
+use schemer_lang::eval::{Environment, Expression, Port, Procedure};
+use schemer_lang::types::{Boolean, Char, Identifier, Integer, MutableRef, Number, SchemeValue};
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -27,9 +43,41 @@ use schemer_lang::types::{Identifier, MutableRef};
 pub fn scheme_base_ports_exports() -> Exports {
     let mut exports = Exports::default();
 
-    export_builtin!(exports, "current_input_port" => current_input_port);
-    export_builtin!(exports, "current_error_port" => current_error_port);
-    export_builtin!(exports, "current_output_port" => current_output_port);
+    export_builtin!(exports, "current-input-port" => current_input_port);
+    export_builtin!(exports, "current-output-port" => current_output_port);
+    export_builtin!(exports, "current-error-port" => current_error_port);
+
+    export_builtin!(exports, "open-input-string" => open_input_string "string");
+    export_builtin!(exports, "open-output-string" => open_output_string);
+    export_builtin!(exports, "get-output-string" => get_output_string "port");
+    export_builtin!(exports, "open-input-bytevector" => open_input_byte_vector "bytevector");
+    export_builtin!(exports, "open-output-bytevector" => open_output_byte_vector);
+    export_builtin!(exports, "get-output-bytevector" => get_output_byte_vector "port");
+
+    export_builtin!(exports, "port?" => is_port "obj");
+    export_builtin!(exports, "input-port?" => is_input_port "obj");
+    export_builtin!(exports, "output-port?" => is_output_port "obj");
+    export_builtin!(exports, "textual-port?" => is_textual_port "obj");
+    export_builtin!(exports, "binary-port?" => is_binary_port "obj");
+    export_builtin!(exports, "input-port-open?" => is_input_port_open "port");
+    export_builtin!(exports, "output-port-open?" => is_output_port_open "port");
+
+    export_builtin!(exports, "close-port" => close_port "port");
+    export_builtin!(exports, "close-input-port" => close_input_port "port");
+    export_builtin!(exports, "close-output-port" => close_output_port "port");
+
+    export_builtin!(exports, "read-char" => read_char ; "port");
+    export_builtin!(exports, "peek-char" => peek_char ; "port");
+    export_builtin!(exports, "read-line" => read_line ; "port");
+    export_builtin!(exports, "read-string" => read_string "k" ; "port");
+    export_builtin!(exports, "char-ready?" => is_char_ready ; "port");
+    export_builtin!(exports, "read-u8" => read_u8 ; "port");
+    export_builtin!(exports, "peek-u8" => peek_u8 ; "port");
+    export_builtin!(exports, "read-bytevector" => read_byte_vector "k" ; "port");
+    export_builtin!(exports, "u8-ready?" => is_u8_ready ; "port");
+
+    export_builtin!(exports, "eof-object" => eof_object);
+    export_builtin!(exports, "eof-object?" => is_eof_object "obj");
 
     exports
 }
@@ -46,21 +94,284 @@ fn current_input_port(
     _: Vec<Expression>,
     _: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    Ok(Expression::Port(ports::current_input_port()))
 }
 
 fn current_output_port(
     _: Vec<Expression>,
     _: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    Ok(Expression::Port(ports::current_output_port()))
 }
 
 fn current_error_port(
     _: Vec<Expression>,
     _: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    Ok(Expression::Port(ports::current_error_port()))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+fn open_input_string(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Port(Port::new_input_string(
+        &string(&arguments[0])?.as_string(),
+    )))
+}
+
+fn open_output_string(
+    _: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Port(Port::new_output_string()))
+}
+
+fn get_output_string(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_string_value(port(&arguments[0])?.output_string()?))
+}
+
+fn open_input_byte_vector(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Port(Port::new_input_bytes(
+        byte_vector(&arguments[0])?.as_bytes().clone(),
+    )))
+}
+
+fn open_output_byte_vector(
+    _: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Port(Port::new_output_bytes()))
+}
+
+fn get_output_byte_vector(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_byte_vector_value(port(&arguments[0])?.output_bytes()?))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+is_a!(is_port, Port);
+
+fn is_input_port(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(eboolean!(
+        matches!(&arguments[0], Expression::Port(port) if port.is_input())
+    ))
+}
+
+fn is_output_port(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(eboolean!(
+        matches!(&arguments[0], Expression::Port(port) if port.is_output())
+    ))
+}
+
+fn is_textual_port(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(eboolean!(
+        matches!(&arguments[0], Expression::Port(port) if port.is_textual())
+    ))
+}
+
+fn is_binary_port(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(eboolean!(
+        matches!(&arguments[0], Expression::Port(port) if port.is_binary())
+    ))
+}
+
+fn is_input_port_open(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let port = port(&arguments[0])?;
+    Ok(eboolean!(port.is_input() && port.is_open()))
+}
+
+fn is_output_port_open(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let port = port(&arguments[0])?;
+    Ok(eboolean!(port.is_output() && port.is_open()))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+fn close_port(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    port(&arguments[0])?.close()?;
+    Ok(Expression::Unspecified)
+}
+
+fn close_input_port(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let port = port(&arguments[0])?;
+    if port.is_input() {
+        port.close()?;
+    }
+    Ok(Expression::Unspecified)
+}
+
+fn close_output_port(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let port = port(&arguments[0])?;
+    if port.is_output() {
+        port.close()?;
+    }
+    Ok(Expression::Unspecified)
+}
+
+// ------------------------------------------------------------------------------------------------
+
+fn read_char(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(char_or_eof(input_port(&arguments, 0)?.read_char()?))
+}
+
+fn peek_char(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(char_or_eof(input_port(&arguments, 0)?.peek_char()?))
+}
+
+fn read_line(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(match input_port(&arguments, 0)?.read_line()? {
+        Some(line) => new_string_value(line),
+        None => Expression::EofObject,
+    })
+}
+
+fn read_string(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let k = index(&arguments[0])?;
+    Ok(match input_port(&arguments, 1)?.read_string(k)? {
+        Some(s) => new_string_value(s),
+        None => Expression::EofObject,
+    })
+}
+
+fn is_char_ready(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(eboolean!(input_port(&arguments, 0)?.is_char_ready()?))
+}
+
+fn read_u8(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(byte_or_eof(input_port(&arguments, 0)?.read_u8()?))
+}
+
+fn peek_u8(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(byte_or_eof(input_port(&arguments, 0)?.peek_u8()?))
+}
+
+fn read_byte_vector(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let k = index(&arguments[0])?;
+    Ok(match input_port(&arguments, 1)?.read_bytes(k)? {
+        Some(bytes) => new_byte_vector_value(bytes),
+        None => Expression::EofObject,
+    })
+}
+
+fn is_u8_ready(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(eboolean!(input_port(&arguments, 0)?.is_u8_ready()?))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+fn eof_object(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    Ok(Expression::EofObject)
+}
+
+is_a!(is_eof_object, EofObject !);
+
+// ------------------------------------------------------------------------------------------------
+
+pub(crate) fn port(value: &Expression) -> Result<&Port, Error> {
+    match value {
+        Expression::Port(port) => Ok(port),
+        _ => unexpected_type!(TYPE_NAME_PORT, value),
+    }
+}
+
+///
+/// The optional port at `arguments[at]`, which defaults to the current input port.
+///
+pub(crate) fn input_port(arguments: &[Expression], at: usize) -> Result<Port, Error> {
+    match arguments.get(at) {
+        Some(value) => port(value).cloned(),
+        None => Ok(ports::current_input_port()),
+    }
+}
+
+///
+/// The optional port at `arguments[at]`, which defaults to the current output port.
+///
+pub(crate) fn output_port(arguments: &[Expression], at: usize) -> Result<Port, Error> {
+    match arguments.get(at) {
+        Some(value) => port(value).cloned(),
+        None => Ok(ports::current_output_port()),
+    }
+}
+
+fn char_or_eof(c: Option<char>) -> Expression {
+    match c {
+        Some(c) => Expression::Character(Char::from(c)),
+        None => Expression::EofObject,
+    }
+}
+
+fn byte_or_eof(byte: Option<u8>) -> Expression {
+    match byte {
+        Some(byte) => Expression::Number(Number::from(Integer::from(byte))),
+        None => Expression::EofObject,
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
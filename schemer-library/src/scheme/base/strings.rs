@@ -1,17 +1,42 @@
 /*!
-One-line description.
+Strings, from R7RS §6.7, along with the string conversions from §6.5 (symbols) and §6.8 (vectors).
+The case-insensitive comparisons and case conversions are exported by `(scheme char)`.
 
-More detailed description, with
+Strings are indexed by character, not byte, and are shared rather than copied so that a mutation
+made by `string-set!`, `string-fill!`, or `string-copy!` is visible through every reference to
+the same string. Procedures that construct strings, such as `string-copy` and `string-append`,
+always return a newly allocated string.
 
 # Example
 
- */
+```scheme
+(define s (make-string 3 #\a))
+(string-set! s 1 #\b)
+s                         ;; => "aba"
+(string<? "abc" "abd")    ;; => #t
+(string=? "Abc" "aBC")    ;; => #f
+```
 
+*/
+
+use crate::scheme::base::lists::list_elements;
+use crate::scheme::base::numbers::{
+    element_index_out_of_range, index, index_out_of_range, index_range,
+};
+use crate::scheme::base::symbols::{new_symbol_value, symbol};
+use crate::scheme::base::vectors::vector_elements;
 use schemer_lang::error::{Error, ErrorKind};
 use schemer_lang::eval::environment::Exports;
 use schemer_lang::eval::{Environment, Expression, Procedure};
+use schemer_lang::read::datum::Datum;
+use schemer_lang::types::char_folding::fold_string;
+use schemer_lang::types::chars::TYPE_NAME_CHAR;
 use schemer_lang::types::strings::TYPE_NAME_STRING;
-use schemer_lang::types::{Identifier, Integer, MutableRef, Number, SchemeValue};
+use schemer_lang::types::{
+    Boolean, Char, Identifier, Integer, MutableRef, Number, SchemeString, SchemeValue, Vector,
+};
+use std::cmp::Ordering;
+use std::ops::Range;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -21,6 +46,31 @@ use schemer_lang::types::{Identifier, Integer, MutableRef, Number, SchemeValue};
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+macro_rules! string_compare {
+    ($fn_name:ident, $fold_case:expr, $( $ordering:path )|+) => {
+        pub(crate) fn $fn_name(
+            arguments: Vec<Expression>,
+            _: &mut MutableRef<Environment>,
+        ) -> Result<Expression, Error> {
+            let strings = arguments
+                .iter()
+                .map(|argument| {
+                    string(argument).map(|s| {
+                        if $fold_case {
+                            fold_string(&s.as_string())
+                        } else {
+                            s.to_string()
+                        }
+                    })
+                })
+                .collect::<Result<Vec<String>, Error>>()?;
+            Ok(eboolean!(strings
+                .windows(2)
+                .all(|pair| matches!(pair[0].cmp(&pair[1]), $( $ordering )|+))))
+        }
+    };
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
@@ -28,7 +78,28 @@ use schemer_lang::types::{Identifier, Integer, MutableRef, Number, SchemeValue};
 pub fn scheme_base_string_exports() -> Exports {
     let mut exports = Exports::default();
 
+    export_builtin!(exports, "make-string" => make_string "k" ; "char");
+    export_builtin!(exports, "string" => new_string ; "char");
     export_builtin!(exports, "string-length" => string_length "str");
+    export_builtin!(exports, "string-ref" => string_ref "string" "k");
+    export_builtin!(exports, "string-set!" => string_set "string" "k" "char");
+    export_builtin!(exports, "substring" => substring "string" "start" "end");
+    export_builtin!(exports, "string-append" => string_append ; "string");
+    export_builtin!(exports, "string->list" => string_to_list "string" ; "start-end");
+    export_builtin!(exports, "list->string" => list_to_string "list");
+    export_builtin!(exports, "string-copy" => string_copy "string" ; "start-end");
+    export_builtin!(exports, "string-copy!" => string_copy_into "to" "at" "from" ; "start-end");
+    export_builtin!(exports, "string-fill!" => string_fill "string" "fill" ; "start-end");
+
+    export_builtin!(exports, "string=?" => string_equal "string1" ; "string");
+    export_builtin!(exports, "string<?" => string_less "string1" ; "string");
+    export_builtin!(exports, "string>?" => string_greater "string1" ; "string");
+    export_builtin!(exports, "string<=?" => string_less_equal "string1" ; "string");
+    export_builtin!(exports, "string>=?" => string_greater_equal "string1" ; "string");
+    export_builtin!(exports, "string->vector" => string_to_vector "string" ; "start-end");
+    export_builtin!(exports, "vector->string" => vector_to_string "vector" ; "start-end");
+    export_builtin!(exports, "string->symbol" => string_to_symbol "string");
+    export_builtin!(exports, "symbol->string" => symbol_to_string "symbol");
 
     exports
 }
@@ -38,12 +109,7 @@ pub fn string_length(
     _: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
     Ok(Expression::Number(Number::from(Integer::from(
-        match &arguments[0] {
-            Expression::String(v) => v.len() as Integer,
-            e => {
-                unexpected_type!(=> TYPE_NAME_STRING, e)
-            }
-        },
+        string(&arguments[0])?.len(),
     ))))
 }
 
@@ -55,6 +121,292 @@ pub fn string_length(
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+fn make_string(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let k = index(&arguments[0])?;
+    let fill = match arguments.get(1) {
+        Some(fill) => character(fill)?,
+        None => ' ',
+    };
+    Ok(new_string_value(std::iter::repeat(fill).take(k).collect()))
+}
+
+fn new_string(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_string_value(
+        arguments
+            .iter()
+            .map(character)
+            .collect::<Result<String, Error>>()?,
+    ))
+}
+
+fn string_ref(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let s = string(&arguments[0])?;
+    let k = index(&arguments[1])?;
+    let c = s
+        .as_string()
+        .chars()
+        .nth(k)
+        .ok_or_else(|| element_index_out_of_range(k, s.len()))?;
+    Ok(Expression::Character(Char::from(c)))
+}
+
+fn string_set(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let s = string(&arguments[0])?;
+    let k = index(&arguments[1])?;
+    let c = character(&arguments[2])?;
+    if k >= s.len() {
+        return Err(element_index_out_of_range(k, s.len()));
+    }
+    let mut value = s.as_string_mut();
+    let range = byte_range(&value, k, k + 1)?;
+    value.replace_range(range, c.encode_utf8(&mut [0; 4]));
+    Ok(Expression::Unspecified)
+}
+
+fn substring(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    copy_range(&arguments)
+}
+
+fn string_append(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let mut result = String::default();
+    for argument in &arguments {
+        result.push_str(&string(argument)?.as_string());
+    }
+    Ok(new_string_value(result))
+}
+
+fn string_to_list(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::from_list(
+        chars_in_range(&arguments, 0)?
+            .map(|c| Expression::Character(Char::from(c)))
+            .collect(),
+    ))
+}
+
+fn list_to_string(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_string_value(
+        list_elements(&arguments[0])?
+            .into_iter()
+            .map(|datum| character(&Expression::from_datum(datum)))
+            .collect::<Result<String, Error>>()?,
+    ))
+}
+
+fn string_copy(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    copy_range(&arguments)
+}
+
+fn string_copy_into(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let to = string(&arguments[0])?;
+    let at = index(&arguments[1])?;
+    // collected first, as `from` may be the same string as `to`.
+    let from: String = chars_in_range(&arguments, 2)?.collect();
+    let mut value = to.as_string_mut();
+    let range = byte_range(&value, at, at + from.chars().count())?;
+    value.replace_range(range, &from);
+    Ok(Expression::Unspecified)
+}
+
+fn string_fill(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let s = string(&arguments[0])?;
+    let fill = character(&arguments[1])?;
+    let (start, end) = index_range(&arguments, 2, s.len())?;
+    let mut value = s.as_string_mut();
+    let range = byte_range(&value, start, end)?;
+    value.replace_range(
+        range,
+        &std::iter::repeat(fill)
+            .take(end - start)
+            .collect::<String>(),
+    );
+    Ok(Expression::Unspecified)
+}
+
+string_compare!(string_equal, false, Ordering::Equal);
+string_compare!(string_less, false, Ordering::Less);
+string_compare!(string_greater, false, Ordering::Greater);
+string_compare!(string_less_equal, false, Ordering::Less | Ordering::Equal);
+string_compare!(
+    string_greater_equal,
+    false,
+    Ordering::Greater | Ordering::Equal
+);
+string_compare!(string_ci_equal, true, Ordering::Equal);
+string_compare!(string_ci_less, true, Ordering::Less);
+string_compare!(string_ci_greater, true, Ordering::Greater);
+string_compare!(string_ci_less_equal, true, Ordering::Less | Ordering::Equal);
+string_compare!(
+    string_ci_greater_equal,
+    true,
+    Ordering::Greater | Ordering::Equal
+);
+
+pub(crate) fn string_upcase(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_string_value(
+        string(&arguments[0])?.as_string().to_uppercase(),
+    ))
+}
+
+pub(crate) fn string_downcase(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_string_value(
+        string(&arguments[0])?.as_string().to_lowercase(),
+    ))
+}
+
+pub(crate) fn string_foldcase(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_string_value(fold_string(
+        &string(&arguments[0])?.as_string(),
+    )))
+}
+
+fn string_to_vector(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Vector(
+        chars_in_range(&arguments, 0)?
+            .map(|c| Datum::Character(Char::from(c)))
+            .collect::<Vector<Datum>>(),
+    ))
+}
+
+fn vector_to_string(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let elements = vector_elements(&arguments[0])?;
+    let (start, end) = index_range(&arguments, 1, elements.len())?;
+    Ok(new_string_value(
+        elements[start..end]
+            .iter()
+            .map(character)
+            .collect::<Result<String, Error>>()?,
+    ))
+}
+
+fn string_to_symbol(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_symbol_value(Identifier::from_str_unchecked(
+        &string(&arguments[0])?.as_string(),
+    )))
+}
+
+fn symbol_to_string(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_string_value(
+        symbol(&arguments[0])?.as_str().to_string(),
+    ))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+pub(crate) fn string(value: &Expression) -> Result<&SchemeString, Error> {
+    match value {
+        Expression::String(s) => Ok(s),
+        _ => unexpected_type!(TYPE_NAME_STRING, value),
+    }
+}
+
+pub(crate) fn character(value: &Expression) -> Result<char, Error> {
+    match value {
+        Expression::Character(c) => Ok(**c),
+        _ => unexpected_type!(TYPE_NAME_CHAR, value),
+    }
+}
+
+#[inline]
+pub(crate) fn new_string_value(s: String) -> Expression {
+    Expression::String(SchemeString::from(s))
+}
+
+///
+/// The characters of the string `arguments[at]` in the optional range that follows it.
+///
+pub(crate) fn chars_in_range(
+    arguments: &[Expression],
+    at: usize,
+) -> Result<impl Iterator<Item = char>, Error> {
+    let s = string(&arguments[at])?;
+    let (start, end) = index_range(arguments, at + 1, s.len())?;
+    let chars: Vec<char> = s
+        .as_string()
+        .chars()
+        .skip(start)
+        .take(end - start)
+        .collect();
+    Ok(chars.into_iter())
+}
+
+fn copy_range(arguments: &[Expression]) -> Result<Expression, Error> {
+    Ok(new_string_value(chars_in_range(arguments, 0)?.collect()))
+}
+
+///
+/// The byte range within `s` of the characters from `start` to `end`.
+///
+fn byte_range(s: &str, start: usize, end: usize) -> Result<Range<usize>, Error> {
+    let mut offsets = s
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(s.len()));
+    let start_offset = offsets.nth(start);
+    let end_offset = if end == start {
+        start_offset
+    } else {
+        offsets.nth(end - start - 1)
+    };
+    match (start_offset, end_offset) {
+        (Some(start), Some(end)) => Ok(start..end),
+        _ => Err(index_out_of_range(end, s.chars().count())),
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -0,0 +1,82 @@
+/*!
+Symbols, from R7RS §6.5; the conversions between symbols and strings are in the `strings` module.
+
+# Example
+
+```scheme
+(symbol=? 'a 'a 'a)    ;; => #t
+(symbol=? 'a 'A)       ;; => #f
+```
+
+*/
+
+use schemer_lang::error::{Error, ErrorKind};
+use schemer_lang::eval::environment::Exports;
+use schemer_lang::eval::{Environment, Expression, Procedure};
+use schemer_lang::read::datum::Datum;
+use schemer_lang::types::symbols::TYPE_NAME_SYMBOL;
+use schemer_lang::types::{Boolean, Identifier, MutableRef, Ref, SchemeValue};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn scheme_base_symbol_exports() -> Exports {
+    let mut exports = Exports::default();
+
+    export_builtin!(exports, "symbol=?" => symbol_equal "symbol1" "symbol2" ; "symbol");
+
+    exports
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn symbol_equal(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let symbols = arguments
+        .iter()
+        .map(symbol)
+        .collect::<Result<Vec<Identifier>, Error>>()?;
+    Ok(eboolean!(symbols.windows(2).all(|pair| pair[0] == pair[1])))
+}
+
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The symbol denoted by `value`, which is usually a quoted symbol.
+///
+pub(crate) fn symbol(value: &Expression) -> Result<Identifier, Error> {
+    match value {
+        Expression::Identifier(id) => Ok(id.clone()),
+        Expression::Quotation(datum) => match datum.as_symbol() {
+            Some(id) => Ok(id.clone()),
+            None => unexpected_type!(TYPE_NAME_SYMBOL, datum),
+        },
+        _ => unexpected_type!(TYPE_NAME_SYMBOL, value),
+    }
+}
+
+#[inline]
+pub(crate) fn new_symbol_value(id: Identifier) -> Expression {
+    Expression::from_datum(Ref::new(Datum::Symbol(id)))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
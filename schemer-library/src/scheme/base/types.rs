@@ -7,6 +7,7 @@ More detailed description, with
 
 */
 
+use crate::scheme::base::lists::proper_length;
 use schemer_lang::error::Error;
 use schemer_lang::eval::environment::Exports;
 use schemer_lang::eval::{Environment, Expression, Procedure};
@@ -45,14 +46,11 @@ pub fn scheme_base_type_predicates_exports() -> Exports {
     exports
 }
 
-is_a!(is_symbol, Identifier);
 is_a!(is_boolean, Boolean);
 is_a!(is_number, Number);
-is_a!(is_vector, Vector);
 is_a!(is_char, Character);
 is_a!(is_string, String);
 is_a!(is_byte_vector, ByteVector);
-is_a!(is_procedure, Procedure);
 is_a!(is_null, Null !);
 
 fn is_atom(
@@ -66,13 +64,42 @@ fn is_atom(
     }
 }
 
+fn is_symbol(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(eboolean!(match &arguments[0] {
+        Expression::Identifier(_) => true,
+        Expression::Quotation(datum) => datum.is_symbol(),
+        _ => false,
+    }))
+}
+
+fn is_vector(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(eboolean!(match &arguments[0] {
+        Expression::Vector(_) => true,
+        Expression::Quotation(datum) => datum.is_vector(),
+        _ => false,
+    }))
+}
+
+fn is_procedure(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(eboolean!(arguments.remove(0).is_callable()))
+}
+
 fn is_pair(
     mut arguments: Vec<Expression>,
     _: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
     Ok(eboolean!(match arguments.remove(0) {
         Expression::List(_) => true,
-        Expression::Quotation(datum) => datum.is_list() && !datum.is_null(),
+        Expression::Quotation(datum) => datum.is_pair(),
         _ => false,
     }))
 }
@@ -83,8 +110,7 @@ fn is_list(
 ) -> Result<Expression, Error> {
     Ok(eboolean!(match arguments.remove(0) {
         Expression::List(_) | Expression::Null => true,
-        Expression::Quotation(datum) =>
-            datum.as_pair().unwrap().is_proper_list() || datum.is_null(),
+        Expression::Quotation(datum) => proper_length(&datum).is_some(),
         _ => false,
     }))
 }
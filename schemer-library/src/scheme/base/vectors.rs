@@ -0,0 +1,233 @@
+/*!
+Vectors, from R7RS §6.8; the conversions between vectors and strings are in the `strings` module.
+
+Vectors are shared rather than copied so that a mutation made by `vector-set!`, `vector-fill!`,
+or `vector-copy!` is visible through every reference to the same vector. Procedures that
+construct vectors, such as `vector-copy` and `vector-append`, always return a newly allocated
+vector although the elements themselves are not copied.
+
+# Example
+
+```scheme
+(define v (make-vector 3 0))
+(vector-set! v 1 'x)
+v                             ;; => #(0 x 0)
+(vector->list #(1 2 3 4) 1 3) ;; => (2 3)
+(vector-append #(a) #(b c))   ;; => #(a b c)
+```
+
+*/
+
+use crate::scheme::base::lists::list_elements;
+use crate::scheme::base::numbers::{
+    element_index_out_of_range, index, index_out_of_range, index_range,
+};
+use schemer_lang::error::{Error, ErrorKind};
+use schemer_lang::eval::environment::Exports;
+use schemer_lang::eval::{Environment, Expression, Procedure};
+use schemer_lang::read::datum::Datum;
+use schemer_lang::types::vector::TYPE_NAME_VECTOR;
+use schemer_lang::types::{Identifier, Integer, MutableRef, Number, Ref, SchemeValue, Vector};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn scheme_base_vector_exports() -> Exports {
+    let mut exports = Exports::default();
+
+    export_builtin!(exports, "make-vector" => make_vector "k" ; "fill");
+    export_builtin!(exports, "vector" => new_vector ; "obj");
+    export_builtin!(exports, "vector-length" => vector_length "vector");
+    export_builtin!(exports, "vector-ref" => vector_ref "vector" "k");
+    export_builtin!(exports, "vector-set!" => vector_set "vector" "k" "obj");
+    export_builtin!(exports, "vector->list" => vector_to_list "vector" ; "start-end");
+    export_builtin!(exports, "list->vector" => list_to_vector "list");
+    export_builtin!(exports, "vector-copy" => vector_copy "vector" ; "start-end");
+    export_builtin!(exports, "vector-copy!" => vector_copy_into "to" "at" "from" ; "start-end");
+    export_builtin!(exports, "vector-append" => vector_append ; "vector");
+    export_builtin!(exports, "vector-fill!" => vector_fill "vector" "fill" ; "start-end");
+
+    exports
+}
+
+pub fn vector_length(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Number(Number::from(Integer::from(
+        vector(&arguments[0])?.len(),
+    ))))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn make_vector(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let k = index(&arguments[0])?;
+    let fill = if arguments.len() > 1 {
+        arguments.remove(1).into_datum()
+    } else {
+        Expression::Unspecified.into_datum()
+    };
+    Ok(new_vector_value(vec![fill; k]))
+}
+
+fn new_vector(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_vector_value(
+        arguments.into_iter().map(Expression::into_datum).collect(),
+    ))
+}
+
+fn vector_ref(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let v = vector(&arguments[0])?;
+    let k = index(&arguments[1])?;
+    let element = v
+        .get(k)
+        .ok_or_else(|| element_index_out_of_range(k, v.len()))?;
+    Ok(Expression::from_datum(element))
+}
+
+fn vector_set(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let v = vector(&arguments[0])?;
+    let k = index(&arguments[1])?;
+    let length = v.len();
+    match v.as_vec_mut().get_mut(k) {
+        Some(element) => *element = arguments.remove(2).into_datum(),
+        None => return Err(element_index_out_of_range(k, length)),
+    }
+    Ok(Expression::Unspecified)
+}
+
+fn vector_to_list(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::from_list(
+        elements_in_range(&arguments, 0)?
+            .into_iter()
+            .map(Expression::from_datum)
+            .collect(),
+    ))
+}
+
+fn list_to_vector(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_vector_value(list_elements(&arguments[0])?))
+}
+
+fn vector_copy(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_vector_value(elements_in_range(&arguments, 0)?))
+}
+
+fn vector_copy_into(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let to = vector(&arguments[0])?;
+    let at = index(&arguments[1])?;
+    // collected first, as `from` may be the same vector as `to`.
+    let from = elements_in_range(&arguments, 2)?;
+    let length = to.len();
+    if at + from.len() > length {
+        return Err(index_out_of_range(at + from.len(), length));
+    }
+    to.as_vec_mut()[at..at + from.len()].clone_from_slice(&from);
+    Ok(Expression::Unspecified)
+}
+
+fn vector_append(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let mut result: Vec<Ref<Datum>> = Vec::default();
+    for argument in &arguments {
+        result.extend(vector(argument)?.as_vec().iter().cloned());
+    }
+    Ok(new_vector_value(result))
+}
+
+fn vector_fill(
+    mut arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let v = vector(&arguments[0])?;
+    let (start, end) = index_range(&arguments, 2, v.len())?;
+    let fill = arguments.remove(1).into_datum();
+    v.as_vec_mut()[start..end].fill(fill);
+    Ok(Expression::Unspecified)
+}
+
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The vector denoted by `value`, either a constructed vector or a vector constant.
+///
+pub(crate) fn vector(value: &Expression) -> Result<Vector<Datum>, Error> {
+    match value {
+        Expression::Vector(vector) => Ok(vector.clone()),
+        Expression::Quotation(datum) => match &**datum {
+            Datum::Vector(vector) => Ok(vector.clone()),
+            _ => unexpected_type!(TYPE_NAME_VECTOR, value),
+        },
+        _ => unexpected_type!(TYPE_NAME_VECTOR, value),
+    }
+}
+
+pub(crate) fn vector_elements(value: &Expression) -> Result<Vec<Expression>, Error> {
+    Ok(vector(value)?
+        .as_vec()
+        .iter()
+        .cloned()
+        .map(Expression::from_datum)
+        .collect())
+}
+
+#[inline]
+pub(crate) fn new_vector_value(elements: Vec<Ref<Datum>>) -> Expression {
+    Expression::Vector(Vector::from(elements))
+}
+
+///
+/// The elements of the vector `arguments[at]` in the optional range that follows it.
+///
+fn elements_in_range(arguments: &[Expression], at: usize) -> Result<Vec<Ref<Datum>>, Error> {
+    let v = vector(&arguments[at])?;
+    let (start, end) = index_range(arguments, at + 1, v.len())?;
+    let elements = v.as_vec()[start..end].to_vec();
+    Ok(elements)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
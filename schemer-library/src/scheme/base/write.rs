@@ -1,12 +1,24 @@
 /*!
-One-line description.
+Output, from R7RS §6.13.3; the output procedures that write characters, strings, and bytes.
+Procedures that write data, such as `write` and `display`, are exported by `(scheme write)`.
 
-More detailed description, with
+Each procedure takes an optional port which defaults to the current output port.
 
 # Example
 
- */
+```scheme
+(write-string "hello" (current-output-port))
+(write-char #\!)
+(newline)
+(flush-output-port)
+```
 
+*/
+
+use crate::scheme::base::bytevectors::{byte, byte_vector};
+use crate::scheme::base::numbers::index_range;
+use crate::scheme::base::ports::output_port;
+use crate::scheme::base::strings::{character, string};
 use schemer_lang::error::Error;
 use schemer_lang::eval::environment::Exports;
 use schemer_lang::eval::{Environment, Expression, Procedure};
@@ -27,12 +39,12 @@ use schemer_lang::types::{Identifier, MutableRef};
 pub fn scheme_base_write_exports() -> Exports {
     let mut exports = Exports::default();
 
-    export_builtin!(exports, "newline" => newline "output-port");
+    export_builtin!(exports, "newline" => newline ; "output-port");
     export_builtin!(exports, "write-char" => write_char "char" ; "output-port");
-    export_builtin!(exports, "write-string" => write_string "str" ; "output-port");
+    export_builtin!(exports, "write-string" => write_string "str" ; "output-port-start-end");
     export_builtin!(exports, "write-u8" => write_u8 "byte" ; "output-port");
-    export_builtin!(exports, "write-bytevector" => write_byte_vector "bytevector" ; "output-port");
-    export_builtin!(exports, "flush-output-port" => flush "obj" ; "output-port");
+    export_builtin!(exports, "write-bytevector" => write_byte_vector "bytevector" ; "output-port-start-end");
+    export_builtin!(exports, "flush-output-port" => flush ; "output-port");
 
     exports
 }
@@ -45,31 +57,65 @@ pub fn scheme_base_write_exports() -> Exports {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-fn newline(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+fn newline(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    output_port(&arguments, 0)?.write_char('\n')?;
+    Ok(Expression::Unspecified)
 }
 
-fn write_char(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+fn write_char(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let c = character(&arguments[0])?;
+    output_port(&arguments, 1)?.write_char(c)?;
+    Ok(Expression::Unspecified)
 }
 
-fn write_string(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+fn write_string(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let s = string(&arguments[0])?;
+    let port = output_port(&arguments, 1)?;
+    let (start, end) = index_range(&arguments, 2, s.len())?;
+    let s: String = s
+        .as_string()
+        .chars()
+        .skip(start)
+        .take(end - start)
+        .collect();
+    port.write_str(&s)?;
+    Ok(Expression::Unspecified)
 }
 
-fn write_u8(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+fn write_u8(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let value = byte(&arguments[0])?;
+    output_port(&arguments, 1)?.write_bytes(&[value])?;
+    Ok(Expression::Unspecified)
 }
 
 fn write_byte_vector(
-    _: Vec<Expression>,
+    arguments: Vec<Expression>,
     _: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let bv = byte_vector(&arguments[0])?;
+    let port = output_port(&arguments, 1)?;
+    let (start, end) = index_range(&arguments, 2, bv.len())?;
+    // copied first, the bytevector may not be borrowed while writing to a port.
+    let bytes = bv.as_bytes()[start..end].to_vec();
+    port.write_bytes(&bytes)?;
+    Ok(Expression::Unspecified)
 }
 
-fn flush(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+fn flush(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    output_port(&arguments, 0)?.flush()?;
+    Ok(Expression::Unspecified)
 }
 
 // ------------------------------------------------------------------------------------------------
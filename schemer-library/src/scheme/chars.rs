@@ -1,19 +1,36 @@
 /*!
-One-line description.
+Characters, from R7RS §6.6, along with the case-insensitive string procedures from §6.7 that
+make up the `(scheme char)` library.
 
-More detailed description, with
+The `-ci` comparisons and `char-foldcase`/`string-foldcase` all use the Unicode case folding
+table in [`char_folding`](schemer_lang::types::char_folding); `string-upcase`,
+`string-downcase`, and `string-foldcase` apply the full, possibly multi-character, mappings.
 
 # Example
 
- */
+```scheme
+(import (scheme char))
+(char-ci=? #\a #\A)          ;; => #t
+(digit-value #\3)            ;; => 3
+(string-upcase "straße")     ;; => "STRASSE"
+(string-foldcase "Straße")   ;; => "strasse"
+```
+
+*/
 
 use crate::forms::library::LibraryName;
+use crate::scheme::base::strings::{
+    character, string_ci_equal, string_ci_greater, string_ci_greater_equal, string_ci_less,
+    string_ci_less_equal, string_downcase, string_foldcase, string_upcase,
+};
 use crate::scheme::ID_LIB_SCHEME;
 use schemer_lang::error::{Error, ErrorKind};
 use schemer_lang::eval::environment::Exports;
 use schemer_lang::eval::{Environment, Expression, Procedure};
+use schemer_lang::types::char_folding::fold_char;
 use schemer_lang::types::chars::TYPE_NAME_CHAR;
-use schemer_lang::types::{Boolean, Char, Identifier, MutableRef, SchemeValue};
+use schemer_lang::types::{Boolean, Char, Identifier, Integer, MutableRef, Number, SchemeValue};
+use std::cmp::Ordering;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -23,13 +40,43 @@ use schemer_lang::types::{Boolean, Char, Identifier, MutableRef, SchemeValue};
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+macro_rules! char_ci_compare {
+    ($fn_name:ident, $( $ordering:path )|+) => {
+        pub fn $fn_name(
+            arguments: Vec<Expression>,
+            _: &mut MutableRef<Environment>,
+        ) -> Result<Expression, Error> {
+            let chars = arguments
+                .iter()
+                .map(|argument| character(argument).map(fold_char))
+                .collect::<Result<Vec<char>, Error>>()?;
+            Ok(eboolean!(chars
+                .windows(2)
+                .all(|pair| matches!(pair[0].cmp(&pair[1]), $( $ordering )|+))))
+        }
+    };
+}
+
+///
+/// The code point of the digit zero in each of the Unicode decimal digit (`Nd`) ranges; every
+/// range is ten contiguous code points from its zero.
+///
+const DECIMAL_DIGIT_ZEROS: &[u32] = &[
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x16A60, 0x16B50,
+    0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E950, 0x1FBF0,
+];
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
 library_name!(
     ID_LIB_SCHEME_CHARS,
-    "chars",
+    "char",
     ID_LIB_SCHEME,
     scheme_chars_name
 );
@@ -42,6 +89,27 @@ pub fn scheme_chars_exports() -> Exports {
     export_builtin!(exports, "char-numeric?" => is_numeric "char");
     export_builtin!(exports, "char-upper-case?" => is_upper_case "char");
     export_builtin!(exports, "char-whitespace?" => is_whitespace "char");
+    export_builtin!(exports, "digit-value" => digit_value "char");
+
+    export_builtin!(exports, "char-upcase" => char_upcase "char");
+    export_builtin!(exports, "char-downcase" => char_downcase "char");
+    export_builtin!(exports, "char-foldcase" => char_foldcase "char");
+
+    export_builtin!(exports, "char-ci=?" => char_ci_equal "char1" ; "char");
+    export_builtin!(exports, "char-ci<?" => char_ci_less "char1" ; "char");
+    export_builtin!(exports, "char-ci>?" => char_ci_greater "char1" ; "char");
+    export_builtin!(exports, "char-ci<=?" => char_ci_less_equal "char1" ; "char");
+    export_builtin!(exports, "char-ci>=?" => char_ci_greater_equal "char1" ; "char");
+
+    export_builtin!(exports, "string-upcase" => string_upcase "string");
+    export_builtin!(exports, "string-downcase" => string_downcase "string");
+    export_builtin!(exports, "string-foldcase" => string_foldcase "string");
+
+    export_builtin!(exports, "string-ci=?" => string_ci_equal "string1" ; "string");
+    export_builtin!(exports, "string-ci<?" => string_ci_less "string1" ; "string");
+    export_builtin!(exports, "string-ci>?" => string_ci_greater "string1" ; "string");
+    export_builtin!(exports, "string-ci<=?" => string_ci_less_equal "string1" ; "string");
+    export_builtin!(exports, "string-ci>=?" => string_ci_greater_equal "string1" ; "string");
 
     exports
 }
@@ -52,31 +120,61 @@ is_char_a!(is_numeric);
 is_char_a!(is_upper_case, is_uppercase);
 is_char_a!(is_whitespace);
 
-/*
-pub fn down_case(arguments: &[Expression], _: &Ref<Environment>) -> Result<Expression, Error> {
-    Ok(Expression::Character(Char::from(match &arguments[0] {
-        Expression::Character(v) => v.to_lowercase(),
-        e => {
-            return Err(Error::from(ErrorKind::UnexpectedType {
-                expected: TYPE_NAME_CHAR.to_string(),
-                actual: e.type_name(),
-            }))
-        }
-    })))
+pub fn digit_value(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let c = character(&arguments[0])?;
+    let code = c as u32;
+    Ok(
+        match DECIMAL_DIGIT_ZEROS
+            .iter()
+            .rev()
+            .find(|zero| **zero <= code)
+            .map(|zero| code - zero)
+        {
+            Some(value) if value < 10 && c.is_numeric() => einteger!(value),
+            _ => eboolean!(false),
+        },
+    )
 }
 
-pub fn up_case(arguments: &[Expression], _: &Ref<Environment>) -> Result<Expression, Error> {
-    Ok(Expression::Character(Char::from(match &arguments[0] {
-        Expression::Character(v) => v.to_uppercase(),
-        e => {
-            return Err(Error::from(ErrorKind::UnexpectedType {
-                expected: TYPE_NAME_CHAR.to_string(),
-                actual: e.type_name(),
-            }))
-        }
-    })))
+pub fn char_upcase(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let c = character(&arguments[0])?;
+    Ok(Expression::Character(Char::from(single_char(
+        c,
+        c.to_uppercase(),
+    ))))
+}
+
+pub fn char_downcase(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let c = character(&arguments[0])?;
+    Ok(Expression::Character(Char::from(single_char(
+        c,
+        c.to_lowercase(),
+    ))))
 }
-*/
+
+pub fn char_foldcase(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Character(Char::from(fold_char(character(
+        &arguments[0],
+    )?))))
+}
+
+char_ci_compare!(char_ci_equal, Ordering::Equal);
+char_ci_compare!(char_ci_less, Ordering::Less);
+char_ci_compare!(char_ci_greater, Ordering::Greater);
+char_ci_compare!(char_ci_less_equal, Ordering::Less | Ordering::Equal);
+char_ci_compare!(char_ci_greater_equal, Ordering::Greater | Ordering::Equal);
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
@@ -86,6 +184,17 @@ pub fn up_case(arguments: &[Expression], _: &Ref<Environment>) -> Result<Express
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+///
+/// A character's case mapping is only used by `char-upcase` and `char-downcase` when it is a
+/// single character, otherwise the character is returned unchanged.
+///
+fn single_char(c: char, mut mapped: impl Iterator<Item = char>) -> char {
+    match (mapped.next(), mapped.next()) {
+        (Some(mapped), None) => mapped,
+        _ => c,
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -1,18 +1,34 @@
 /*!
-One-line description.
+Complex number construction and accessors, from R7RS §6.2.6.
 
-More detailed description, with
+Constructing a complex number from exact parts produces an exact complex number, unless the
+imaginary part is an exact zero in which case the result is simply the real part.
 
 # Example
 
+```scheme
+(import (scheme complex))
+
+(make-rectangular 1 2)  ;; => 1+2i
+(real-part 3+4i)        ;; => 3
+(magnitude 3+4i)        ;; => 5
+(angle -1)              ;; => 3.141592653589793
+```
+
  */
 
 use crate::forms::library::LibraryName;
+use crate::scheme::base::numbers::{is_non_real, number, real};
+use crate::scheme::inexact::sqrt_number;
 use crate::scheme::ID_LIB_SCHEME;
+use num::Zero;
 use schemer_lang::error::Error;
 use schemer_lang::eval::environment::Exports;
 use schemer_lang::eval::{Environment, Expression, Procedure};
-use schemer_lang::types::{Identifier, MutableRef};
+use schemer_lang::types::{
+    ExactComplex, ExactReal, Identifier, InexactComplex, InexactReal, MutableRef, Number,
+};
+use std::convert::TryFrom;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -38,7 +54,7 @@ pub fn scheme_complex_exports() -> Exports {
 
     export_builtin!(exports, "angle" => angle "num");
     export_builtin!(exports, "magnitude" => magnitude "num");
-    export_builtin!(exports, "make-rectangle" => make_rectangular_complex "real" "imag");
+    export_builtin!(exports, "make-rectangular" => make_rectangular_complex "real" "imag");
     export_builtin!(exports, "make-polar" => make_polar_complex "mag" "theta");
     export_builtin!(exports, "real-part" => real_part "num");
     export_builtin!(exports, "imag-part" => imaginary_part "num");
@@ -55,36 +71,86 @@ pub fn scheme_complex_exports() -> Exports {
 // ------------------------------------------------------------------------------------------------
 
 fn make_rectangular_complex(
-    _: Vec<Expression>,
+    arguments: Vec<Expression>,
     _: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let re = real(&arguments[0])?;
+    let im = real(&arguments[1])?;
+    Ok(Expression::Number(if im.is_exact() && im.is_zero() {
+        re.clone()
+    } else if re.is_exact() && im.is_exact() {
+        ExactComplex::new(
+            ExactReal::try_from(re.clone())?,
+            ExactReal::try_from(im.clone())?,
+        )
+        .into()
+    } else {
+        InexactComplex::new(
+            InexactReal::try_from(re.clone())?,
+            InexactReal::try_from(im.clone())?,
+        )
+        .into()
+    }))
 }
 
 fn make_polar_complex(
-    _: Vec<Expression>,
+    arguments: Vec<Expression>,
     _: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    let magnitude = real(&arguments[0])?;
+    let angle = real(&arguments[1])?;
+    Ok(Expression::Number(if angle.is_exact() && angle.is_zero() {
+        magnitude.clone()
+    } else {
+        Number::from(InexactComplex::from_polar(
+            InexactReal::try_from(magnitude.clone())?,
+            InexactReal::try_from(angle.clone())?,
+        ))
+        .simplify()
+    }))
 }
 
-fn angle(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+fn angle(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let z = number(&arguments[0])?;
+    Ok(Expression::Number(if is_non_real(z) {
+        InexactComplex::try_from(z.clone())?.arg().into()
+    } else if z.is_exact() && !z.is_negative().unwrap_or_default() {
+        Number::zero()
+    } else {
+        InexactReal::zero()
+            .atan2(InexactReal::try_from(z.clone())?)
+            .into()
+    }))
 }
 
-fn magnitude(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+fn magnitude(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let z = number(&arguments[0])?;
+    Ok(Expression::Number(if !is_non_real(z) {
+        z.abs()?
+    } else if z.is_exact() {
+        let re = z.real_part();
+        let im = z.imaginary_part();
+        sqrt_number(&(re.clone() * re + im.clone() * im))?
+    } else {
+        InexactComplex::try_from(z.clone())?.norm().into()
+    }))
 }
 
-fn real_part(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+fn real_part(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Number(number(&arguments[0])?.real_part()))
 }
 
 fn imaginary_part(
-    _: Vec<Expression>,
+    arguments: Vec<Expression>,
     _: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    todo!()
+    Ok(Expression::Number(number(&arguments[0])?.imaginary_part()))
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -1,15 +1,27 @@
 /*!
-One-line description.
-
-More detailed description, with
+The three and four level `car` and `cdr` compositions, from R7RS §6.4; the two level
+compositions such as `cadr` are part of `(scheme base)`.
 
 # Example
 
+```scheme
+(import (scheme cxr))
+
+(caddr '(1 2 3 4))  ;; => 3
+(cddddr '(1 2 3 4)) ;; => ()
+```
+
 */
 
 use crate::forms::library::LibraryName;
+use crate::scheme::base::lists::{
+    caaaar, caaadr, caaar, caadar, caaddr, caadr, cadaar, cadadr, cadar, caddar, cadddr, caddr,
+    cdaaar, cdaadr, cdaar, cdadar, cdaddr, cdadr, cddaar, cddadr, cddar, cdddar, cddddr, cdddr,
+};
 use crate::scheme::ID_LIB_SCHEME;
 use schemer_lang::eval::environment::Exports;
+use schemer_lang::eval::{Expression, Procedure};
+use schemer_lang::types::Identifier;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -26,7 +38,34 @@ use schemer_lang::eval::environment::Exports;
 library_name!(ID_LIB_SCHEME_CXR, "cxr", ID_LIB_SCHEME, scheme_cxr_name);
 
 pub fn scheme_cxr_exports() -> Exports {
-    Exports::default()
+    let mut exports = Exports::default();
+
+    export_builtin!(exports, "caaar" => caaar "pair");
+    export_builtin!(exports, "caadr" => caadr "pair");
+    export_builtin!(exports, "cadar" => cadar "pair");
+    export_builtin!(exports, "caddr" => caddr "pair");
+    export_builtin!(exports, "cdaar" => cdaar "pair");
+    export_builtin!(exports, "cdadr" => cdadr "pair");
+    export_builtin!(exports, "cddar" => cddar "pair");
+    export_builtin!(exports, "cdddr" => cdddr "pair");
+    export_builtin!(exports, "caaaar" => caaaar "pair");
+    export_builtin!(exports, "caaadr" => caaadr "pair");
+    export_builtin!(exports, "caadar" => caadar "pair");
+    export_builtin!(exports, "caaddr" => caaddr "pair");
+    export_builtin!(exports, "cadaar" => cadaar "pair");
+    export_builtin!(exports, "cadadr" => cadadr "pair");
+    export_builtin!(exports, "caddar" => caddar "pair");
+    export_builtin!(exports, "cadddr" => cadddr "pair");
+    export_builtin!(exports, "cdaaar" => cdaaar "pair");
+    export_builtin!(exports, "cdaadr" => cdaadr "pair");
+    export_builtin!(exports, "cdadar" => cdadar "pair");
+    export_builtin!(exports, "cdaddr" => cdaddr "pair");
+    export_builtin!(exports, "cddaar" => cddaar "pair");
+    export_builtin!(exports, "cddadr" => cddadr "pair");
+    export_builtin!(exports, "cdddar" => cdddar "pair");
+    export_builtin!(exports, "cddddr" => cddddr "pair");
+
+    exports
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -1,21 +1,35 @@
 /*!
-One-line description.
+The `(scheme file)` library, from R7RS §6.13; file ports and the file system procedures.
 
-More detailed description, with
+Any failure to open, read, or write a file is reported as an error that satisfies `file-error?`.
+The `call-with-` and `with-` procedures close their port once the procedure, or thunk, given to
+them returns.
 
 # Example
 
+```scheme
+(import (scheme file))
+(with-output-to-file "greeting.txt"
+  (lambda () (write-string "hello")))
+(call-with-input-file "greeting.txt" read-line) ;; => "hello"
+(delete-file "greeting.txt")
+(guard (e ((file-error? e) 'missing))
+  (open-input-file "greeting.txt"))            ;; => missing
+```
+
  */
 
 use crate::forms::library::LibraryName;
+use crate::scheme::base::strings::string;
 use crate::scheme::ID_LIB_SCHEME;
 use schemer_lang::error::{Error, ErrorKind};
 use schemer_lang::eval::environment::Exports;
-use schemer_lang::eval::{Environment, Expression, Procedure};
+use schemer_lang::eval::ports::{with_current_input_port, with_current_output_port};
+use schemer_lang::eval::{Environment, Expression, Port, Procedure};
 use schemer_lang::types::strings::TYPE_NAME_STRING;
 use schemer_lang::types::{Boolean, Identifier, MutableRef, SchemeValue};
-use std::fs;
-use std::ops::Deref;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
 use std::path::PathBuf;
 
 // ------------------------------------------------------------------------------------------------
@@ -38,6 +52,15 @@ pub fn scheme_file_exports() -> Exports {
     export_builtin!(exports, "delete-file" => delete_file "file-name");
     export_builtin!(exports, "file-exists?" => file_exists "file-name");
 
+    export_builtin!(exports, "open-input-file" => open_input_file "file-name");
+    export_builtin!(exports, "open-binary-input-file" => open_binary_input_file "file-name");
+    export_builtin!(exports, "open-output-file" => open_output_file "file-name");
+    export_builtin!(exports, "open-binary-output-file" => open_binary_output_file "file-name");
+    export_builtin!(exports, "call-with-input-file" => call_with_input_file "file-name" "proc");
+    export_builtin!(exports, "call-with-output-file" => call_with_output_file "file-name" "proc");
+    export_builtin!(exports, "with-input-from-file" => with_input_from_file "file-name" "thunk");
+    export_builtin!(exports, "with-output-to-file" => with_output_to_file "file-name" "thunk");
+
     exports
 }
 
@@ -55,7 +78,7 @@ fn delete_file(
 ) -> Result<Expression, Error> {
     match &arguments[0] {
         Expression::String(file_name) => {
-            let file = PathBuf::from(file_name.deref());
+            let file = PathBuf::from(&*file_name.as_string());
             fs::remove_file(file)?;
         }
         e => {
@@ -71,7 +94,7 @@ fn file_exists(
 ) -> Result<Expression, Error> {
     Ok(eboolean!(match &arguments[0] {
         Expression::String(file_name) => {
-            let file = PathBuf::from(file_name.deref());
+            let file = PathBuf::from(&*file_name.as_string());
             file.exists()
         }
         e => {
@@ -80,6 +103,117 @@ fn file_exists(
     }))
 }
 
+// ------------------------------------------------------------------------------------------------
+
+fn open_input_file(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Port(input_file(&arguments[0], true)?))
+}
+
+fn open_binary_input_file(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Port(input_file(&arguments[0], false)?))
+}
+
+fn open_output_file(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Port(output_file(&arguments[0], true)?))
+}
+
+fn open_binary_output_file(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(Expression::Port(output_file(&arguments[0], false)?))
+}
+
+fn call_with_input_file(
+    arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let port = input_file(&arguments[0], true)?;
+    call_and_close(port.clone(), || {
+        arguments[1].call(vec![Expression::Port(port)], environment)
+    })
+}
+
+fn call_with_output_file(
+    arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let port = output_file(&arguments[0], true)?;
+    call_and_close(port.clone(), || {
+        arguments[1].call(vec![Expression::Port(port)], environment)
+    })
+}
+
+fn with_input_from_file(
+    arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let port = input_file(&arguments[0], true)?;
+    call_and_close(port.clone(), || {
+        with_current_input_port(port, || arguments[1].call(Vec::default(), environment))
+    })
+}
+
+fn with_output_to_file(
+    arguments: Vec<Expression>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let port = output_file(&arguments[0], true)?;
+    call_and_close(port.clone(), || {
+        with_current_output_port(port, || arguments[1].call(Vec::default(), environment))
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+
+fn file_name(value: &Expression) -> Result<String, Error> {
+    Ok(string(value)?.to_string())
+}
+
+fn input_file(value: &Expression, textual: bool) -> Result<Port, Error> {
+    let file_name = file_name(value)?;
+    let reader = BufReader::new(File::open(&file_name)?);
+    Ok(if textual {
+        Port::new_textual_input(&file_name, reader)
+    } else {
+        Port::new_binary_input(&file_name, reader)
+    })
+}
+
+fn output_file(value: &Expression, textual: bool) -> Result<Port, Error> {
+    let file_name = file_name(value)?;
+    let writer = BufWriter::new(File::create(&file_name)?);
+    Ok(if textual {
+        Port::new_textual_output(&file_name, writer)
+    } else {
+        Port::new_binary_output(&file_name, writer)
+    })
+}
+
+///
+/// Call `f`, closing `port` once it returns; an error closing the port is only reported if `f`
+/// itself succeeded.
+///
+fn call_and_close(
+    port: Port,
+    f: impl FnOnce() -> Result<Expression, Error>,
+) -> Result<Expression, Error> {
+    let result = f();
+    let closed = port.close();
+    let result = result?;
+    closed?;
+    Ok(result)
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
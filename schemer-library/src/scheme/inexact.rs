@@ -1,19 +1,36 @@
 /*!
-One-line description.
+Inexact and transcendental functions, from R7RS §6.2.6.
 
-More detailed description, with
+Each function accepts any number; a real argument outside the real domain of the function, such
+as the square root of a negative number, produces a complex result. Results are inexact except
+where an exact argument has an exact result, as for `(sqrt 16)` or `(exp 0)`.
 
 # Example
 
+```scheme
+(import (scheme inexact))
+
+(sqrt 16)   ;; => 4
+(sqrt -4)   ;; => +2i
+(sqrt 2.0)  ;; => 1.4142135623730951
+(log 100 10) ;; => 2.0
+```
+
 */
 
 use crate::forms::library::LibraryName;
+use crate::scheme::base::numbers::{check_exact_divisor, is_non_real, number, real};
 use crate::scheme::ID_LIB_SCHEME;
+use num::{One, Signed, Zero};
 use schemer_lang::error::{Error, ErrorKind};
 use schemer_lang::eval::environment::Exports;
 use schemer_lang::eval::{Environment, Expression, Procedure};
 use schemer_lang::types::numbers::TYPE_NAME_NUMBER;
-use schemer_lang::types::{Boolean, Identifier, MutableRef, Number, SchemeValue};
+use schemer_lang::types::{
+    Boolean, ExactComplex, ExactReal, Identifier, InexactComplex, InexactReal, Integer, MutableRef,
+    Number, Rational, SchemeValue,
+};
+use std::convert::TryFrom;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -41,9 +58,43 @@ pub fn scheme_inexact_exports() -> Exports {
     export_builtin!(exports, "infinite?" => is_infinite "num");
     export_builtin!(exports, "nan?" => is_nan "num");
 
+    export_builtin!(exports, "exp" => exp "z");
+    export_builtin!(exports, "log" => log "z" ; "z2");
+    export_builtin!(exports, "sin" => sin "z");
+    export_builtin!(exports, "cos" => cos "z");
+    export_builtin!(exports, "tan" => tan "z");
+    export_builtin!(exports, "asin" => asin "z");
+    export_builtin!(exports, "acos" => acos "z");
+    export_builtin!(exports, "atan" => atan "z" ; "x");
+    export_builtin!(exports, "sqrt" => sqrt "z");
+
     exports
 }
 
+///
+/// The square root of `z`, exact if `z` is an exact rational whose numerator and denominator
+/// are both perfect squares, or the negation of one.
+///
+pub(crate) fn sqrt_number(z: &Number) -> Result<Number, Error> {
+    if z.is_exact() && !is_non_real(z) {
+        let q = Rational::try_from(z.clone())?;
+        if let Some(root) = exact_rational_sqrt(&q.abs()) {
+            let root = Number::from(root);
+            return Ok(if q.is_negative() {
+                Number::from(ExactComplex::new(
+                    ExactReal::zero(),
+                    ExactReal::try_from(root)?,
+                ))
+            } else if matches!(z, Number::ExactReal(_)) {
+                Number::from(ExactReal::try_from(root)?.normalize())
+            } else {
+                root.simplify()
+            });
+        }
+    }
+    transcendental(z, f64::sqrt, InexactComplex::sqrt, |x| x >= 0.0)
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
@@ -56,6 +107,176 @@ is_number_a!(is_finite);
 is_number_a!(is_infinite);
 is_number_a!(is_nan);
 
+fn exp(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let z = number(&arguments[0])?;
+    if is_exact_value(z, 0) {
+        return Ok(Expression::Number(Integer::one().into()));
+    }
+    Ok(Expression::Number(transcendental(
+        z,
+        f64::exp,
+        InexactComplex::exp,
+        |_| true,
+    )?))
+}
+
+fn log(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let z = number(&arguments[0])?;
+    match arguments.len() {
+        1 => Ok(Expression::Number(natural_log(z)?)),
+        2 => {
+            let base = natural_log(number(&arguments[1])?)?;
+            check_exact_divisor(&base)?;
+            Ok(Expression::Number(natural_log(z)? / base))
+        }
+        given => Err(Error::from(ErrorKind::ProcedureArgumentCardinality {
+            name: Identifier::from_str_unchecked("log"),
+            min: 1,
+            max: Some(2),
+            given,
+        })),
+    }
+}
+
+fn sin(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let z = number(&arguments[0])?;
+    if is_exact_value(z, 0) {
+        return Ok(Expression::Number(z.clone()));
+    }
+    Ok(Expression::Number(transcendental(
+        z,
+        f64::sin,
+        InexactComplex::sin,
+        |_| true,
+    )?))
+}
+
+fn cos(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let z = number(&arguments[0])?;
+    if is_exact_value(z, 0) {
+        return Ok(Expression::Number(Integer::one().into()));
+    }
+    Ok(Expression::Number(transcendental(
+        z,
+        f64::cos,
+        InexactComplex::cos,
+        |_| true,
+    )?))
+}
+
+fn tan(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let z = number(&arguments[0])?;
+    if is_exact_value(z, 0) {
+        return Ok(Expression::Number(z.clone()));
+    }
+    Ok(Expression::Number(transcendental(
+        z,
+        f64::tan,
+        InexactComplex::tan,
+        |_| true,
+    )?))
+}
+
+fn asin(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let z = number(&arguments[0])?;
+    if is_exact_value(z, 0) {
+        return Ok(Expression::Number(z.clone()));
+    }
+    Ok(Expression::Number(transcendental(
+        z,
+        f64::asin,
+        InexactComplex::asin,
+        |x| x.abs() <= 1.0,
+    )?))
+}
+
+fn acos(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let z = number(&arguments[0])?;
+    if is_exact_value(z, 1) {
+        return Ok(Expression::Number(Integer::zero().into()));
+    }
+    Ok(Expression::Number(transcendental(
+        z,
+        f64::acos,
+        InexactComplex::acos,
+        |x| x.abs() <= 1.0,
+    )?))
+}
+
+fn atan(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    match arguments.len() {
+        1 => {
+            let z = number(&arguments[0])?;
+            if is_exact_value(z, 0) {
+                return Ok(Expression::Number(z.clone()));
+            }
+            Ok(Expression::Number(transcendental(
+                z,
+                f64::atan,
+                InexactComplex::atan,
+                |_| true,
+            )?))
+        }
+        2 => {
+            let y = InexactReal::try_from(real(&arguments[0])?.clone())?;
+            let x = InexactReal::try_from(real(&arguments[1])?.clone())?;
+            Ok(Expression::Number(y.atan2(x).into()))
+        }
+        given => Err(Error::from(ErrorKind::ProcedureArgumentCardinality {
+            name: Identifier::from_str_unchecked("atan"),
+            min: 1,
+            max: Some(2),
+            given,
+        })),
+    }
+}
+
+fn sqrt(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    Ok(Expression::Number(sqrt_number(number(&arguments[0])?)?))
+}
+
+fn natural_log(z: &Number) -> Result<Number, Error> {
+    if is_exact_value(z, 1) {
+        Ok(Integer::zero().into())
+    } else {
+        transcendental(z, f64::ln, InexactComplex::ln, |x| x >= 0.0)
+    }
+}
+
+///
+/// Apply either `real_fn`, if `z` is real and within `real_domain`, or `complex_fn`. A NaN
+/// argument is always considered to be in the real domain.
+///
+fn transcendental(
+    z: &Number,
+    real_fn: fn(InexactReal) -> InexactReal,
+    complex_fn: fn(InexactComplex) -> InexactComplex,
+    real_domain: fn(InexactReal) -> bool,
+) -> Result<Number, Error> {
+    if !is_non_real(z) {
+        let x = InexactReal::try_from(z.clone())?;
+        if x.is_nan() || real_domain(x) {
+            return Ok(real_fn(x).into());
+        }
+    }
+    Ok(Number::from(complex_fn(InexactComplex::try_from(z.clone())?)).simplify())
+}
+
+#[inline]
+fn is_exact_value(z: &Number, value: i32) -> bool {
+    matches!(z, Number::Integer(v) if *v == Integer::from(value))
+}
+
+fn exact_rational_sqrt(q: &Rational) -> Option<Rational> {
+    let numer = q.numer().sqrt();
+    let denom = q.denom().sqrt();
+    if &(&numer * &numer) == q.numer() && &(&denom * &denom) == q.denom() {
+        Some(Rational::new(numer, denom))
+    } else {
+        None
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -74,7 +74,7 @@ pub fn get_environment_variable(
     _: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
     Ok(match &arguments[0] {
-        Expression::String(name) => match std::env::var(name.as_str()) {
+        Expression::String(name) => match std::env::var(&*name.as_string()) {
             Err(_) => efalse!(),
             Ok(value) => estring!(value),
         },
@@ -8,9 +8,12 @@ More detailed description, with
 */
 
 use crate::forms::library::LibraryName;
+use crate::scheme::base::booleans::scheme_base_boolean_exports;
+use crate::scheme::base::equivalence::scheme_base_equivalence_exports;
 use crate::scheme::base::ports::scheme_base_ports_exports;
 use crate::scheme::base::strings::scheme_base_string_exports;
 use crate::scheme::base::types::scheme_base_type_predicates_exports;
+use crate::scheme::base::vectors::scheme_base_vector_exports;
 use crate::scheme::base::write::scheme_base_write_exports;
 use crate::scheme::chars::scheme_chars_exports;
 use crate::scheme::eval::scheme_eval_exports;
@@ -46,9 +49,26 @@ pub fn scheme_r5rs_exports() -> Exports {
 
     // (scheme base) ------------------------------------------------------------------------------
 
+    exports.import(
+        scheme_base_boolean_exports()
+            .only(&[&Identifier::from_str_unchecked("not")])
+            .clone(),
+    );
+    exports.import(scheme_base_equivalence_exports());
     exports.import(
         scheme_base_ports_exports()
-            .except(&[&Identifier::from_str_unchecked("current-error-port")])
+            .only(&[
+                &Identifier::from_str_unchecked("current-input-port"),
+                &Identifier::from_str_unchecked("current-output-port"),
+                &Identifier::from_str_unchecked("input-port?"),
+                &Identifier::from_str_unchecked("output-port?"),
+                &Identifier::from_str_unchecked("close-input-port"),
+                &Identifier::from_str_unchecked("close-output-port"),
+                &Identifier::from_str_unchecked("read-char"),
+                &Identifier::from_str_unchecked("peek-char"),
+                &Identifier::from_str_unchecked("char-ready?"),
+                &Identifier::from_str_unchecked("eof-object?"),
+            ])
             .clone(),
     );
     exports.import(scheme_base_string_exports());
@@ -61,6 +81,20 @@ pub fn scheme_r5rs_exports() -> Exports {
             ])
             .clone(),
     );
+    exports.import(
+        scheme_base_vector_exports()
+            .only(&[
+                &Identifier::from_str_unchecked("make-vector"),
+                &Identifier::from_str_unchecked("vector"),
+                &Identifier::from_str_unchecked("vector-length"),
+                &Identifier::from_str_unchecked("vector-ref"),
+                &Identifier::from_str_unchecked("vector-set!"),
+                &Identifier::from_str_unchecked("vector->list"),
+                &Identifier::from_str_unchecked("list->vector"),
+                &Identifier::from_str_unchecked("vector-fill!"),
+            ])
+            .clone(),
+    );
     exports.import(
         scheme_base_write_exports()
             .only(&[
@@ -109,7 +143,7 @@ pub fn null_environment(
 ) -> Result<Expression, Error> {
     match &arguments[0] {
         Expression::Number(Number::Integer(v)) => Ok(Expression::Environment(
-            make_preset_environment(PresetEnvironmentKind::Null(*v))?,
+            make_preset_environment(PresetEnvironmentKind::Null(v.clone()))?,
         )),
         e => unexpected_type!(TYPE_NAME_INTEGER, e),
     }
@@ -121,7 +155,7 @@ fn scheme_report_environment(
 ) -> Result<Expression, Error> {
     match &arguments[0] {
         Expression::Number(Number::Integer(v)) => Ok(Expression::Environment(
-            make_preset_environment(PresetEnvironmentKind::Report(*v))?,
+            make_preset_environment(PresetEnvironmentKind::Report(v.clone()))?,
         )),
         e => unexpected_type!(TYPE_NAME_INTEGER, e),
     }
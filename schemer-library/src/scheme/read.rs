@@ -1,18 +1,31 @@
 /*!
-One-line description.
+The `(scheme read)` library, from R7RS §6.13.2; reading data in their external representation.
 
-More detailed description, with
+`read` reads a single datum from a textual input port, leaving any characters that follow it
+unread, and returns the EOF object if there are no more data in the port. Malformed input, or
+input that ends within a datum, raises an error that satisfies `read-error?`.
 
 # Example
 
+```scheme
+(import (scheme read))
+(define p (open-input-string "(a . b) #(1 2) ; the end"))
+(read p)                       ;; => (a . b)
+(read-char p)                  ;; => #\space
+(read p)                       ;; => #(1 2)
+(eof-object? (read p))         ;; => #t
+```
+
  */
 
 use crate::forms::library::LibraryName;
+use crate::scheme::base::ports::input_port;
 use crate::scheme::ID_LIB_SCHEME;
 use schemer_lang::error::Error;
 use schemer_lang::eval::environment::Exports;
 use schemer_lang::eval::{Environment, Expression, Procedure};
 use schemer_lang::types::{Identifier, MutableRef};
+use schemer_parse::reader::read_datum;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -31,7 +44,7 @@ library_name!(ID_LIB_SCHEME_READ, "read", ID_LIB_SCHEME, scheme_read_name);
 pub fn scheme_read_exports() -> Exports {
     let mut exports = Exports::default();
 
-    export_builtin!(exports, "read" => read "input-port");
+    export_builtin!(exports, "read" => read ; "input-port");
 
     exports
 }
@@ -44,8 +57,12 @@ pub fn scheme_read_exports() -> Exports {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-fn read(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+fn read(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    let mut port = input_port(&arguments, 0)?;
+    Ok(match read_datum(&mut port)? {
+        Some(datum) => Expression::from_datum(datum),
+        None => Expression::EofObject,
+    })
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -9,7 +9,6 @@ More detailed description, with
 
 use crate::forms::library::LibraryName;
 use crate::scheme::ID_LIB_SCHEME;
-use num::traits::ToPrimitive;
 use schemer_lang::error::Error;
 use schemer_lang::eval::environment::Exports;
 use schemer_lang::eval::{Environment, Expression, Procedure};
@@ -24,7 +23,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
-pub const JIFFIES_PER_SECOND: Integer = 1_000_000;
+pub const JIFFIES_PER_SECOND: u32 = 1_000_000;
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
@@ -55,16 +54,8 @@ pub fn current_jiffy(
     _: Vec<Expression>,
     _: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
-    // TODO: remove unwraps
     let duration = SystemTime::now().duration_since(UNIX_EPOCH)?;
-    let secs = duration
-        .as_secs()
-        .to_i64()
-        .and_then(|s| s.checked_mul(JIFFIES_PER_SECOND))
-        .unwrap();
-    let sub_secs = duration.as_micros().to_i64().unwrap();
-
-    Ok(einteger!(secs.checked_add(sub_secs).unwrap()))
+    Ok(einteger!(duration.as_micros()))
 }
 
 pub fn jiffies_per_second(
@@ -1,18 +1,52 @@
 /*!
-One-line description.
+The `(scheme write)` library, from R7RS §6.13.3; writing data in their external representation.
 
-More detailed description, with
+* `write` uses datum labels only for the pairs and vectors that are part of a cycle, so that the
+  output always terminates.
+* `write-shared` uses datum labels for every pair and vector that appears more than once.
+* `write-simple` never uses datum labels, and so does not terminate on circular data.
+* `display` writes as `write` does, except that strings, characters, and symbols are written as
+  their content rather than as literals.
+
+Strings are written with `"`, `\`, and control characters escaped, and a symbol whose name is
+not an identifier is written between `|` delimiters, so that the output of `write` can be read.
+
+Labels are assigned in the order in which they are written, starting at `0`. The global flags
+`write-boolean-long-form`, `write-cons-long-form`, and `write-quote-long-form` select `#true`
+over `#t`, `(a . (b . ()))` over `(a b)`, and `(quote a)` over `'a` respectively.
 
 # Example
 
+```scheme
+(import (scheme write))
+(define x (list 1 2))
+(set-cdr! (cdr x) x)
+(write x)                      ;; => #0=(1 2 . #0#)
+(define y (list 'a))
+(write-shared (list y y))      ;; => (#0=(a) #0#)
+(write (list y y))             ;; => ((a) (a))
+(display '("a" #\b c))         ;; => (a b c)
+```
+
  */
 
 use crate::forms::library::LibraryName;
+use crate::scheme::base::ports::output_port;
 use crate::scheme::ID_LIB_SCHEME;
 use schemer_lang::error::Error;
 use schemer_lang::eval::environment::Exports;
 use schemer_lang::eval::{Environment, Expression, Procedure};
-use schemer_lang::types::{Identifier, MutableRef};
+use schemer_lang::parameters::{get_global_flag, WRITE_CONS_LONG_FORM, WRITE_QUOTE_LONG_FORM};
+use schemer_lang::read::datum::{Datum, Label};
+use schemer_lang::read::syntax_str::{
+    SYNTAX_CONS_DOT, SYNTAX_DOUBLE_QUOTE_CHAR, SYNTAX_HASH_CHAR, SYNTAX_LEFT_PARENTHESIS_CHAR,
+    SYNTAX_MATH_EQUALITY_CHAR, SYNTAX_NULL_LIST, SYNTAX_RIGHT_PARENTHESIS_CHAR, SYNTAX_SPACE_CHAR,
+    SYNTAX_VECTOR_PREFIX, SYNTAX_VERTICAL_LINE_CHAR,
+};
+use schemer_lang::types::strings::{escape_delimited, escape_string};
+use schemer_lang::types::{Identifier, MutableRef, Pair, Ref, SchemeRepr, Vector};
+use schemer_parse::parser::parse_number_str;
+use std::collections::{HashMap, HashSet};
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -22,6 +56,31 @@ use schemer_lang::types::{Identifier, MutableRef};
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Which compound data are given datum labels when written.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Labels {
+    None,
+    Cycles,
+    Shared,
+}
+
+///
+/// The identity of a pair, or vector, while it is being written.
+///
+type Key = *const ();
+
+///
+/// Replaces each labeled datum with `Datum::Labeled` where it is first written and with
+/// `Datum::LabelRef` wherever it is written again.
+///
+struct Labeler {
+    labeled: HashSet<Key>,
+    assigned: HashMap<Key, Label>,
+    next: Label,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
@@ -48,24 +107,305 @@ pub fn scheme_write_exports() -> Exports {
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl Labeler {
+    fn new(labeled: HashSet<Key>) -> Self {
+        Self {
+            labeled,
+            assigned: Default::default(),
+            next: 0,
+        }
+    }
+
+    fn label(&mut self, datum: &Ref<Datum>) -> Ref<Datum> {
+        match datum_key(datum) {
+            Some(key) if self.labeled.contains(&key) => match self.assigned.get(&key) {
+                Some(label) => Ref::new(Datum::LabelRef(*label)),
+                None => {
+                    let label = self.next;
+                    self.next += 1;
+                    let _ = self.assigned.insert(key, label);
+                    Ref::new(Datum::Labeled(label, self.copy(datum)))
+                }
+            },
+            _ => self.copy(datum),
+        }
+    }
+
+    fn copy(&mut self, datum: &Ref<Datum>) -> Ref<Datum> {
+        match &**datum {
+            Datum::List(pair) => {
+                // the car is labeled first, as it is written first.
+                let car = self.label(&pair.car());
+                Ref::new(Datum::List(Pair::cons(car, self.label(&pair.cdr()))))
+            }
+            Datum::Vector(vector) => Ref::new(Datum::Vector(Vector::from(
+                vector
+                    .as_vec()
+                    .iter()
+                    .map(|element| self.label(element))
+                    .collect::<Vec<Ref<Datum>>>(),
+            ))),
+            Datum::Abbreviation(abbreviation, quoted) => Ref::new(Datum::Abbreviation(
+                abbreviation.clone(),
+                self.label(quoted),
+            )),
+            _ => datum.clone(),
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-fn display(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+fn display(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    write_to_port(&arguments, Labels::Cycles, true)
+}
+
+fn write(arguments: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
+    write_to_port(&arguments, Labels::Cycles, false)
+}
+
+fn write_simple(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    write_to_port(&arguments, Labels::None, false)
+}
+
+fn write_shared(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    write_to_port(&arguments, Labels::Shared, false)
+}
+
+// ------------------------------------------------------------------------------------------------
+
+fn write_to_port(
+    arguments: &[Expression],
+    labels: Labels,
+    human: bool,
+) -> Result<Expression, Error> {
+    let port = output_port(arguments, 1)?;
+    let datum = arguments[0].clone().into_datum();
+    let datum = match labels {
+        Labels::None => datum,
+        Labels::Cycles => label_datum(&datum, find_cycles(&datum)),
+        Labels::Shared => label_datum(&datum, find_shared(&datum)),
+    };
+    let mut result = String::new();
+    write_datum(&datum, human, &mut result);
+    port.write_str(&result)?;
+    Ok(Expression::Unspecified)
+}
+
+fn label_datum(datum: &Ref<Datum>, labeled: HashSet<Key>) -> Ref<Datum> {
+    if labeled.is_empty() {
+        datum.clone()
+    } else {
+        Labeler::new(labeled).label(datum)
+    }
+}
+
+///
+/// Pairs are identified by the datum that holds them, vectors by their shared storage; other
+/// data are never labeled.
+///
+fn datum_key(datum: &Ref<Datum>) -> Option<Key> {
+    match &**datum {
+        Datum::List(_) => Some(Ref::as_ptr(datum) as Key),
+        Datum::Vector(vector) => Some(vector.as_ptr()),
+        _ => None,
+    }
+}
+
+fn datum_children(datum: &Datum) -> Vec<Ref<Datum>> {
+    match datum {
+        Datum::List(pair) => vec![pair.car(), pair.cdr()],
+        Datum::Vector(vector) => vector.as_vec().clone(),
+        Datum::Abbreviation(_, quoted) | Datum::Labeled(_, quoted) => vec![quoted.clone()],
+        _ => Vec::default(),
+    }
+}
+
+///
+/// The pairs and vectors reachable from `datum` that are reachable from themselves. The graph is
+/// walked with an explicit stack so that long lists do not exhaust the Rust stack.
+///
+fn find_cycles(datum: &Ref<Datum>) -> HashSet<Key> {
+    enum Step {
+        Enter(Ref<Datum>),
+        Leave(Key),
+    }
+    let mut in_progress: HashSet<Key> = Default::default();
+    let mut done: HashSet<Key> = Default::default();
+    let mut cycles: HashSet<Key> = Default::default();
+    let mut stack = vec![Step::Enter(datum.clone())];
+    while let Some(step) = stack.pop() {
+        match step {
+            Step::Enter(datum) => match datum_key(&datum) {
+                Some(key) if in_progress.contains(&key) => {
+                    let _ = cycles.insert(key);
+                }
+                Some(key) if done.contains(&key) => {}
+                key => {
+                    if let Some(key) = key {
+                        let _ = in_progress.insert(key);
+                        stack.push(Step::Leave(key));
+                    }
+                    stack.extend(datum_children(&datum).into_iter().map(Step::Enter));
+                }
+            },
+            Step::Leave(key) => {
+                let _ = in_progress.remove(&key);
+                let _ = done.insert(key);
+            }
+        }
+    }
+    cycles
+}
+
+///
+/// The pairs and vectors that are reachable from `datum` by more than one path.
+///
+fn find_shared(datum: &Ref<Datum>) -> HashSet<Key> {
+    let mut seen: HashSet<Key> = Default::default();
+    let mut shared: HashSet<Key> = Default::default();
+    let mut stack = vec![datum.clone()];
+    while let Some(datum) = stack.pop() {
+        if let Some(key) = datum_key(&datum) {
+            if !seen.insert(key) {
+                let _ = shared.insert(key);
+                continue;
+            }
+        }
+        stack.extend(datum_children(&datum));
+    }
+    shared
 }
 
-fn write(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+fn write_datum(datum: &Datum, human: bool, result: &mut String) {
+    match datum {
+        Datum::String(s) if human => result.push_str(&s.as_string()),
+        Datum::String(s) => {
+            result.push(SYNTAX_DOUBLE_QUOTE_CHAR);
+            result.push_str(&escape_string(&s.as_string()));
+            result.push(SYNTAX_DOUBLE_QUOTE_CHAR);
+        }
+        Datum::Character(c) if human => result.push(**c),
+        Datum::Symbol(id) if human || is_written_as_is(id) => result.push_str(id.as_str()),
+        Datum::Symbol(id) => {
+            result.push(SYNTAX_VERTICAL_LINE_CHAR);
+            result.push_str(&escape_delimited(id.as_str(), SYNTAX_VERTICAL_LINE_CHAR));
+            result.push(SYNTAX_VERTICAL_LINE_CHAR);
+        }
+        Datum::Null => result.push_str(SYNTAX_NULL_LIST),
+        Datum::List(pair) => write_pair(pair, human, result),
+        Datum::Vector(vector) => {
+            result.push_str(SYNTAX_VECTOR_PREFIX);
+            result.push(SYNTAX_LEFT_PARENTHESIS_CHAR);
+            for (i, element) in vector.as_vec().iter().enumerate() {
+                if i > 0 {
+                    result.push(SYNTAX_SPACE_CHAR);
+                }
+                write_datum(element, human, result);
+            }
+            result.push(SYNTAX_RIGHT_PARENTHESIS_CHAR);
+        }
+        Datum::Abbreviation(abbreviation, quoted) => {
+            if get_global_flag(WRITE_QUOTE_LONG_FORM).unwrap_or_default() {
+                result.push(SYNTAX_LEFT_PARENTHESIS_CHAR);
+                result.push_str(&format!("{:#}", abbreviation));
+                result.push(SYNTAX_SPACE_CHAR);
+                write_datum(quoted, human, result);
+                result.push(SYNTAX_RIGHT_PARENTHESIS_CHAR);
+            } else {
+                result.push_str(&abbreviation.to_string());
+                write_datum(quoted, human, result);
+            }
+        }
+        Datum::Labeled(label, labeled) => {
+            result.push_str(&format!(
+                "{}{}{}",
+                SYNTAX_HASH_CHAR, label, SYNTAX_MATH_EQUALITY_CHAR
+            ));
+            write_datum(labeled, human, result);
+        }
+        _ => result.push_str(&datum.to_repr_string()),
+    }
 }
 
-fn write_simple(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+///
+/// Whether the symbol `id` can be written without `|` delimiters; either its name is an
+/// identifier that would not be read as a number, or it is already delimited, as the reader
+/// keeps the delimiters in the name.
+///
+fn is_written_as_is(id: &Identifier) -> bool {
+    fn is_initial(c: char) -> bool {
+        c.is_ascii_alphabetic() || "!$%&*/:<=>?^_~".contains(c)
+    }
+    fn is_subsequent(c: char) -> bool {
+        is_initial(c) || c.is_ascii_digit() || "+-.@".contains(c)
+    }
+    fn is_sign_subsequent(c: char) -> bool {
+        is_initial(c) || "+-@".contains(c)
+    }
+    let name = id.as_str();
+    if parse_number_str(name).is_ok() {
+        return false;
+    }
+    let mut chars = name.chars();
+    match (chars.next(), chars.next()) {
+        (None, _) => false,
+        (Some(SYNTAX_VERTICAL_LINE_CHAR), Some(_)) => name.ends_with(SYNTAX_VERTICAL_LINE_CHAR),
+        (Some('+' | '-'), None) => true,
+        (Some('+' | '-'), Some('.')) | (Some('.'), Some(_)) => {
+            let mut rest = name
+                .strip_prefix(['+', '-'])
+                .unwrap_or(name)
+                .chars()
+                .skip(1);
+            matches!(rest.next(), Some(c) if c == '.' || is_sign_subsequent(c))
+                && rest.all(is_subsequent)
+        }
+        (Some('+' | '-'), Some(c)) => is_sign_subsequent(c) && chars.all(is_subsequent),
+        (Some(c), _) => is_initial(c) && name.chars().skip(1).all(is_subsequent),
+    }
 }
 
-fn write_shared(_: Vec<Expression>, _: &mut MutableRef<Environment>) -> Result<Expression, Error> {
-    todo!()
+///
+/// Write a list, following the `cdr` in a loop rather than recursively.
+///
+fn write_pair(pair: &Pair, human: bool, result: &mut String) {
+    result.push(SYNTAX_LEFT_PARENTHESIS_CHAR);
+    if get_global_flag(WRITE_CONS_LONG_FORM).unwrap_or_default() {
+        write_datum(&pair.car(), human, result);
+        result.push_str(SYNTAX_CONS_DOT);
+        write_datum(&pair.cdr(), human, result);
+    } else {
+        let mut pair = pair.clone();
+        loop {
+            write_datum(&pair.car(), human, result);
+            let cdr = pair.cdr();
+            match &*cdr {
+                Datum::List(next) => {
+                    result.push(SYNTAX_SPACE_CHAR);
+                    pair = next.clone();
+                }
+                Datum::Null => break,
+                cdr => {
+                    result.push_str(SYNTAX_CONS_DOT);
+                    write_datum(cdr, human, result);
+                    break;
+                }
+            }
+        }
+    }
+    result.push(SYNTAX_RIGHT_PARENTHESIS_CHAR);
 }
 
 // ------------------------------------------------------------------------------------------------
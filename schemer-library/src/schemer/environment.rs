@@ -13,9 +13,10 @@ use schemer_lang::error::{Error, ErrorKind};
 use schemer_lang::eval::environment::{Exports, TYPE_NAME_ENVIRONMENT};
 use schemer_lang::eval::{Environment, Expression, Procedure};
 use schemer_lang::read::datum::Datum;
-use schemer_lang::types::lists::vec_to_list;
 use schemer_lang::types::symbols::TYPE_NAME_SYMBOL;
-use schemer_lang::types::{Boolean, Identifier, MutableRef, Pair, Ref, SchemeString, SchemeValue};
+use schemer_lang::types::{
+    Boolean, Identifier, MutableRef, Pair, Ref, SchemeRepr, SchemeString, SchemeValue,
+};
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -151,7 +152,7 @@ fn bound_names(
         Expression::Environment(env) => Ok(Expression::List(
             env.borrow()
                 .binding_names()
-                .map(|name| Datum::Symbol(name.clone()).into())
+                .map(|name| Expression::Quotation(Ref::new(Datum::Symbol(name.clone()))))
                 .collect(),
         )),
         e => {
@@ -169,10 +170,10 @@ fn bindings(
             env.borrow()
                 .bindings()
                 .map(|(k, v)| {
-                    Datum::List(Pair::cons(
+                    Expression::Quotation(Ref::new(Datum::List(Pair::cons(
                         Datum::Symbol(k.clone()).into(),
-                        Datum::f(SchemeString::from(v)).into(),
-                    ))
+                        Datum::String(SchemeString::from(v.to_repr_string())).into(),
+                    ))))
                 })
                 .collect(),
         )),
@@ -17,7 +17,6 @@ use schemer_lang::types::lists::vec_to_list;
 use schemer_lang::types::strings::TYPE_NAME_STRING;
 use schemer_lang::types::{Boolean, Identifier, MutableRef, Pair, SchemeString, SchemeValue};
 use std::ffi::OsStr;
-use std::ops::Deref;
 use std::path::{Component, PathBuf, Prefix};
 
 // ------------------------------------------------------------------------------------------------
@@ -69,7 +68,7 @@ fn is_absolute(
 ) -> Result<Expression, Error> {
     Ok(eboolean!(match &arguments[0] {
         Expression::String(file_name) => {
-            let file = PathBuf::from(file_name.deref());
+            let file = PathBuf::from(&*file_name.as_string());
             file.is_absolute()
         }
         e => {
@@ -84,7 +83,7 @@ fn is_relative(
 ) -> Result<Expression, Error> {
     Ok(eboolean!(match &arguments[0] {
         Expression::String(file_name) => {
-            let file = PathBuf::from(file_name.deref());
+            let file = PathBuf::from(&*file_name.as_string());
             file.is_relative()
         }
         e => {
@@ -99,7 +98,7 @@ fn is_dir(
 ) -> Result<Expression, Error> {
     Ok(eboolean!(match &arguments[0] {
         Expression::String(file_name) => {
-            let file = PathBuf::from(file_name.deref());
+            let file = PathBuf::from(&*file_name.as_string());
             file.is_dir()
         }
         e => {
@@ -114,7 +113,7 @@ fn is_file(
 ) -> Result<Expression, Error> {
     Ok(eboolean!(match &arguments[0] {
         Expression::String(file_name) => {
-            let file = PathBuf::from(file_name.deref());
+            let file = PathBuf::from(&*file_name.as_string());
             file.is_file()
         }
         e => {
@@ -129,7 +128,7 @@ fn extension(
 ) -> Result<Expression, Error> {
     Ok(match &arguments[0] {
         Expression::String(file_name) => {
-            let file = PathBuf::from(file_name.deref());
+            let file = PathBuf::from(&*file_name.as_string());
             match file.extension() {
                 None => Expression::Boolean(Boolean::from(false)),
                 Some(v) => Expression::String(SchemeString::from(v.to_string_lossy().to_string())),
@@ -147,7 +146,7 @@ fn file_name(
 ) -> Result<Expression, Error> {
     Ok(match &arguments[0] {
         Expression::String(file_name) => {
-            let file = PathBuf::from(file_name.deref());
+            let file = PathBuf::from(&*file_name.as_string());
             match file.file_name() {
                 None => Expression::Boolean(Boolean::from(false)),
                 Some(v) => Expression::String(SchemeString::from(v.to_string_lossy().to_string())),
@@ -165,7 +164,7 @@ fn file_stem(
 ) -> Result<Expression, Error> {
     Ok(match &arguments[0] {
         Expression::String(file_name) => {
-            let file = PathBuf::from(file_name.deref());
+            let file = PathBuf::from(&*file_name.as_string());
             match file.file_stem() {
                 None => Expression::Boolean(Boolean::from(false)),
                 Some(v) => Expression::String(SchemeString::from(v.to_string_lossy().to_string())),
@@ -184,7 +183,7 @@ fn canonicalize_path(
     Ok(Expression::String(SchemeString::from(
         match &arguments[0] {
             Expression::String(file_name) => {
-                let file = PathBuf::from(file_name.deref());
+                let file = PathBuf::from(&*file_name.as_string());
                 let new_file = file.canonicalize()?;
                 new_file.to_string_lossy().to_string()
             }
@@ -200,7 +199,9 @@ fn components(
     _: &mut MutableRef<Environment>,
 ) -> Result<Expression, Error> {
     Ok(Expression::List(match &arguments[0] {
-        Expression::String(file_name) => path_to_components(&PathBuf::from(file_name.deref()))?,
+        Expression::String(file_name) => {
+            path_to_components(&PathBuf::from(&*file_name.as_string()))?
+        }
         e => {
             unexpected_type!(=> TYPE_NAME_STRING, e)
         }
@@ -50,3 +50,5 @@ pub mod lists;
 pub mod load;
 
 pub mod repl;
+
+pub mod symbols;
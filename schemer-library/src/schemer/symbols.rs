@@ -0,0 +1,103 @@
+/*!
+Uninterned symbols, for use by macros and code generation where an identifier must not be the
+same as any identifier written in the program.
+
+An uninterned symbol is only `eq?` to itself, even when another symbol has the same name.
+
+# Example
+
+```scheme
+(import (schemer symbols))
+(define g (gensym "tmp"))
+(symbol? g)                            ;; => #t
+(eq? g (string->symbol (symbol->string g))) ;; => #f
+(symbol-interned? g)                   ;; => #f
+(eq? (string->uninterned-symbol "a")
+     (string->uninterned-symbol "a"))  ;; => #f
+```
+
+*/
+
+use crate::forms::library::LibraryName;
+use crate::scheme::base::strings::string;
+use crate::scheme::base::symbols::{new_symbol_value, symbol};
+use crate::schemer::ID_LIB_SCHEMER;
+use schemer_lang::error::{Error, ErrorKind};
+use schemer_lang::eval::environment::Exports;
+use schemer_lang::eval::{Environment, Expression, Procedure};
+use schemer_lang::types::strings::TYPE_NAME_STRING;
+use schemer_lang::types::{Boolean, Identifier, MutableRef, SchemeValue};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const DEFAULT_GENSYM_PREFIX: &str = "g";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+library_name!(
+    ID_LIB_SCHEMER_SYMBOLS,
+    "symbols",
+    ID_LIB_SCHEMER,
+    schemer_symbols_name
+);
+
+pub fn schemer_symbols_exports() -> Exports {
+    let mut exports = Exports::default();
+
+    export_builtin!(exports, "gensym" => gensym ; "prefix");
+    export_builtin!(exports, "string->uninterned-symbol" => string_to_uninterned_symbol "string");
+    export_builtin!(exports, "symbol-interned?" => is_symbol_interned "symbol");
+
+    exports
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn gensym(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let prefix = match arguments.first() {
+        None => DEFAULT_GENSYM_PREFIX.to_string(),
+        Some(Expression::String(s)) => s.to_string(),
+        Some(value) => match symbol(value) {
+            Ok(id) => id.as_str().to_string(),
+            Err(_) => unexpected_type!(=> TYPE_NAME_STRING, value),
+        },
+    };
+    Ok(new_symbol_value(Identifier::new_generated(&prefix)))
+}
+
+fn string_to_uninterned_symbol(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(new_symbol_value(Identifier::new_uninterned(
+        &string(&arguments[0])?.as_string(),
+    )))
+}
+
+fn is_symbol_interned(
+    arguments: Vec<Expression>,
+    _: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    Ok(eboolean!(symbol(&arguments[0])?.is_interned()))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
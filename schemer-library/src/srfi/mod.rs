@@ -35,4 +35,6 @@ library_name!(ID_LIB_SRFI, "srfi", srfi_name);
 // Modules
 // ------------------------------------------------------------------------------------------------
 
+pub mod srfi_8;
+
 pub mod srfi_112;
@@ -12,7 +12,7 @@ use schemer_lang::error::{Error, ErrorKind};
 use schemer_lang::eval::environment::Exports;
 use schemer_lang::eval::Procedure;
 use schemer_lang::eval::{Environment, Expression};
-use schemer_lang::types::{Identifier, Integer, MutableRef, SchemeString};
+use schemer_lang::types::{Identifier, MutableRef, SchemeString};
 use schemer_lang::{IMPLEMENTATION_NAME, IMPLEMENTATION_VERSION};
 
 // ------------------------------------------------------------------------------------------------
@@ -27,7 +27,7 @@ use schemer_lang::{IMPLEMENTATION_NAME, IMPLEMENTATION_VERSION};
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
-pub const ID_LIB_SRFI_112: Integer = 112;
+pub const ID_LIB_SRFI_112: u32 = 112;
 
 pub fn srfi_112_name() -> LibraryName {
     LibraryName::srfi(ID_LIB_SRFI_112).unwrap()
@@ -0,0 +1,71 @@
+/*!
+SRFI 8: receive, binding to multiple values.
+
+```scheme
+(receive (q r) (floor/ 7 2) (list q r))
+```
+
+*/
+
+use crate::forms::library::LibraryName;
+use schemer_lang::error::Error;
+use schemer_lang::eval::environment::Exports;
+use schemer_lang::eval::forms::{bind_values_let, head};
+use schemer_lang::eval::{Environment, Expression, Form};
+use schemer_lang::read::datum::Datum;
+use schemer_lang::types::{Identifier, MutableRef, Pair, Ref};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub const ID_LIB_SRFI_8: u32 = 8;
+
+pub const FORM_NAME_RECEIVE: &str = "receive";
+
+pub fn srfi_8_name() -> LibraryName {
+    LibraryName::srfi(ID_LIB_SRFI_8).unwrap()
+}
+
+pub fn srfi_8_exports() -> Exports {
+    let mut exports = Exports::default();
+
+    export_standard_form!(exports, FORM_NAME_RECEIVE => receive "formals" "expression" "body" ; "body");
+
+    exports
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// `(receive formals expression body ...)` is simply `(let-values ((formals expression)) body ...)`.
+///
+fn receive(
+    mut arguments: Vec<Ref<Datum>>,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    let formals = head(&mut arguments);
+    let expression = head(&mut arguments);
+    let binding =
+        Pair::cons_nil(Datum::List(Pair::cons_list(formals, Pair::cons_nil(expression))).into());
+    arguments.insert(0, Datum::List(binding).into());
+    bind_values_let(arguments, environment)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
@@ -0,0 +1,66 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, Integer, Number};
+use schemer_macros::{eboolean, einteger};
+
+#[test]
+fn test_byte_vector_constructors() {
+    assert_eval_repr("(bytevector 1 3 5 1 3 5)", "#u8(1 3 5 1 3 5)");
+    assert_eval_repr("(bytevector)", "#u8()");
+    assert_eval_repr("(make-bytevector 2 12)", "#u8(12 12)");
+    assert_eval_repr("#u8(0 10 255)", "#u8(0 10 255)");
+    assert_eval_eq("(bytevector-length (make-bytevector 3))", einteger!(3));
+    assert_eval_eq("(bytevector? #u8(1))", eboolean!(true));
+    let error = assert_eval_err("(bytevector 1 256)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedValue { .. }));
+    let error = assert_eval_err("(make-bytevector 2 #\\a)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_byte_vector_ref_set() {
+    assert_eval_eq("(bytevector-u8-ref #u8(1 1 2 3 5 8 13 21) 5)", einteger!(8));
+    assert_eval_repr(
+        "(define bv (bytevector 1 2 3 4)) (define other bv) (bytevector-u8-set! other 1 3) bv",
+        "#u8(1 3 3 4)",
+    );
+    let error = assert_eval_err("(bytevector-u8-ref #u8(1 2) 2)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedValue { .. }));
+    let error = assert_eval_err("(bytevector-u8-set! (bytevector 1 2) 0 300)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedValue { .. }));
+}
+
+#[test]
+fn test_byte_vector_copy_append() {
+    assert_eval_repr("(bytevector-copy #u8(1 2 3 4 5) 2 4)", "#u8(3 4)");
+    assert_eval_repr(
+        "(define a (bytevector 1 2)) (define b (bytevector-copy a)) (bytevector-u8-set! b 0 0) a",
+        "#u8(1 2)",
+    );
+    assert_eval_repr(
+        "(define a (bytevector 1 2 3 4 5)) (define b (bytevector 10 20 30 40 50)) (bytevector-copy! b 1 a 0 2) b",
+        "#u8(10 1 2 40 50)",
+    );
+    assert_eval_repr(
+        "(define a (bytevector 1 2 3 4 5)) (bytevector-copy! a 1 a 0 3) a",
+        "#u8(1 1 2 3 5)",
+    );
+    assert_eval_repr(
+        "(bytevector-append #u8(0 1 2) #u8(3 4 5))",
+        "#u8(0 1 2 3 4 5)",
+    );
+    let error = assert_eval_err("(bytevector-copy #u8(1 2) 1 3)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedValue { .. }));
+}
+
+#[test]
+fn test_utf8_conversion() {
+    assert_eval_repr("(utf8->string #u8(65))", "\"A\"");
+    assert_eval_repr("(utf8->string #u8(206 187 65) 2)", "\"A\"");
+    assert_eval_repr("(string->utf8 \"λ\")", "#u8(206 187)");
+    assert_eval_repr("(string->utf8 \"aλb\" 1 2)", "#u8(206 187)");
+    let error = assert_eval_err("(utf8->string #u8(206))");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedValue { .. }));
+}
@@ -0,0 +1,123 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, Integer, Number};
+use schemer_macros::{eboolean, einteger};
+
+#[test]
+fn test_char_predicates() {
+    assert_eval_eq(
+        "(import (scheme char)) (char-alphabetic? #\\a)",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(import (scheme char)) (char-numeric? #\\a)",
+        eboolean!(false),
+    );
+    assert_eval_eq(
+        "(import (scheme char)) (char-upper-case? #\\A)",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(import (scheme char)) (char-whitespace? #\\space)",
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_digit_value() {
+    assert_eval_eq("(import (scheme char)) (digit-value #\\3)", einteger!(3));
+    assert_eval_eq("(import (scheme char)) (digit-value #\\٤)", einteger!(4));
+    assert_eval_eq("(import (scheme char)) (digit-value #\\௦)", einteger!(0));
+    assert_eval_eq(
+        "(import (scheme char)) (digit-value #\\a)",
+        eboolean!(false),
+    );
+}
+
+#[test]
+fn test_char_case() {
+    assert_eval_repr("(import (scheme char)) (char-upcase #\\a)", "#\\A");
+    assert_eval_repr("(import (scheme char)) (char-downcase #\\Λ)", "#\\λ");
+    assert_eval_repr("(import (scheme char)) (char-upcase #\\ß)", "#\\ß");
+    assert_eval_repr("(import (scheme char)) (char-foldcase #\\Σ)", "#\\σ");
+    assert_eval_repr("(import (scheme char)) (char-foldcase #\\ς)", "#\\σ");
+    let error = assert_eval_err("(import (scheme char)) (char-upcase \"a\")");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_char_ci_comparison() {
+    assert_eval_eq(
+        "(import (scheme char)) (char-ci=? #\\a #\\A #\\a)",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(import (scheme char)) (char-ci<? #\\a #\\B #\\c)",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(import (scheme char)) (char-ci>? #\\a #\\B)",
+        eboolean!(false),
+    );
+    assert_eval_eq(
+        "(import (scheme char)) (char-ci<=? #\\Z #\\z)",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(import (scheme char)) (char-ci>=? #\\ς #\\Σ)",
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_string_ci_comparison() {
+    assert_eval_eq(
+        "(import (scheme char)) (string-ci=? \"Abc\" \"aBC\")",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(import (scheme char)) (string-ci<? \"abc\" \"ABD\")",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(import (scheme char)) (string-ci=? \"Straße\" \"STRASSE\")",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(import (scheme char)) (string-ci>=? \"b\" \"A\" \"a\")",
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_string_case() {
+    assert_eval_repr(
+        "(import (scheme char)) (string-upcase \"Hello\")",
+        "\"HELLO\"",
+    );
+    assert_eval_repr(
+        "(import (scheme char)) (string-downcase \"Hello\")",
+        "\"hello\"",
+    );
+    assert_eval_repr(
+        "(import (scheme char)) (string-upcase \"straße\")",
+        "\"STRASSE\"",
+    );
+    assert_eval_repr(
+        "(import (scheme char)) (string-foldcase \"Straße\")",
+        "\"strasse\"",
+    );
+}
+
+#[test]
+fn test_fold_case_directive() {
+    assert_eval_eq("#!fold-case (DEFINE Abc 1) abc", einteger!(1));
+    assert_eval_repr("#!fold-case 'Hello", "'hello");
+    assert_eval_repr("#!fold-case #!no-fold-case 'Hello", "'Hello");
+    assert_eval_repr("(quote (A #!fold-case B))", "'(A b)");
+    assert_eval_eq("#!fold-case (EQV? #\\SPACE #\\x20)", eboolean!(true));
+    let error = assert_eval_err("(define Abc 1) #!fold-case Abc");
+    assert!(matches!(error.kind(), ErrorKind::UnboundVariable { .. }));
+}
@@ -0,0 +1,61 @@
+#![allow(dead_code)]
+
+use schemer_lang::error::Error;
+use schemer_lang::eval::{Environment, Evaluate, Expression};
+use schemer_lang::types::{MutableRef, SchemeRepr};
+use schemer_library::{make_preset_environment, PresetEnvironmentKind};
+use schemer_parse::parser::parse_data_str;
+
+// ------------------------------------------------------------------------------------------------
+// Test environment
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A mutable child of the `(scheme base)` environment, so that tests may define values.
+///
+pub fn test_environment() -> MutableRef<Environment> {
+    let environment = make_preset_environment(PresetEnvironmentKind::SchemeBase).unwrap();
+    Environment::new_child_named(environment, "*test*")
+}
+
+pub fn eval_str(src: &str) -> Result<Expression, Error> {
+    eval_str_in(src, &mut test_environment())
+}
+
+pub fn eval_str_in(
+    src: &str,
+    environment: &mut MutableRef<Environment>,
+) -> Result<Expression, Error> {
+    println!("<< {}", src);
+    let data = parse_data_str(src)?;
+    let mut result = Expression::Unspecified;
+    for datum in data {
+        result = datum.eval(environment)?;
+    }
+    println!(">> {}", result.to_repr_string());
+    Ok(result)
+}
+
+pub fn assert_eval_eq(src: &str, expected: Expression) {
+    match eval_str(src) {
+        Ok(result) => assert_eq!(result, expected),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+pub fn assert_eval_repr(src: &str, expected: &str) {
+    match eval_str(src) {
+        Ok(result) => assert_eq!(result.to_repr_string(), expected),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+pub fn assert_eval_err(src: &str) -> Error {
+    match eval_str(src) {
+        Ok(result) => panic!("expected an error, not {}", result.to_repr_string()),
+        Err(e) => {
+            println!("!! {}", e);
+            e
+        }
+    }
+}
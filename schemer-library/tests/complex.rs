@@ -0,0 +1,51 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_repr};
+use schemer_lang::eval::Expression;
+use schemer_lang::types::Boolean;
+use schemer_macros::eboolean;
+
+const IMPORT: &str = "(import (scheme complex))";
+
+fn assert_complex_repr(src: &str, expected: &str) {
+    assert_eval_repr(&format!("{} {}", IMPORT, src), expected);
+}
+
+fn assert_complex_true(src: &str) {
+    assert_eval_eq(&format!("{} {}", IMPORT, src), eboolean!(true));
+}
+
+#[test]
+fn test_make_rectangular() {
+    assert_complex_repr("(make-rectangular 1 2)", "1+2i");
+    assert_complex_true("(exact? (make-rectangular 1 2))");
+    assert_complex_repr("(make-rectangular 1 0)", "1");
+    assert_complex_true("(exact-integer? (make-rectangular 1 0))");
+    assert_complex_true("(inexact? (make-rectangular 1 (inexact 2)))");
+}
+
+#[test]
+fn test_make_polar() {
+    assert_complex_repr("(make-polar 2 0)", "2");
+    assert_complex_true("(exact? (make-polar 2 0))");
+    assert_complex_true("(inexact? (make-polar 2 1))");
+}
+
+#[test]
+fn test_parts() {
+    assert_complex_repr("(real-part 3+4i)", "3");
+    assert_complex_repr("(imag-part 3+4i)", "4");
+    assert_complex_true("(exact-integer? (imag-part 3+4i))");
+    assert_complex_repr("(real-part 5)", "5");
+    assert_complex_repr("(imag-part 5)", "0");
+}
+
+#[test]
+fn test_magnitude_and_angle() {
+    assert_complex_repr("(magnitude 3+4i)", "5");
+    assert_complex_true("(exact? (magnitude 3+4i))");
+    assert_complex_repr("(magnitude -7)", "7");
+    assert_complex_repr("(magnitude #i3+4i)", "5.0");
+    assert_complex_repr("(angle 1)", "0");
+    assert_complex_repr("(angle -1)", "3.141592653589793");
+    assert_complex_repr("(angle 0+i)", "1.5707963267948966");
+}
@@ -0,0 +1,354 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr, eval_str};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, SchemeRepr};
+use schemer_macros::eboolean;
+
+#[test]
+fn test_call_cc_normal_return() {
+    assert_eval_repr("(call/cc (lambda (k) 'normal))", "'normal");
+    assert_eval_repr(
+        "(call-with-current-continuation (lambda (k) 'normal))",
+        "'normal",
+    );
+}
+
+#[test]
+fn test_call_cc_escape() {
+    assert_eval_repr(
+        "(call/cc (lambda (k) (k 'escaped) 'not-reached))",
+        "'escaped",
+    );
+    assert_eval_repr(
+        "(call/cc (lambda (k) (if (k 'escaped) 'yes 'no)))",
+        "'escaped",
+    );
+}
+
+#[test]
+fn test_call_cc_escape_from_nested_calls() {
+    assert_eval_eq(
+        r#"
+(define (check x return)
+  (if (negative? x) (return x) x))
+(define (search a b c return)
+  (check a return)
+  (check b return)
+  (check c return)
+  #f)
+(call/cc (lambda (return) (search 54 -3 19 return)))
+"#,
+        common::eval_str("-3").unwrap(),
+    );
+}
+
+#[test]
+fn test_nested_call_cc() {
+    assert_eval_repr(
+        r#"
+(call/cc (lambda (outer)
+  (call/cc (lambda (inner)
+    (outer 'from-inner)))
+  'not-reached))
+"#,
+        "'from-inner",
+    );
+    assert_eval_repr(
+        r#"
+(call/cc (lambda (outer)
+  (call/cc (lambda (inner)
+    (inner 'ignored)))
+  'after-inner))
+"#,
+        "'after-inner",
+    );
+}
+
+#[test]
+fn test_continuation_value() {
+    let result = eval_str("(call/cc (lambda (k) k))").unwrap();
+    assert!(result.is_continuation());
+    assert!(result.to_repr_string().starts_with("#<continuation:"));
+    assert_eval_eq("(call/cc (lambda (k) (procedure? k)))", eboolean!(true));
+}
+
+#[test]
+fn test_continuation_not_active() {
+    let error = assert_eval_err(
+        r#"
+(define saved #f)
+(call/cc (lambda (k) (set! saved k)))
+(saved 'again)
+"#,
+    );
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::ContinuationNotActive { .. }
+    ));
+}
+
+#[test]
+fn test_call_cc_in_tail_position() {
+    assert_eval_repr(
+        "(define (count n) (if (= n 0) 'done (call/cc (lambda (k) (count (- n 1)))))) (count 100000)",
+        "'done",
+    );
+    assert_eval_repr(
+        r#"
+(define (count n k) (if (= n 0) (k 'escaped) (call/cc (lambda (c) (count (- n 1) c)))))
+(count 10000 #f)
+"#,
+        "'escaped",
+    );
+}
+
+#[test]
+fn test_call_cc_escape_in_loop() {
+    assert_eval_repr(
+        r#"
+(define (count n) (if (= n 0) 'done (begin (call/cc (lambda (k) (k n))) (count (- n 1)))))
+(count 10000)
+"#,
+        "'done",
+    );
+}
+
+#[test]
+fn test_continuation_reentry() {
+    assert_eval_repr(
+        r#"
+(define (make-generator items)
+  (define return #f)
+  (define (resume)
+    (for-each (lambda (item)
+                (call/cc (lambda (next)
+                  (set! resume (lambda () (next #f)))
+                  (return item))))
+              items)
+    (return 'done))
+  (lambda () (call/cc (lambda (k) (set! return k) (resume)))))
+(define next (make-generator '(a b c)))
+(list (next) (next) (next) (next))
+"#,
+        "'(a b c done)",
+    );
+}
+
+#[test]
+fn test_continuation_reentry_values() {
+    // the value passed to a re-entered continuation is returned from its call/cc.
+    assert_eval_repr(
+        r#"
+(define saved #f)
+(define results '())
+(define (producer)
+  (let ((x (call/cc (lambda (k) (set! saved k) (escape 'first)))))
+    (escape `(resumed ,x))))
+(define escape #f)
+(set! results (cons (call/cc (lambda (k) (set! escape k) (producer))) results))
+(set! results (cons (call/cc (lambda (k) (set! escape k) (saved 42))) results))
+results
+"#,
+        "'((resumed 42) first)",
+    );
+}
+
+#[test]
+fn test_continuation_reentry_once() {
+    let error = assert_eval_err(
+        r#"
+(define saved #f)
+(define escape #f)
+(define (producer)
+  (call/cc (lambda (k) (set! saved k) (escape 'first)))
+  (escape 'second))
+(call/cc (lambda (k) (set! escape k) (producer)))
+(call/cc (lambda (k) (set! escape k) (saved #f)))
+(call/cc (lambda (k) (set! escape k) (saved #f)))
+"#,
+    );
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::ContinuationNotActive { .. }
+    ));
+}
+
+#[test]
+fn test_dynamic_wind_normal_return() {
+    assert_eval_repr(
+        r#"
+(define trace '())
+(define (note x) (set! trace `(,@trace ,x)))
+(dynamic-wind
+  (lambda () (note 'before))
+  (lambda () (note 'during))
+  (lambda () (note 'after)))
+trace
+"#,
+        "'(before during after)",
+    );
+    assert_eval_repr(
+        "(dynamic-wind (lambda () #f) (lambda () 'result) (lambda () #f))",
+        "'result",
+    );
+}
+
+#[test]
+fn test_dynamic_wind_escape() {
+    assert_eval_repr(
+        r#"
+(define trace '())
+(define (note x) (set! trace `(,@trace ,x)))
+(define result
+  (call/cc (lambda (k)
+    (dynamic-wind
+      (lambda () (note 'before))
+      (lambda () (k 'escaped) (note 'not-reached))
+      (lambda () (note 'after))))))
+`(,result ,@trace)
+"#,
+        "'(escaped before after)",
+    );
+}
+
+#[test]
+fn test_dynamic_wind_nested_escape() {
+    assert_eval_repr(
+        r#"
+(define trace '())
+(define (note x) (set! trace `(,@trace ,x)))
+(call/cc (lambda (k)
+  (dynamic-wind
+    (lambda () (note 'outer-before))
+    (lambda ()
+      (dynamic-wind
+        (lambda () (note 'inner-before))
+        (lambda () (k 'escaped))
+        (lambda () (note 'inner-after))))
+    (lambda () (note 'outer-after)))))
+trace
+"#,
+        "'(outer-before inner-before inner-after outer-after)",
+    );
+}
+
+#[test]
+fn test_dynamic_wind_reentry() {
+    assert_eval_repr(
+        r#"
+(define trace '())
+(define (note x) (set! trace `(,@trace ,x)))
+(define saved #f)
+(define escape #f)
+(call/cc (lambda (k)
+  (set! escape k)
+  (dynamic-wind
+    (lambda () (note 'before))
+    (lambda ()
+      (call/cc (lambda (k) (set! saved k) (escape 'left)))
+      (note 'during)
+      (escape 'done))
+    (lambda () (note 'after)))))
+(note 'outside)
+(call/cc (lambda (k) (set! escape k) (saved #f)))
+trace
+"#,
+        "'(before after outside before during after)",
+    );
+}
+
+#[test]
+fn test_dynamic_wind_error() {
+    let mut environment = common::test_environment();
+    let _ = common::eval_str_in(
+        r#"
+(define trace '())
+(define (note x) (set! trace `(,@trace ,x)))
+(dynamic-wind
+  (lambda () (note 'before))
+  (lambda () (undefined-procedure))
+  (lambda () (note 'after)))
+"#,
+        &mut environment,
+    )
+    .unwrap_err();
+    assert_eq!(
+        common::eval_str_in("trace", &mut environment)
+            .unwrap()
+            .to_repr_string(),
+        "'(before after)"
+    );
+}
+
+#[test]
+fn test_call_cc_requires_procedure() {
+    let error = assert_eval_err("(call/cc 'not-a-procedure)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_apply() {
+    assert_eval_repr("(apply + (list 3 4))", "7");
+    assert_eval_repr("(apply + 1 2 '(3 4))", "10");
+    assert_eval_repr("(apply list '())", "null");
+    assert_eval_repr(
+        "(define compose (lambda (f g) (lambda args (f (apply g args))))) ((compose - *) 12 75)",
+        "-900",
+    );
+    let error = assert_eval_err("(apply + 1 2)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_apply_in_tail_position() {
+    assert_eval_repr(
+        "(define (count n) (if (= n 0) 'done (apply count (list (- n 1))))) (count 100000)",
+        "'done",
+    );
+}
+
+#[test]
+fn test_map() {
+    assert_eval_repr("(map cadr '((a b) (d e) (g h)))", "'(b e h)");
+    assert_eval_repr("(map (lambda (n) (* n n)) '(1 2 3 4))", "'(1 4 9 16)");
+    assert_eval_repr("(map + '(1 2 3) '(10 20 30))", "'(11 22 33)");
+    assert_eval_repr("(map + '(1 2 3) '(10 20))", "'(11 22)");
+    assert_eval_repr("(map + '())", "null");
+    assert_eval_repr(
+        "(define x (list 1 2)) (set-cdr! (cdr x) x) (map + x '(10 20 30))",
+        "'(11 22 31)",
+    );
+}
+
+#[test]
+fn test_for_each() {
+    assert_eval_repr(
+        "(define total 0) (for-each (lambda (a b) (set! total (+ total (* a b)))) '(1 2 3) '(4 5 6)) total",
+        "32",
+    );
+    let result = eval_str("(for-each + '(1 2))").unwrap();
+    assert!(result.is_unspecified());
+}
+
+#[test]
+fn test_vector_map() {
+    assert_eval_repr("(vector-map cadr '#((a b) (d e) (g h)))", "#(b e h)");
+    assert_eval_repr("(vector-map + '#(1 2) '#(10 20 30))", "#(11 22)");
+    assert_eval_repr(
+        "(define total 0) (vector-for-each (lambda (n) (set! total (+ total n))) '#(1 2 3)) total",
+        "6",
+    );
+}
+
+#[test]
+fn test_string_map() {
+    assert_eval_repr("(string-map (lambda (c) #\\z) \"abc\")", "\"zzz\"");
+    assert_eval_repr("(string-map (lambda (a b) b) \"abc\" \"xy\")", "\"xy\"");
+    assert_eval_repr(
+        "(define n 0) (string-for-each (lambda (a b) (set! n (+ n 1))) \"abc\" \"de\") n",
+        "2",
+    );
+    let error = assert_eval_err("(string-map (lambda (c) 1) \"abc\")");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
@@ -0,0 +1,146 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::Boolean;
+use schemer_macros::eboolean;
+
+#[test]
+fn test_eqv() {
+    assert_eval_eq("(eqv? 'a 'a)", eboolean!(true));
+    assert_eval_eq("(eqv? 'a 'b)", eboolean!(false));
+    assert_eval_eq("(eqv? 2 2)", eboolean!(true));
+    assert_eval_eq("(eqv? 2 (inexact 2))", eboolean!(false));
+    assert_eval_eq("(eqv? '() '())", eboolean!(true));
+    assert_eval_eq("(eqv? #\\a #\\a)", eboolean!(true));
+    assert_eval_eq("(eqv? (cons 1 2) (cons 1 2))", eboolean!(false));
+    assert_eval_eq("(eqv? \"\" \"\")", eboolean!(false));
+    assert_eval_eq("(let ((p (lambda (x) x))) (eqv? p p))", eboolean!(true));
+    assert_eval_eq("(eqv? (lambda () 1) (lambda () 2))", eboolean!(false));
+    assert_eval_eq("(define s \"abc\") (eqv? s s)", eboolean!(true));
+}
+
+#[test]
+fn test_eq() {
+    assert_eval_eq("(eq? 'a 'a)", eboolean!(true));
+    assert_eval_eq("(eq? (list 'a) (list 'a))", eboolean!(false));
+    assert_eval_eq("(define x (list 'a)) (eq? x x)", eboolean!(true));
+    assert_eval_eq("(define v (vector 1)) (eq? v v)", eboolean!(true));
+    assert_eval_eq("(eq? (vector 1) (vector 1))", eboolean!(false));
+    assert_eval_eq("(eq? car car)", eboolean!(true));
+    assert_eval_eq("(let ((x '(a))) (eq? x x))", eboolean!(true));
+}
+
+#[test]
+fn test_equal() {
+    assert_eval_eq("(equal? 'a 'a)", eboolean!(true));
+    assert_eval_eq("(equal? '(a) '(a))", eboolean!(true));
+    assert_eval_eq("(equal? '(a (b) c) '(a (b) c))", eboolean!(true));
+    assert_eval_eq("(equal? '(a (b) c) '(a (b) d))", eboolean!(false));
+    assert_eval_eq("(equal? \"abc\" \"abc\")", eboolean!(true));
+    assert_eval_eq("(equal? 2 2)", eboolean!(true));
+    assert_eval_eq("(equal? 2 (inexact 2))", eboolean!(false));
+    assert_eval_eq(
+        "(equal? (make-vector 5 'a) (make-vector 5 'a))",
+        eboolean!(true),
+    );
+    assert_eval_eq("(equal? #u8(1 2) (bytevector 1 2))", eboolean!(true));
+}
+
+#[test]
+fn test_equal_circular() {
+    assert_eval_eq(
+        "(define x (list 1 2)) (set-cdr! (cdr x) x) \
+         (define y (list 1 2)) (set-cdr! (cdr y) y) \
+         (equal? x y)",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(define x (list 1 2)) (set-cdr! (cdr x) x) \
+         (define y (list 1 3)) (set-cdr! (cdr y) y) \
+         (equal? x y)",
+        eboolean!(false),
+    );
+    assert_eval_eq(
+        "(define v (vector 1 2)) (vector-set! v 1 v) \
+         (define w (vector 1 2)) (vector-set! w 1 w) \
+         (equal? v w)",
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_booleans() {
+    assert_eval_eq("(not #t)", eboolean!(false));
+    assert_eval_eq("(not 3)", eboolean!(false));
+    assert_eval_eq("(not '())", eboolean!(false));
+    assert_eval_eq("(not #f)", eboolean!(true));
+    assert_eval_eq("(boolean=? #t #t)", eboolean!(true));
+    assert_eval_eq("(boolean=? #f #f #f)", eboolean!(true));
+    assert_eval_eq("(boolean=? #t #t #f)", eboolean!(false));
+    let error = assert_eval_err("(boolean=? #t 1)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_symbols() {
+    assert_eval_eq("(symbol=? 'a 'a)", eboolean!(true));
+    assert_eval_eq("(symbol=? 'a 'a 'b)", eboolean!(false));
+    assert_eval_eq("(symbol=? 'a (string->symbol \"a\"))", eboolean!(true));
+    let error = assert_eval_err("(symbol=? 'a \"a\")");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_uninterned_symbols() {
+    assert_eval_eq(
+        "(import (schemer symbols)) (symbol? (gensym))",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(import (schemer symbols)) (define g (gensym \"tmp\")) \
+         (eq? g (string->symbol (symbol->string g)))",
+        eboolean!(false),
+    );
+    assert_eval_eq(
+        "(import (schemer symbols)) (define g (gensym 'x)) (eq? g g)",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(import (schemer symbols)) (eq? (gensym) (gensym))",
+        eboolean!(false),
+    );
+    assert_eval_eq(
+        "(import (schemer symbols)) \
+         (eq? (string->uninterned-symbol \"a\") (string->uninterned-symbol \"a\"))",
+        eboolean!(false),
+    );
+    assert_eval_eq(
+        "(import (schemer symbols)) (symbol-interned? (string->uninterned-symbol \"a\"))",
+        eboolean!(false),
+    );
+    assert_eval_eq(
+        "(import (schemer symbols)) (symbol-interned? 'a)",
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_symbols_quoted_by_macros() {
+    assert_eval_eq(
+        "(define-syntax m (syntax-rules () ((_) 'x))) (eq? (m) 'x)",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(define-syntax m (syntax-rules () ((_) `x))) (eq? (m) 'x)",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(define-syntax m (syntax-rules () ((_) `(x ,(+ 1 2))))) (equal? (m) '(x 3))",
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        "(define-syntax m (syntax-rules () ((_ e) `#(x (y ,e))))) (equal? (m 3) '#(x (y 3)))",
+        eboolean!(true),
+    );
+}
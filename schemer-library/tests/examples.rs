@@ -0,0 +1,70 @@
+pub mod common;
+use common::{eval_str_in, test_environment};
+use schemer_lang::eval::{Environment, Evaluate};
+use schemer_lang::read::datum::Datum;
+use schemer_lang::types::{MutableRef, SchemeRepr};
+use schemer_parse::parser::parse_data_str;
+use std::path::PathBuf;
+
+///
+/// Evaluate the `begin` declaration of the example library `name` in a test environment. The
+/// library is not imported, as loading a library from a file is not yet implemented, and so its
+/// definitions are used under their own names rather than their exported ones.
+///
+fn example_library_environment(name: &str) -> MutableRef<Environment> {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.extend(["..", "test", "lib", "example", name]);
+    let source = std::fs::read_to_string(path.with_extension("sr")).unwrap();
+    let library = parse_data_str(&source).unwrap().remove(0);
+    let begin = library
+        .as_pair()
+        .unwrap()
+        .iter()
+        .find(|declaration| {
+            declaration
+                .as_pair()
+                .and_then(|declaration| declaration.car().as_symbol().cloned())
+                .map(|keyword| keyword.as_str() == "begin")
+                .unwrap_or_default()
+        })
+        .unwrap();
+    let mut environment = test_environment();
+    let _ = Datum::clone(&begin).eval(&mut environment).unwrap();
+    environment
+}
+
+#[test]
+fn test_grid_example() {
+    let mut environment = example_library_environment("grid");
+    let result = eval_str_in(
+        r#"
+(define grid (make 2 3))
+(put! grid 1 2 'x)
+(list (rows grid) (cols grid) (ref grid 1 2) (ref grid 0 0) (ref grid 2 0) (ref grid 0 -1))
+"#,
+        &mut environment,
+    )
+    .unwrap();
+    assert_eq!(result.to_repr_string(), "'(2 3 x #f #f #f)");
+}
+
+#[test]
+fn test_grid_example_each() {
+    let mut environment = example_library_environment("grid");
+    let result = eval_str_in(
+        r#"
+(define grid (make 2 2))
+(put! grid 0 1 'a)
+(put! grid 1 0 'b)
+(define visited '())
+(each grid (lambda (j k v) (set! visited (cons (list j k v) visited))))
+(reverse visited)
+"#,
+        &mut environment,
+    )
+    .unwrap();
+    assert_eq!(
+        result.to_repr_string(),
+        "'((0 0 #f) (0 1 a) (1 0 b) (1 1 #f))"
+    );
+}
@@ -0,0 +1,304 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, Integer, Number, SchemeString};
+use schemer_macros::{eboolean, einteger};
+
+#[test]
+fn test_guard_raised_value() {
+    assert_eval_repr(
+        "(guard (e (#t `(caught ,e))) (raise 'oops))",
+        "'(caught oops)",
+    );
+    assert_eval_repr(
+        r#"(guard (e ((string? e) 'string) ((vector? e) 'vector)) (raise #(1 2)))"#,
+        "'vector",
+    );
+    assert_eval_repr("(guard (e (else 'other)) (raise 'oops))", "'other");
+}
+
+#[test]
+fn test_guard_arrow_clause() {
+    assert_eval_repr(
+        "(guard (e (e => (lambda (x) `(got ,x)))) (raise 'a))",
+        "'(got a)",
+    );
+}
+
+#[test]
+fn test_guard_no_exception() {
+    assert_eval_repr("(guard (e (#t 'caught)) 'a 'b)", "'b");
+}
+
+#[test]
+fn test_guard_reraise() {
+    assert_eval_repr(
+        r#"
+(guard (outer (#t `(outer ,outer)))
+  (guard (inner ((string? inner) 'inner))
+    (raise 'oops)))
+"#,
+        "'(outer oops)",
+    );
+    let error = assert_eval_err("(guard (e ((string? e) 'string)) (raise 'oops))");
+    assert!(matches!(error.kind(), ErrorKind::Raised { .. }));
+}
+
+#[test]
+fn test_guard_interpreter_errors() {
+    assert_eval_eq(
+        "(guard (e ((error-object? e) (error-object-message e))) (undefined-variable))",
+        Expression::String(SchemeString::from(
+            "Unbound variable: 'undefined-variable'.".to_string(),
+        )),
+    );
+    assert_eval_eq(
+        "(guard (e (#t (error-object? e))) ((lambda (x) x)))",
+        eboolean!(true),
+    );
+    assert_eval_eq("(guard (e (#t (error-object? e))) (car))", eboolean!(true));
+}
+
+#[test]
+fn test_guard_and_dynamic_wind() {
+    assert_eval_repr(
+        r#"
+(define trace '())
+(define (note x) (set! trace `(,@trace ,x)))
+(guard (e (#t (note 'handler)))
+  (dynamic-wind
+    (lambda () (note 'before))
+    (lambda () (raise 'oops))
+    (lambda () (note 'after))))
+trace
+"#,
+        "'(before after handler)",
+    );
+}
+
+#[test]
+fn test_guard_reraise_continuable() {
+    // with no matching clause the guard re-enters the body's dynamic extent and re-raises with
+    // `raise-continuable`, so the outer handler's value is returned to the raise.
+    assert_eval_eq(
+        r#"
+(with-exception-handler
+  (lambda (e) 42)
+  (lambda () (+ (guard (e (#f 0)) (raise-continuable 'x)) 1)))
+"#,
+        einteger!(43),
+    );
+    assert_eval_repr(
+        r#"
+(define trace '())
+(define (note x) (set! trace `(,@trace ,x)))
+(with-exception-handler
+  (lambda (e) (note 'handler) 'value)
+  (lambda ()
+    (guard (e ((string? e) 'string))
+      (dynamic-wind
+        (lambda () (note 'before))
+        (lambda () (note (raise-continuable 'x)))
+        (lambda () (note 'after))))))
+trace
+"#,
+        "'(before after before handler value after)",
+    );
+}
+
+#[test]
+fn test_handler_called_at_raise() {
+    // the handler runs before leaving the dynamic extent of the raise.
+    assert_eval_repr(
+        r#"
+(define trace '())
+(define (note x) (set! trace `(,@trace ,x)))
+(call/cc (lambda (k)
+  (with-exception-handler
+    (lambda (e) (note 'handler) (k e))
+    (lambda ()
+      (dynamic-wind
+        (lambda () (note 'before))
+        (lambda () (raise 'oops))
+        (lambda () (note 'after)))))))
+trace
+"#,
+        "'(before handler after)",
+    );
+    // the handler is called with the outer handlers installed.
+    assert_eval_repr(
+        r#"
+(guard (e (#t `(outer ,e)))
+  (with-exception-handler
+    (lambda (e) (raise-continuable `(inner ,e)))
+    (lambda () (raise 'x))))
+"#,
+        "'(outer (inner x))",
+    );
+}
+
+#[test]
+fn test_handler_returns_secondary_exception() {
+    // the secondary exception is raised to the outer handler, not to the one that returned.
+    assert_eval_eq(
+        r#"
+(define count 0)
+(guard (e (#t (error-object? e)))
+  (with-exception-handler
+    (lambda (e) (set! count (+ count 1)) 'ignored)
+    (lambda () (raise 'oops))))
+"#,
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        r#"
+(define count 0)
+(guard (e (#t count))
+  (with-exception-handler
+    (lambda (e) (set! count (+ count 1)) 'ignored)
+    (lambda () (raise 'oops))))
+"#,
+        einteger!(1),
+    );
+}
+
+#[test]
+fn test_guard_ignores_continuations() {
+    assert_eval_repr(
+        "(call/cc (lambda (k) (guard (e (#t 'caught)) (k 'escaped))))",
+        "'escaped",
+    );
+}
+
+#[test]
+fn test_error_objects() {
+    assert_eval_eq(
+        r#"(guard (e ((error-object? e) (error-object-message e))) (error "bad thing" 1 2))"#,
+        Expression::String(SchemeString::from("bad thing".to_string())),
+    );
+    assert_eval_repr(
+        r#"(guard (e ((error-object? e) (error-object-irritants e))) (error "bad thing" 1 2))"#,
+        "(1 2)",
+    );
+    assert_eval_repr(
+        r#"(guard (e ((error-object? e) (error-object-irritants e))) (error "bad thing"))"#,
+        "null",
+    );
+    assert_eval_repr(
+        r#"(guard (e (#t e)) (error "bad thing" 'a "b"))"#,
+        r#"#<error-object:bad thing 'a "b">"#,
+    );
+    assert_eval_eq("(error-object? 'oops)", eboolean!(false));
+}
+
+#[test]
+fn test_read_and_file_errors() {
+    assert_eval_eq(
+        r#"(read-error? (guard (e (#t e)) (error "bad thing")))"#,
+        eboolean!(false),
+    );
+    assert_eval_eq(
+        r#"(file-error? (guard (e (#t e)) (error "bad thing")))"#,
+        eboolean!(false),
+    );
+    assert_eval_eq("(file-error? 'oops)", eboolean!(false));
+}
+
+#[test]
+fn test_raise_continuable() {
+    assert_eval_repr(
+        r#"
+(with-exception-handler
+  (lambda (c) 42)
+  (lambda () `(,(raise-continuable 'oops) after)))
+"#,
+        "'(42 after)",
+    );
+}
+
+#[test]
+fn test_with_exception_handler_escape() {
+    assert_eval_repr(
+        r#"
+(call/cc (lambda (k)
+  (with-exception-handler
+    (lambda (e) (k `(caught ,e)))
+    (lambda () (raise 'boom)))))
+"#,
+        "'(caught boom)",
+    );
+    assert_eval_eq(
+        r#"
+(call/cc (lambda (k)
+  (with-exception-handler
+    (lambda (e) (k (error-object? e)))
+    (lambda () (undefined-variable)))))
+"#,
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_with_exception_handler_returns() {
+    let error =
+        assert_eval_err("(with-exception-handler (lambda (e) 'ignored) (lambda () (raise 'oops)))");
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::ExceptionHandlerReturned { .. }
+    ));
+    assert_eval_eq(
+        r#"
+(guard (e (#t (error-object? e)))
+  (with-exception-handler (lambda (e) 'ignored) (lambda () (raise 'oops))))
+"#,
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_nested_handlers() {
+    assert_eval_repr(
+        r#"
+(with-exception-handler
+  (lambda (e) `(outer ,e))
+  (lambda ()
+    (with-exception-handler
+      (lambda (e) (raise-continuable `(inner ,e)))
+      (lambda () (raise-continuable 'x)))))
+"#,
+        "'(outer (inner x))",
+    );
+    assert_eval_repr(
+        r#"
+(guard (e (#t `(guarded ,e)))
+  (with-exception-handler
+    (lambda (e) (raise `(handled ,e)))
+    (lambda () (raise 'x))))
+"#,
+        "'(guarded (handled x))",
+    );
+    assert_eval_repr(
+        r#"
+(with-exception-handler
+  (lambda (e) 'outer)
+  (lambda ()
+    (guard (e (#t `(guarded ,e)))
+      (raise-continuable 'x))))
+"#,
+        "'(guarded x)",
+    );
+}
+
+#[test]
+fn test_uncaught() {
+    assert_eq!(
+        assert_eval_err("(raise 'oops)").to_string(),
+        "An object was raised and not handled: 'oops."
+    );
+    assert_eq!(
+        assert_eval_err(r#"(error "bad thing:" 42)"#).to_string(),
+        "bad thing: 42"
+    );
+    let _ = assert_eval_err("(raise-continuable 'oops)");
+}
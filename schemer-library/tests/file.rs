@@ -0,0 +1,181 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, Integer, Number};
+use schemer_macros::{eboolean, einteger};
+
+fn temp_file(name: &str) -> String {
+    let path = std::env::temp_dir().join(format!("schemer-{}-{}", std::process::id(), name));
+    path.to_string_lossy().to_string()
+}
+
+#[test]
+fn test_textual_file_ports() {
+    let file = temp_file("textual.txt");
+    assert_eval_repr(
+        &format!(
+            r#"
+(import (scheme file))
+(define out (open-output-file "{0}"))
+(write-string "hello" out)
+(newline out)
+(write-string "world" out)
+(close-port out)
+(define in (open-input-file "{0}"))
+(define first (read-line in))
+(define second (read-line in))
+(define end (read-line in))
+(close-port in)
+(delete-file "{0}")
+(list first second (eof-object? end) (file-exists? "{0}"))
+"#,
+            file
+        ),
+        "'(\"hello\" \"world\" #t #f)",
+    );
+}
+
+#[test]
+fn test_binary_file_ports() {
+    let file = temp_file("binary.dat");
+    assert_eval_repr(
+        &format!(
+            r#"
+(import (scheme file))
+(define out (open-binary-output-file "{0}"))
+(write-bytevector #u8(1 2 3) out)
+(close-port out)
+(define in (open-binary-input-file "{0}"))
+(define bytes (read-bytevector 10 in))
+(close-port in)
+(delete-file "{0}")
+bytes
+"#,
+            file
+        ),
+        "#u8(1 2 3)",
+    );
+}
+
+#[test]
+fn test_call_with_file() {
+    let file = temp_file("call-with.txt");
+    assert_eval_eq(
+        &format!(
+            r#"
+(import (scheme file))
+(define saved #f)
+(call-with-output-file "{0}"
+  (lambda (port) (set! saved port) (write-string "abc" port)))
+(define result (list (output-port-open? saved)
+                     (call-with-input-file "{0}" (lambda (port) (read-string 2 port)))))
+(delete-file "{0}")
+(equal? result '(#f "ab"))
+"#,
+            file
+        ),
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_with_file() {
+    let file = temp_file("with.txt");
+    assert_eval_eq(
+        &format!(
+            r#"
+(import (scheme file))
+(with-output-to-file "{0}"
+  (lambda () (write-string "12") (newline)))
+(define line (with-input-from-file "{0}" read-line))
+(delete-file "{0}")
+(equal? line "12")
+"#,
+            file
+        ),
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        &format!(
+            r#"
+(import (scheme file))
+(define before (current-output-port))
+(with-output-to-file "{0}" (lambda () 1))
+(delete-file "{0}")
+(eq? before (current-output-port))
+"#,
+            file
+        ),
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_with_file_escape() {
+    let file = temp_file("escape.txt");
+    assert_eval_eq(
+        &format!(
+            r#"
+(import (scheme file))
+(define before (current-output-port))
+(define result (call/cc (lambda (k) (with-output-to-file "{0}" (lambda () (k 1))))))
+(define restored (eq? before (current-output-port)))
+(delete-file "{0}")
+(and restored (= result 1))
+"#,
+            file
+        ),
+        eboolean!(true),
+    );
+    assert_eval_eq(
+        &format!(
+            r#"
+(import (scheme file))
+(define before (current-input-port))
+(with-output-to-file "{0}" (lambda () (write-string "1")))
+(define result
+  (guard (e ((symbol? e) e))
+    (with-input-from-file "{0}" (lambda () (raise 'oops)))))
+(define restored (eq? before (current-input-port)))
+(delete-file "{0}")
+(and restored (eq? result 'oops))
+"#,
+            file
+        ),
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_file_errors() {
+    let file = temp_file("missing.txt");
+    assert_eval_eq(
+        &format!(
+            r#"
+(import (scheme file))
+(guard (e ((file-error? e) 1)
+          (else 2))
+  (open-input-file "{0}"))
+"#,
+            file
+        ),
+        einteger!(1),
+    );
+    assert_eval_eq(
+        &format!(
+            r#"
+(import (scheme file))
+(guard (e ((file-error? e) 1))
+  (call-with-input-file "{0}" read-line))
+"#,
+            file
+        ),
+        einteger!(1),
+    );
+    let error = assert_eval_err(&format!(
+        "(import (scheme file)) (open-input-file \"{}\")",
+        file
+    ));
+    assert!(matches!(error.kind(), ErrorKind::File));
+}
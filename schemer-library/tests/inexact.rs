@@ -0,0 +1,70 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::eval::Expression;
+use schemer_lang::types::Boolean;
+use schemer_macros::eboolean;
+
+const IMPORT: &str = "(import (scheme inexact)) (import (scheme complex))";
+
+fn assert_inexact_repr(src: &str, expected: &str) {
+    assert_eval_repr(&format!("{} {}", IMPORT, src), expected);
+}
+
+fn assert_inexact_true(src: &str) {
+    assert_inexact_eq(src, true);
+}
+
+fn assert_inexact_eq(src: &str, expected: bool) {
+    assert_eval_eq(&format!("{} {}", IMPORT, src), eboolean!(expected));
+}
+
+#[test]
+fn test_sqrt() {
+    assert_inexact_repr("(sqrt 16)", "4");
+    assert_inexact_true("(exact-integer? (sqrt 16))");
+    assert_inexact_repr("(sqrt 9/4)", "3/2");
+    assert_inexact_repr("(sqrt 2.25)", "1.5");
+    assert_inexact_true("(exact? (sqrt 2.25))");
+    assert_inexact_repr("(sqrt -4)", "+2i");
+    assert_inexact_true("(exact? (sqrt -4))");
+    assert_inexact_repr("(sqrt 2)", "1.4142135623730951");
+    assert_inexact_repr("(sqrt (inexact -4))", "+2.0i");
+    assert_inexact_repr("(sqrt (expt 10 40))", "100000000000000000000");
+    assert_inexact_true("(inexact? (sqrt -3+4i))");
+    assert_inexact_true("(< (magnitude (- (sqrt -3+4i) 1+2i)) 1e-15)");
+}
+
+#[test]
+fn test_exp_and_log() {
+    assert_inexact_repr("(exp 0)", "1");
+    assert_inexact_repr("(exp (inexact 0))", "1.0");
+    assert_inexact_repr("(log 1)", "0");
+    assert_inexact_true("(= (log (exp 2)) 2)");
+    assert_inexact_repr("(log 100 10)", "2.0");
+    assert_inexact_true("(= (log -1) (* (acos -1) (sqrt -1)))");
+    let _ = assert_eval_err(&format!("{} (log 2 1)", IMPORT));
+}
+
+#[test]
+fn test_trigonometry() {
+    assert_inexact_repr("(sin 0)", "0");
+    assert_inexact_repr("(cos 0)", "1");
+    assert_inexact_repr("(tan 0)", "0");
+    assert_inexact_repr("(asin 0)", "0");
+    assert_inexact_repr("(acos 1)", "0");
+    assert_inexact_repr("(atan 0)", "0");
+    assert_inexact_repr("(* 4 (atan 1))", "3.141592653589793");
+    assert_inexact_repr("(atan 1 -1)", "2.356194490192345");
+    assert_inexact_true("(inexact? (sin 1))");
+    assert_inexact_true("(complex? (asin 2))");
+    assert_inexact_eq("(real? (asin 2))", false);
+    let _ = assert_eval_err(&format!("{} (atan 0+i 1)", IMPORT));
+}
+
+#[test]
+fn test_finite_infinite_nan() {
+    assert_inexact_true("(finite? 1)");
+    assert_inexact_eq("(infinite? 1)", false);
+    assert_inexact_true("(infinite? (string->number \"-inf.0\"))");
+    assert_inexact_true("(nan? (string->number \"+nan.0\"))");
+}
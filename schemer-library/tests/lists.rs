@@ -0,0 +1,187 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, Integer, Number};
+use schemer_macros::{eboolean, einteger};
+
+#[test]
+fn test_cons_car_cdr() {
+    assert_eval_repr("(cons 1 2)", "'(1 . 2)");
+    assert_eval_repr("(cons 1 '(2 3))", "'(1 2 3)");
+    assert_eval_repr("(cons 1 '())", "'(1)");
+    assert_eval_eq("(car '(1 2 3))", einteger!(1));
+    assert_eval_repr("(cdr '(1 2 3))", "'(2 3)");
+    assert_eval_eq("(cdr '(1))", Expression::Null);
+    assert_eval_eq("(symbol? (car '(a b)))", eboolean!(true));
+    assert_eval_eq("(null? (cdr '(a)))", eboolean!(true));
+    assert_eval_eq("(pair? (cons 1 2))", eboolean!(true));
+    assert_eval_eq("(list? (cons 1 2))", eboolean!(false));
+}
+
+#[test]
+fn test_car_of_non_pair() {
+    let error = assert_eval_err("(car '())");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+    let error = assert_eval_err("(cdr 1)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_set_car_is_shared() {
+    assert_eval_repr(
+        "(define x (list 'a 'b 'c)) (define y x) (set-car! (cdr y) 'z) x",
+        "'(a z c)",
+    );
+    assert_eval_repr(
+        "(define x (list 1 2)) (define y (cdr x)) (set-cdr! y '(3 4)) x",
+        "'(1 2 3 4)",
+    );
+    assert_eval_repr(
+        "(define (f) (list 1 2 3)) (define x (f)) (set-car! x 0) (list x (f))",
+        "'((0 2 3) (1 2 3))",
+    );
+}
+
+#[test]
+fn test_set_car_on_rest_arguments() {
+    assert_eval_repr(
+        "(define (f . args) (set-car! args 0) args) (f 1 2 3)",
+        "'(0 2 3)",
+    );
+}
+
+#[test]
+fn test_cxr() {
+    assert_eval_eq("(cadr '(1 2 3))", einteger!(2));
+    assert_eval_repr("(cddr '(1 2 3))", "'(3)");
+    assert_eval_eq("(caar '((1) 2))", einteger!(1));
+    assert_eval_eq("(import (scheme cxr)) (caddr '(1 2 3 4))", einteger!(3));
+    assert_eval_eq("(import (scheme cxr)) (cadddr '(1 2 3 4))", einteger!(4));
+    assert_eval_eq(
+        "(import (scheme cxr)) (cddddr '(1 2 3 4))",
+        Expression::Null,
+    );
+}
+
+#[test]
+fn test_list_constructors() {
+    assert_eval_repr("(list 1 2 3)", "'(1 2 3)");
+    assert_eval_eq("(list)", Expression::Null);
+    assert_eval_repr("(make-list 2 'x)", "'(x x)");
+    assert_eval_eq("(length (make-list 3))", einteger!(3));
+    assert_eval_repr("(list-copy '(1 2 . 3))", "'(1 2 . 3)");
+    assert_eval_eq("(list-copy 1)", einteger!(1));
+    assert_eval_repr(
+        "(define x (list 1 2)) (define y (list-copy x)) (set-car! y 0) x",
+        "'(1 2)",
+    );
+}
+
+#[test]
+fn test_length() {
+    assert_eval_eq("(length '(1 2 3))", einteger!(3));
+    assert_eval_eq("(length '())", einteger!(0));
+    let error = assert_eval_err("(length '(1 2 . 3))");
+    assert!(matches!(error.kind(), ErrorKind::ImproperList));
+    let error = assert_eval_err("(define x (list 1 2)) (set-cdr! (cdr x) x) (length x)");
+    assert!(matches!(error.kind(), ErrorKind::ImproperList));
+    assert_eval_eq(
+        "(define x (list 1 2)) (set-cdr! (cdr x) x) (list? x)",
+        eboolean!(false),
+    );
+}
+
+#[test]
+fn test_circular_repr() {
+    assert_eval_repr(
+        "(define c (list 1 2)) (set-cdr! (cdr c) c) c",
+        "'#0=(1 2 . #0#)",
+    );
+    assert_eval_repr(
+        "(define c (list 1 2)) (set-cdr! (cdr c) (cdr c)) c",
+        "'(1 . #0=(2 . #0#))",
+    );
+    assert_eval_repr(
+        "(define c (list 1 2)) (set-car! (cdr c) c) (list c c)",
+        "'(#0=(1 #0#) #1=(1 #1#))",
+    );
+    assert_eval_repr(
+        "(define v (vector 1 2)) (vector-set! v 1 v) v",
+        "#0=#(1 #0#)",
+    );
+    let error = assert_eval_err("(define c (list 1 2)) (set-cdr! (cdr c) c) (vector-ref c 0)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+    assert!(error.to_string().contains("given: pair"));
+}
+
+#[test]
+fn test_long_lists() {
+    // dropping a long list must not recurse once for each pair.
+    assert_eval_eq("(length (make-list 1000000 1))", einteger!(1000000));
+    assert_eval_eq(
+        "(let loop ((n 100000) (acc '())) (if (= n 0) (length acc) (loop (- n 1) (cons n acc))))",
+        einteger!(100000),
+    );
+    assert_eval_eq(
+        r#"
+(define x (make-list 300000 1))
+(+ (length (reverse x)) (length (append x '(2))) (length (list-copy x)) (length (map - x)))
+"#,
+        einteger!(1200001),
+    );
+}
+
+#[test]
+fn test_append_reverse() {
+    assert_eval_repr("(append '(x) '(y))", "'(x y)");
+    assert_eval_repr("(append '(a) '(b c d))", "'(a b c d)");
+    assert_eval_repr("(append '(a (b)) '((c)))", "'(a (b) (c))");
+    assert_eval_repr("(append '(a b) '(c . d))", "'(a b c . d)");
+    assert_eval_repr("(append '() 'a)", "'a");
+    assert_eval_eq("(append)", Expression::Null);
+    assert_eval_repr(
+        "(define tail (list 3)) (define x (append '(1 2) tail)) (set-car! tail 0) x",
+        "'(1 2 0)",
+    );
+    assert_eval_repr("(reverse '(a (b c) d (e (f))))", "'((e (f)) d (b c) a)");
+    assert_eval_eq("(reverse '())", Expression::Null);
+}
+
+#[test]
+fn test_list_index() {
+    assert_eval_repr("(list-tail '(a b c d) 2)", "'(c d)");
+    assert_eval_eq("(list-ref '(1 2 3 4) 2)", einteger!(3));
+    assert_eval_repr(
+        "(define ls (list 'one 'two 'five!)) (list-set! ls 2 'three) ls",
+        "'(one two three)",
+    );
+    let error = assert_eval_err("(list-ref '(1 2) 2)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_member() {
+    assert_eval_repr("(memq 'a '(a b c))", "'(a b c)");
+    assert_eval_repr("(memq 'b '(a b c))", "'(b c)");
+    assert_eval_eq("(memq 'a '(b c d))", eboolean!(false));
+    assert_eval_eq("(memq (list 'a) '(b (a) c))", eboolean!(false));
+    assert_eval_repr("(member (list 'a) '(b (a) c))", "'((a) c)");
+    assert_eval_repr("(memv 101 '(100 101 102))", "'(101 102)");
+    assert_eval_repr("(member 2.0 '(1 2 3) =)", "'(2 3)");
+    assert_eval_repr(
+        "(define x (list 1 2 3)) (set-car! (memv 2 x) 0) x",
+        "'(1 0 3)",
+    );
+}
+
+#[test]
+fn test_assoc() {
+    assert_eval_repr("(assq 'a '((a 1) (b 2) (c 3)))", "'(a 1)");
+    assert_eval_repr("(assq 'b '((a 1) (b 2) (c 3)))", "'(b 2)");
+    assert_eval_eq("(assq 'd '((a 1) (b 2) (c 3)))", eboolean!(false));
+    assert_eval_eq("(assq (list 'a) '(((a)) ((b)) ((c))))", eboolean!(false));
+    assert_eval_repr("(assoc (list 'a) '(((a)) ((b)) ((c))))", "'((a))");
+    assert_eval_repr("(assoc 2.0 '((1 1) (2 4) (3 9)) =)", "'(2 4)");
+    assert_eval_repr("(assv 5 '((2 3) (5 7) (11 13)))", "'(5 7)");
+}
@@ -0,0 +1,301 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr, eval_str};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::Boolean;
+use schemer_macros::eboolean;
+
+#[test]
+fn test_addition_and_multiplication() {
+    assert_eval_repr("(+)", "0");
+    assert_eval_repr("(+ 1 2 3)", "6");
+    assert_eval_repr("(*)", "1");
+    assert_eval_repr("(* 2 3 4)", "24");
+    assert_eval_repr("(+ 1 1/2)", "3/2");
+    assert_eval_repr("(* 2 1/2)", "1");
+    assert_eval_eq("(exact-integer? (* 2 1/2))", eboolean!(true));
+    assert_eval_repr("(+ 1 1.5)", "2.5");
+    assert_eval_eq("(exact? (+ 1 1.5))", eboolean!(true));
+    assert_eval_eq("(inexact? (+ 1 (inexact 1/2)))", eboolean!(true));
+    assert_eval_eq("(inexact? (* 0 (inexact 1/2)))", eboolean!(true));
+}
+
+#[test]
+fn test_subtraction_and_division() {
+    assert_eval_repr("(- 3)", "-3");
+    assert_eval_repr("(- 3 4 5)", "-6");
+    assert_eval_repr("(/ 2)", "1/2");
+    assert_eval_repr("(/ 3 4 5)", "3/20");
+    assert_eval_repr("(/ 6 3)", "2");
+    assert_eval_eq("(exact-integer? (/ 6 3))", eboolean!(true));
+    assert_eval_repr("(/ (inexact 1) 4)", "0.25");
+    let error = assert_eval_err("(/ 1 0)");
+    assert!(matches!(error.kind(), ErrorKind::DivisionByZero));
+    let _ = assert_eval_err("(+ 1 'a)");
+}
+
+#[test]
+fn test_complex_contagion() {
+    assert_eval_repr("(+ 1 0+2i)", "1+2i");
+    assert_eval_repr("(* #e0+1i #e0+1i)", "-1");
+    assert_eval_eq("(inexact? (+ 0+2i (inexact 1/2)))", eboolean!(true));
+    assert_eval_repr("(* #i0+1i #i0+1i)", "-1.0+0.0i");
+    assert_eval_repr("(* 0+1i 0+1i)", "-1");
+    assert_eval_eq("(= (* 0+1i 0+1i) -1)", eboolean!(true));
+    assert_eval_eq("(exact? (* #e0+1i #e0+1i))", eboolean!(true));
+}
+
+#[test]
+fn test_comparisons() {
+    assert_eval_eq("(= 1 1 1)", eboolean!(true));
+    assert_eval_eq("(= 1 (inexact 1))", eboolean!(true));
+    assert_eval_eq("(= 1/2 0.5)", eboolean!(true));
+    assert_eval_eq("(= 1 2)", eboolean!(false));
+    assert_eval_eq("(< 1 2 3)", eboolean!(true));
+    assert_eval_eq("(< 1 3 2)", eboolean!(false));
+    assert_eval_eq("(> 3 2 1/2)", eboolean!(true));
+    assert_eval_eq("(<= 1 1 2)", eboolean!(true));
+    assert_eval_eq("(>= 2 2 3)", eboolean!(false));
+    let _ = assert_eval_err("(< 1 0+1i)");
+    let _ = assert_eval_err("(= 1)");
+}
+
+#[test]
+fn test_max_min_abs() {
+    assert_eval_repr("(max 1 3 2)", "3");
+    assert_eval_repr("(min 1 3 2)", "1");
+    assert_eval_eq("(inexact? (max 3 (inexact 1)))", eboolean!(true));
+    assert_eval_eq("(= (max 3 (inexact 1)) 3)", eboolean!(true));
+    assert_eval_repr("(abs -7)", "7");
+    assert_eval_repr("(abs -7/2)", "7/2");
+}
+
+#[test]
+fn test_integer_division() {
+    assert_eval_repr("(floor/ 5 2)", "2\n1");
+    assert_eval_repr("(floor/ -5 2)", "-3\n1");
+    assert_eval_repr("(floor/ 5 -2)", "-3\n-1");
+    assert_eval_repr("(floor/ -5 -2)", "2\n-1");
+    assert_eval_repr("(truncate/ 5 2)", "2\n1");
+    assert_eval_repr("(truncate/ -5 2)", "-2\n-1");
+    assert_eval_repr("(truncate/ 5 -2)", "-2\n1");
+    assert_eval_repr("(truncate/ -5 -2)", "2\n-1");
+    assert_eval_eq("(= (truncate-quotient (inexact -5) 2) -2)", eboolean!(true));
+    assert_eval_eq(
+        "(inexact? (truncate-quotient (inexact -5) 2))",
+        eboolean!(true),
+    );
+    assert_eval_repr("(floor-quotient -7 2)", "-4");
+    assert_eval_repr("(floor-remainder -7 2)", "1");
+    assert_eval_repr("(modulo 13 -4)", "-3");
+    assert_eval_repr("(remainder 13 -4)", "1");
+    assert_eval_repr("(quotient 13 -4)", "-3");
+    let error = assert_eval_err("(quotient 1 0)");
+    assert!(matches!(error.kind(), ErrorKind::DivisionByZero));
+    let _ = assert_eval_err("(modulo 1/2 2)");
+}
+
+#[test]
+fn test_gcd_lcm() {
+    assert_eval_repr("(gcd 32 -36)", "4");
+    assert_eval_repr("(gcd)", "0");
+    assert_eval_repr("(lcm 32 -36)", "288");
+    assert_eval_repr("(lcm)", "1");
+    assert_eval_eq("(inexact? (lcm 32 (inexact -36)))", eboolean!(true));
+}
+
+#[test]
+fn test_numerator_denominator() {
+    assert_eval_repr("(numerator (/ 6 4))", "3");
+    assert_eval_repr("(denominator (/ 6 4))", "2");
+    assert_eval_repr("(denominator 5)", "1");
+    assert_eval_eq("(= (denominator (inexact (/ 6 4))) 2)", eboolean!(true));
+    assert_eval_eq(
+        "(inexact? (denominator (inexact (/ 6 4))))",
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_rounding() {
+    assert_eval_repr("(floor -7/2)", "-4");
+    assert_eval_repr("(ceiling -7/2)", "-3");
+    assert_eval_repr("(truncate -7/2)", "-3");
+    assert_eval_repr("(round -7/2)", "-4");
+    assert_eval_repr("(round 7/2)", "4");
+    assert_eval_repr("(round 5/2)", "2");
+    assert_eval_repr("(round 7)", "7");
+    assert_eval_repr("(floor -4.3)", "-5");
+    assert_eval_repr("(round 2.5)", "2");
+    assert_eval_eq("(= (round (inexact 7/2)) 4)", eboolean!(true));
+    assert_eval_eq("(inexact? (floor (inexact 1/2)))", eboolean!(true));
+}
+
+#[test]
+fn test_rationalize() {
+    assert_eval_repr("(rationalize 3/10 1/10)", "1/3");
+    assert_eval_eq(
+        "(= (rationalize (inexact 3/10) 1/10) (inexact 1/3))",
+        eboolean!(true),
+    );
+}
+
+#[test]
+fn test_square_and_roots() {
+    assert_eval_repr("(square 42)", "1764");
+    assert_eval_repr("(square 1/2)", "1/4");
+    assert_eval_repr("(exact-integer-sqrt 4)", "2\n0");
+    assert_eval_repr("(exact-integer-sqrt 5)", "2\n1");
+    let _ = assert_eval_err("(exact-integer-sqrt -1)");
+    let _ = assert_eval_err("(exact-integer-sqrt 1/2)");
+}
+
+#[test]
+fn test_expt() {
+    assert_eval_repr("(expt 2 10)", "1024");
+    assert_eval_repr("(expt 2 -2)", "1/4");
+    assert_eval_repr("(expt 1/2 3)", "1/8");
+    assert_eval_repr("(expt 0 0)", "1");
+    assert_eval_repr("(expt #e0+1i 2)", "-1");
+    assert_eval_eq("(= (expt 4 1/2) 2)", eboolean!(true));
+    assert_eval_eq("(inexact? (expt 4 1/2))", eboolean!(true));
+    let error = assert_eval_err("(expt 0 -1)");
+    assert!(matches!(error.kind(), ErrorKind::DivisionByZero));
+}
+
+#[test]
+fn test_exact_inexact() {
+    assert_eval_eq("(exact? (exact (inexact 1/2)))", eboolean!(true));
+    assert_eval_eq("(= (exact (inexact 1/2)) 1/2)", eboolean!(true));
+    assert_eval_eq("(exact-integer? (exact (inexact 2)))", eboolean!(true));
+    assert_eval_eq("(inexact? (inexact 1/3))", eboolean!(true));
+    assert_eval_eq("(exact? (exact 1/3))", eboolean!(true));
+    assert_eval_eq("(exact? (exact (inexact 0+2i)))", eboolean!(true));
+    assert!(eval_str("(inexact (exact 0+2i))").is_ok());
+    let _: Expression = eval_str("(exact 5)").unwrap();
+}
+
+#[test]
+fn test_big_integers() {
+    assert_eval_repr("(expt 2 100)", "1267650600228229401496703205376");
+    assert_eval_repr("(* 9223372036854775807 2)", "18446744073709551614");
+    assert_eval_repr("(- -9223372036854775808 1)", "-9223372036854775809");
+    assert_eval_repr(
+        "(+ 100000000000000000000000000000 1)",
+        "100000000000000000000000000001",
+    );
+    assert_eval_repr("(/ (expt 2 100) (expt 2 98))", "4");
+    assert_eval_repr("(/ 1 (expt 2 70))", "1/1180591620717411303424");
+    assert_eval_repr("(quotient (expt 10 30) (expt 10 28))", "100");
+    assert_eval_repr(
+        "(exact-integer-sqrt (expt 10 40))",
+        "100000000000000000000\n0",
+    );
+    assert_eval_eq("(exact-integer? (expt 3 80))", eboolean!(true));
+    assert_eval_eq("(< (expt 2 64) (expt 2 65))", eboolean!(true));
+    assert_eval_eq("(= (inexact (expt 2 70)) (expt 2 70))", eboolean!(true));
+    assert_eval_eq("(if (memq 'big-numbers (features)) #t #f)", eboolean!(true));
+}
+
+#[test]
+fn test_big_integers_with_decimals() {
+    assert_eval_repr("(+ 0.5 (expt 10 30))", "2000000000000000000000000000001/2");
+    assert_eval_repr("(- 0.5 (expt 10 30))", "-1999999999999999999999999999999/2");
+    assert_eval_repr("(* 1.5 (expt 10 30))", "1500000000000000000000000000000");
+    assert_eval_repr("(* 1.5 (expt 10 28) 10)", "150000000000000000000000000000");
+    assert_eval_repr("(/ (expt 10 30) 0.5)", "2000000000000000000000000000000");
+    assert_eval_eq("(exact? (+ 0.5 (expt 10 30)))", eboolean!(true));
+    assert_eval_eq("(< 0.5 (expt 10 30))", eboolean!(true));
+    assert_eval_eq("(> (expt 10 30) 0.5)", eboolean!(true));
+    assert_eval_eq("(> 0.5 (- (expt 10 30)))", eboolean!(true));
+    assert_eval_eq(
+        "(= (+ 0.5 (expt 10 30)) (+ (expt 10 30) 1/2))",
+        eboolean!(true),
+    );
+    assert_eval_repr("(max 0.5 (expt 10 30))", "1000000000000000000000000000000");
+    assert_eval_repr("(min 0.5 (expt 10 30))", "0.5");
+    assert_eval_repr(
+        "(min 0.5 (- (expt 10 30)))",
+        "-1000000000000000000000000000000",
+    );
+}
+
+#[test]
+fn test_number_to_string() {
+    assert_eval_repr("(number->string 255)", "\"255\"");
+    assert_eval_repr("(number->string 255 16)", "\"ff\"");
+    assert_eval_repr("(number->string -5 2)", "\"-101\"");
+    assert_eval_repr("(number->string 8 8)", "\"10\"");
+    assert_eval_repr("(number->string -3/4 2)", "\"-11/100\"");
+    assert_eval_repr("(number->string 2.0 2)", "\"10\"");
+    assert_eval_repr("(number->string 1+2i 2)", "\"1+10i\"");
+    assert_eval_repr("(number->string (inexact 1))", "\"1.0\"");
+    assert_eval_repr("(number->string (string->number \"+inf.0\"))", "\"+inf.0\"");
+    let _ = assert_eval_err("(number->string (inexact 1/2) 16)");
+    let _ = assert_eval_err("(number->string 3.5 2)");
+    let _ = assert_eval_err("(number->string 1 3)");
+}
+
+#[test]
+fn test_string_to_number() {
+    assert_eval_repr("(string->number \"100\")", "100");
+    assert_eval_repr("(string->number \"100\" 16)", "256");
+    assert_eval_repr("(string->number \"#x100\" 2)", "256");
+    assert_eval_repr("(string->number \"#e#b101\" 16)", "5");
+    assert_eval_repr("(string->number \"1e2\")", "100");
+    assert_eval_repr("(string->number \"-1/3\" 8)", "-1/3");
+    assert_eval_eq("(inexact? (string->number \"#i1/2\"))", eboolean!(true));
+    assert_eval_eq("(inexact? (string->number \"#i1.5\"))", eboolean!(true));
+    assert_eval_eq("(exact? (string->number \"#e1.5\"))", eboolean!(true));
+    assert_eval_eq("(exact? (string->number \"1/2+3i\"))", eboolean!(true));
+    assert_eval_repr("(string->number \"-i\")", "-i");
+    assert_eval_repr("(string->number \"1-i\")", "1-i");
+    assert_eval_eq(
+        "(let ((z (string->number \"-nan.0\"))) (= z z))",
+        eboolean!(false),
+    );
+    assert_eval_eq("(= (string->number \"2@0\") 2)", eboolean!(true));
+    assert_eval_repr("(string->number \"1@0\")", "1.0");
+    assert_eval_eq("(real? (string->number \"-2@0\"))", eboolean!(true));
+    assert_eval_eq("(string->number \"abc\")", eboolean!(false));
+    assert_eval_eq("(string->number \"12abc\")", eboolean!(false));
+    assert_eval_eq("(string->number \"12\" 2)", eboolean!(false));
+    assert_eval_eq("(string->number \"1/0\")", eboolean!(false));
+    assert_eval_eq("(string->number \"#i1/0\")", eboolean!(false));
+    assert_eval_eq("(string->number \"1+1/0i\")", eboolean!(false));
+}
+
+#[test]
+fn test_number_string_round_trip() {
+    for value in &[
+        "1/3",
+        "-7/22",
+        "#x-ff/7",
+        "(string->number \"+inf.0\")",
+        "(string->number \"-inf.0\")",
+        "1+2i",
+        "-3/4-1/2i",
+        "#e0+1i",
+    ] {
+        assert_eval_eq(
+            &format!(
+                "(let ((z {})) (= z (string->number (number->string z))))",
+                value
+            ),
+            eboolean!(true),
+        );
+    }
+    for radix in &[2, 8, 16] {
+        assert_eval_eq(
+            &format!(
+                "(let ((q (/ (expt 3 50) 7))) (= q (string->number (number->string q {0}) {0})))",
+                radix
+            ),
+            eboolean!(true),
+        );
+    }
+    assert_eval_repr(
+        "(number->string (string->number (number->string (string->number \"-nan.0\"))))",
+        "\"-nan.0\"",
+    );
+}
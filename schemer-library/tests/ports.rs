@@ -0,0 +1,197 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr, eval_str_in, test_environment};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::{Expression, Port};
+use schemer_lang::types::{Boolean, Char, Identifier, Integer, Number, SchemeRepr};
+use schemer_macros::{eboolean, einteger};
+use std::io::Cursor;
+
+fn eval_with_port(port: Port, src: &str) -> Expression {
+    let mut environment = test_environment();
+    let _ = environment
+        .borrow_mut()
+        .insert(Identifier::from_str_unchecked("p"), Expression::Port(port))
+        .unwrap();
+    eval_str_in(src, &mut environment).unwrap()
+}
+
+fn text_input(s: &str) -> Port {
+    Port::new_textual_input("test", Cursor::new(s.as_bytes().to_vec()))
+}
+
+#[test]
+fn test_current_ports() {
+    assert_eval_eq("(port? (current-input-port))", eboolean!(true));
+    assert_eval_eq("(input-port? (current-input-port))", eboolean!(true));
+    assert_eval_eq("(output-port? (current-input-port))", eboolean!(false));
+    assert_eval_eq("(output-port? (current-output-port))", eboolean!(true));
+    assert_eval_eq("(output-port? (current-error-port))", eboolean!(true));
+    assert_eval_eq("(textual-port? (current-output-port))", eboolean!(true));
+    assert_eval_eq("(binary-port? (current-output-port))", eboolean!(false));
+    assert_eval_eq(
+        "(eq? (current-output-port) (current-output-port))",
+        eboolean!(true),
+    );
+    assert_eval_eq("(input-port-open? (current-input-port))", eboolean!(true));
+    assert_eval_eq("(port? 'port)", eboolean!(false));
+}
+
+#[test]
+fn test_eof_object() {
+    assert_eval_eq("(eof-object? (eof-object))", eboolean!(true));
+    assert_eval_eq("(eof-object? '())", eboolean!(false));
+    assert_eval_eq("(eq? (eof-object) (eof-object))", eboolean!(true));
+}
+
+#[test]
+fn test_read_char() {
+    assert_eq!(
+        eval_with_port(
+            text_input("ab"),
+            "(peek-char p) (read-char p) (read-char p)"
+        ),
+        Expression::Character(Char::from('b'))
+    );
+    assert_eq!(
+        eval_with_port(text_input("a"), "(read-char p) (read-char p)"),
+        Expression::EofObject
+    );
+    assert_eq!(
+        eval_with_port(text_input(""), "(eof-object? (peek-char p))"),
+        eboolean!(true)
+    );
+    assert_eq!(
+        eval_with_port(text_input("a"), "(char-ready? p)"),
+        eboolean!(true)
+    );
+}
+
+#[test]
+fn test_read_line_and_string() {
+    assert_eq!(
+        eval_with_port(text_input("one\ntwo"), "(read-line p) (read-line p)").to_repr_string(),
+        "\"two\""
+    );
+    assert_eq!(
+        eval_with_port(text_input("one"), "(read-line p) (read-line p)"),
+        Expression::EofObject
+    );
+    assert_eq!(
+        eval_with_port(text_input("abcde"), "(read-string 3 p)").to_repr_string(),
+        "\"abc\""
+    );
+}
+
+#[test]
+fn test_read_bytes() {
+    let input = || Port::new_binary_input("test", Cursor::new(vec![1, 2, 3]));
+    assert_eq!(
+        eval_with_port(input(), "(peek-u8 p) (read-u8 p) (read-u8 p)"),
+        einteger!(2)
+    );
+    assert_eq!(
+        eval_with_port(input(), "(read-bytevector 2 p)").to_repr_string(),
+        "#u8(1 2)"
+    );
+    assert_eq!(
+        eval_with_port(input(), "(read-bytevector 5 p) (read-u8 p)"),
+        Expression::EofObject
+    );
+    assert_eq!(eval_with_port(input(), "(u8-ready? p)"), eboolean!(true));
+    assert_eq!(eval_with_port(input(), "(binary-port? p)"), eboolean!(true));
+}
+
+#[test]
+fn test_close_port() {
+    assert_eq!(
+        eval_with_port(text_input("a"), "(close-port p) (input-port-open? p)"),
+        eboolean!(false)
+    );
+    assert_eq!(
+        eval_with_port(
+            text_input("a"),
+            "(close-output-port p) (input-port-open? p)"
+        ),
+        eboolean!(true)
+    );
+    let error = assert_eval_err("(read-char 'p)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_write_to_current_ports() {
+    assert_eval_eq("(write-char #\\a)", Expression::Unspecified);
+    assert_eval_eq(
+        "(write-string \"hello\" (current-output-port) 1 3)",
+        Expression::Unspecified,
+    );
+    assert_eval_eq("(newline (current-error-port))", Expression::Unspecified);
+    assert_eval_eq("(flush-output-port)", Expression::Unspecified);
+    let error = assert_eval_err("(write-u8 1)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+    let error = assert_eval_err("(read-char (current-output-port))");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_string_ports() {
+    assert_eval_eq(
+        "(define out (open-output-string)) \
+         (write-string \"abc\" out) (write-char #\\λ out) (newline out) \
+         (get-output-string out)",
+        Expression::String("abcλ\n".into()),
+    );
+    assert_eval_eq(
+        "(define out (open-output-string)) (get-output-string out)",
+        Expression::String("".into()),
+    );
+    assert_eval_eq(
+        "(define in (open-input-string \"one\ntwo\")) (read-line in) (read-line in)",
+        Expression::String("two".into()),
+    );
+    assert_eval_eq(
+        "(define in (open-input-string \"ab\")) (read-char in) (peek-char in)",
+        Expression::Character(Char::from('b')),
+    );
+    assert_eval_eq(
+        "(eof-object? (read-char (open-input-string \"\")))",
+        eboolean!(true),
+    );
+    assert_eval_eq("(textual-port? (open-input-string \"\"))", eboolean!(true));
+    let error = assert_eval_err("(get-output-string (current-output-port))");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+    let error = assert_eval_err("(get-output-string (open-input-string \"\"))");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_byte_vector_ports() {
+    assert_eval_repr(
+        "(define out (open-output-bytevector)) \
+         (write-u8 1 out) (write-bytevector #u8(2 3 4) out 1) \
+         (get-output-bytevector out)",
+        "#u8(1 3 4)",
+    );
+    assert_eval_eq(
+        "(define in (open-input-bytevector #u8(7 8))) (read-u8 in) (read-u8 in)",
+        einteger!(8),
+    );
+    assert_eval_eq(
+        "(eof-object? (read-u8 (open-input-bytevector (bytevector))))",
+        eboolean!(true),
+    );
+    assert_eval_eq("(binary-port? (open-output-bytevector))", eboolean!(true));
+    let error = assert_eval_err("(write-char #\\a (open-output-bytevector))");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_closed_string_port() {
+    let error =
+        assert_eval_err("(define out (open-output-string)) (close-port out) (write-char #\\a out)");
+    assert!(matches!(error.kind(), ErrorKind::ClosedPort { .. }));
+    assert_eval_eq(
+        "(define out (open-output-string)) (close-output-port out) (output-port-open? out)",
+        eboolean!(false),
+    );
+}
@@ -0,0 +1,135 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::eval::Expression;
+
+#[test]
+fn test_read_data() {
+    assert_eval_repr(
+        "(import (scheme read)) (read (open-input-string \"(a . (b #(1 2)))\"))",
+        "'(a b #(1 2))",
+    );
+    assert_eval_eq(
+        "(import (scheme read)) (import (scheme write)) \
+         (define out (open-output-string)) \
+         (write \"abc\" out) \
+         (read (open-input-string (get-output-string out)))",
+        Expression::String("abc".into()),
+    );
+    assert_eval_eq(
+        "(import (scheme read)) (symbol? (read (open-input-string \"; comment\n abc\")))",
+        Expression::Boolean(true.into()),
+    );
+}
+
+#[test]
+fn test_read_written_strings() {
+    assert_eval_eq(
+        "(import (scheme read)) (import (scheme write)) \
+         (define s (string #\\a #\\x22 #\\x5c #\\newline #\\tab #\\return #\\alarm #\\x1 #\\λ)) \
+         (define out (open-output-string)) \
+         (write s out) \
+         (equal? s (read (open-input-string (get-output-string out))))",
+        Expression::Boolean(true.into()),
+    );
+    assert_eval_eq(
+        "(import (scheme read)) \
+         (equal? (read (open-input-string \"\\\"a\\\\\\n   b\\x41;\\\"\")) \"ab\\x41;\")",
+        Expression::Boolean(true.into()),
+    );
+}
+
+#[test]
+fn test_read_leaves_rest() {
+    assert_eval_repr(
+        "(import (scheme read)) \
+         (define p (open-input-string \"abc(def) ghi\")) \
+         (define a (read p)) \
+         (define b (read-char p)) \
+         (define c (read p)) \
+         (list a b c (read-string 10 p))",
+        "'(abc #\\( def \") ghi\")",
+    );
+}
+
+#[test]
+fn test_read_eof() {
+    assert_eval_eq(
+        "(import (scheme read)) \
+         (define p (open-input-string \"1 #| comment |# \")) \
+         (read p) \
+         (eof-object? (read p))",
+        Expression::Boolean(true.into()),
+    );
+    assert_eval_eq(
+        "(import (scheme read)) (eof-object? (read (open-input-string \"\")))",
+        Expression::Boolean(true.into()),
+    );
+}
+
+#[test]
+fn test_read_errors() {
+    assert_eval_repr(
+        "(import (scheme read)) \
+         (guard (e ((read-error? e) 'read-error)) (read (open-input-string \"(a b\")))",
+        "'read-error",
+    );
+    assert_eval_repr(
+        "(import (scheme read)) \
+         (guard (e ((read-error? e) 'read-error)) (read (open-input-string \")\")))",
+        "'read-error",
+    );
+    let _ = assert_eval_err("(import (scheme read)) (read (open-output-string))");
+}
+
+#[test]
+fn test_read_labels() {
+    assert_eval_eq(
+        "(import (scheme read)) \
+         (define x (read (open-input-string \"#0=(a . #0#)\"))) \
+         (and (eq? x (cdr x)) (eq? (car x) 'a))",
+        Expression::Boolean(true.into()),
+    );
+    assert_eval_eq(
+        "(import (scheme read)) \
+         (define x (read (open-input-string \"(#0=(b) #1=#(#1#) #0# #1#)\"))) \
+         (and (eq? (list-ref x 0) (list-ref x 2)) \
+              (eq? (list-ref x 1) (list-ref x 3)) \
+              (eq? (list-ref x 1) (vector-ref (list-ref x 1) 0)))",
+        Expression::Boolean(true.into()),
+    );
+    assert_eval_repr(
+        "(import (scheme read)) \
+         (guard (e ((read-error? e) 'read-error)) (read (open-input-string \"(#0# #0=a)\")))",
+        "'read-error",
+    );
+}
+
+#[test]
+fn test_read_written_labels() {
+    assert_eval_repr(
+        "(import (scheme read)) (import (scheme write)) \
+         (define (write-read-write x) \
+           (let ((out (open-output-string)) (again (open-output-string))) \
+             (write x out) \
+             (write (read (open-input-string (get-output-string out))) again) \
+             (list (get-output-string out) (get-output-string again)))) \
+         (define x (list 1 2)) \
+         (set-cdr! (cdr x) x) \
+         (define v (vector 'a 'b)) \
+         (vector-set! v 1 v) \
+         (append (write-read-write x) (write-read-write v))",
+        "'(\"#0=(1 2 . #0#)\" \"#0=(1 2 . #0#)\" \"#0=#(a #0#)\" \"#0=#(a #0#)\")",
+    );
+}
+
+#[test]
+fn test_read_fold_case() {
+    assert_eval_repr(
+        "(import (scheme read)) \
+         (define p (open-input-string \"#!fold-case ABC Def #!no-fold-case GHI\")) \
+         (define a (read p)) \
+         (define b (read p)) \
+         (list a b (read p))",
+        "'(abc def GHI)",
+    );
+}
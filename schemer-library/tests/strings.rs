@@ -0,0 +1,96 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, Integer, Number};
+use schemer_macros::{eboolean, einteger};
+
+#[test]
+fn test_string_constructors() {
+    assert_eval_repr("(make-string 3 #\\a)", "\"aaa\"");
+    assert_eval_repr("(make-string 0)", "\"\"");
+    assert_eval_repr("(string #\\a #\\b #\\c)", "\"abc\"");
+    assert_eval_repr("(string)", "\"\"");
+    assert_eval_repr("(string-append \"ab\" \"\" \"cd\")", "\"abcd\"");
+    assert_eval_repr("(substring \"hello\" 1 3)", "\"el\"");
+}
+
+#[test]
+fn test_string_indexing_by_character() {
+    assert_eval_eq("(string-length \"héllo\")", einteger!(5));
+    assert_eval_repr("(string-ref \"héllo\" 1)", "#\\é");
+    assert_eval_repr("(string-ref \"héllo\" 2)", "#\\l");
+    assert_eval_repr("(substring \"αβγδ\" 1 3)", "\"βγ\"");
+    let error = assert_eval_err("(string-ref \"abc\" 3)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedValue { .. }));
+    let error = assert_eval_err("(substring \"abc\" 2 1)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedValue { .. }));
+}
+
+#[test]
+fn test_string_mutation() {
+    assert_eval_repr(
+        "(define s (make-string 3 #\\a)) (string-set! s 1 #\\é) s",
+        "\"aéa\"",
+    );
+    assert_eval_repr(
+        "(define s (string-copy \"abc\")) (define t s) (string-set! t 0 #\\z) s",
+        "\"zbc\"",
+    );
+    assert_eval_repr(
+        "(define s (string-copy \"abc\")) (define t (string-copy s)) (string-set! t 0 #\\z) s",
+        "\"abc\"",
+    );
+    assert_eval_repr(
+        "(define s (make-string 5 #\\-)) (string-fill! s #\\y 1 3) s",
+        "\"-yy--\"",
+    );
+    assert_eval_repr(
+        "(define a \"12345\") (define b (string-copy \"abcde\")) (string-copy! b 1 a 0 2) b",
+        "\"a12de\"",
+    );
+    assert_eval_repr(
+        "(define s (string-copy \"abcde\")) (string-copy! s 1 s 0 3) s",
+        "\"aabce\"",
+    );
+    let error = assert_eval_err("(define s (make-string 2)) (string-copy! s 1 \"abc\")");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedValue { .. }));
+}
+
+#[test]
+fn test_string_conversions() {
+    assert_eval_repr("(string->list \"abc\")", "'(#\\a #\\b #\\c)");
+    assert_eval_repr("(string->list \"abcd\" 1 3)", "'(#\\b #\\c)");
+    assert_eval_repr("(list->string (list #\\a #\\b))", "\"ab\"");
+    assert_eval_repr("(list->string '())", "\"\"");
+    assert_eval_repr("(string-copy \"abcd\" 2)", "\"cd\"");
+    assert_eval_repr("(string->vector \"abc\")", "#(#\\a #\\b #\\c)");
+    assert_eval_repr("(vector->string '#(#\\1 #\\2 #\\3) 1)", "\"23\"");
+    assert_eval_repr("(string->symbol \"mISSISSIppi\")", "'mISSISSIppi");
+    assert_eval_eq("(symbol? (string->symbol \"abc\"))", eboolean!(true));
+    assert_eval_repr("(symbol->string 'flying-fish)", "\"flying-fish\"");
+}
+
+#[test]
+fn test_string_comparison() {
+    assert_eval_eq("(string=? \"abc\" \"abc\" \"abc\")", eboolean!(true));
+    assert_eval_eq("(string=? \"abc\" \"abd\")", eboolean!(false));
+    assert_eval_eq("(string<? \"abc\" \"abd\" \"abe\")", eboolean!(true));
+    assert_eval_eq("(string<? \"abc\" \"abc\")", eboolean!(false));
+    assert_eval_eq("(string<=? \"abc\" \"abc\")", eboolean!(true));
+    assert_eval_eq("(string>? \"b\" \"a\")", eboolean!(true));
+    assert_eval_eq("(string>=? \"a\" \"b\")", eboolean!(false));
+    assert_eval_eq("(string=? \"Abc\" \"aBC\")", eboolean!(false));
+}
+
+#[test]
+fn test_string_type_errors() {
+    let error = assert_eval_err("(string-length 'abc)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+    let error = assert_eval_err("(list->string (list 1 2))");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+    assert_eval_eq(
+        "(string? (string-copy \"abc\"))",
+        Expression::Boolean(Boolean::from(true)),
+    );
+}
@@ -0,0 +1,105 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr, eval_str};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, SchemeRepr};
+use schemer_macros::eboolean;
+
+#[test]
+fn test_values() {
+    assert_eval_repr("(values 'a)", "'a");
+    let result = eval_str("(values 'a 'b)").unwrap();
+    assert!(result.is_values());
+    assert_eq!(result.to_repr_string(), "'a\n'b");
+    assert_eq!(eval_str("(values)").unwrap().into_values(), vec![]);
+}
+
+#[test]
+fn test_call_with_values() {
+    assert_eval_repr(
+        "(call-with-values (lambda () (values 'a 'b)) (lambda (x y) `(,y ,x)))",
+        "'(b a)",
+    );
+    assert_eval_repr(
+        "(call-with-values (lambda () 'a) (lambda (x) `(,x)))",
+        "'(a)",
+    );
+    assert_eval_repr(
+        "(call-with-values (lambda () (values)) (lambda args args))",
+        "null",
+    );
+    let _ = assert_eval_err("(call-with-values (lambda () (values 'a 'b)) (lambda (x) x))");
+}
+
+#[test]
+fn test_call_with_values_in_tail_position() {
+    assert_eval_repr(
+        r#"
+(define (count n acc)
+  (if (= n 0)
+      acc
+      (call-with-values (lambda () (values (- n 1) (+ acc 1))) count)))
+(count 100000 0)
+"#,
+        "100000",
+    );
+}
+
+#[test]
+fn test_continuation_values() {
+    assert_eval_repr(
+        "(call-with-values (lambda () (call/cc (lambda (k) (k 'a 'b)))) (lambda (x y) `(,x ,y)))",
+        "'(a b)",
+    );
+}
+
+#[test]
+fn test_let_values() {
+    assert_eval_repr(
+        "(let-values (((a b) (values 'a 'b)) ((c) (values 'c))) `(,a ,b ,c))",
+        "'(a b c)",
+    );
+    assert_eval_repr(
+        "(let-values (((a . rest) (values 'a 'b 'c))) `(,a ,rest))",
+        "'(a (b c))",
+    );
+    assert_eval_repr(
+        "(let*-values (((a b) (values 'a 'b)) ((c d) (values b a))) `(,c ,d))",
+        "'(b a)",
+    );
+}
+
+#[test]
+fn test_let_values_arity() {
+    let error = assert_eval_err("(let-values (((a b) (values 'a 'b 'c))) a)");
+    assert!(matches!(error.kind(), ErrorKind::ValuesCardinality { .. }));
+    assert!(error
+        .to_string()
+        .starts_with("The form 'let-values' was given 3 values"));
+    let error = assert_eval_err("(let*-values (((a b c) (values 'a 'b))) a)");
+    assert!(matches!(error.kind(), ErrorKind::ValuesCardinality { .. }));
+}
+
+#[test]
+fn test_define_values() {
+    assert_eval_repr("(define-values (a b) (values 'a 'b)) `(,b ,a)", "'(b a)");
+    assert_eval_repr(
+        "(define-values (a . rest) (values 'a 'b 'c)) `(,a ,rest)",
+        "'(a (b c))",
+    );
+    assert_eval_repr("(define-values all (values 'a 'b)) all", "'(a b)");
+    let error = assert_eval_err("(define-values (a b) (values 'a))");
+    assert!(matches!(error.kind(), ErrorKind::ValuesCardinality { .. }));
+}
+
+#[test]
+fn test_receive() {
+    assert_eval_repr(
+        "(import (srfi 8)) (receive (a . rest) (values 'a 'b 'c) `(,a ,rest))",
+        "'(a (b c))",
+    );
+    assert_eval_eq(
+        "(import (srfi 8)) (receive all (values) (null? all))",
+        eboolean!(true),
+    );
+}
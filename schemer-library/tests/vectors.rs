@@ -0,0 +1,83 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err, assert_eval_repr};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+use schemer_lang::types::{Boolean, Integer, Number};
+use schemer_macros::{eboolean, einteger};
+
+#[test]
+fn test_vector_constructors() {
+    assert_eval_repr("(vector 'a 1 \"b\")", "#(a 1 \"b\")");
+    assert_eval_repr("(vector)", "#()");
+    assert_eval_repr("(make-vector 2 'x)", "#(x x)");
+    assert_eval_eq("(vector-length (make-vector 3))", einteger!(3));
+    assert_eval_eq("(vector? (vector 1))", eboolean!(true));
+    assert_eval_eq("(vector? '#(1))", eboolean!(true));
+    assert_eval_eq("(vector? '(1))", eboolean!(false));
+}
+
+#[test]
+fn test_vector_ref_set() {
+    assert_eval_eq("(vector-ref '#(1 1 2 3 5 8 13 21) 5)", einteger!(8));
+    assert_eval_repr("(vector-ref (vector (list 1 2)) 0)", "'(1 2)");
+    assert_eval_repr(
+        "(define v (vector 1 2 3)) (define w v) (vector-set! w 0 'z) v",
+        "#(z 2 3)",
+    );
+    assert_eval_repr(
+        "(define v (vector 1 2)) (define w (vector v v)) (vector-set! v 1 'x) w",
+        "#(#(1 x) #(1 x))",
+    );
+    let error = assert_eval_err("(vector-ref (vector 1 2) 2)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedValue { .. }));
+    assert!(error.to_string().contains("an index less than 2"));
+    let error = assert_eval_err("(vector-set! (vector 1 2) 5 0)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedValue { .. }));
+    let error = assert_eval_err("(vector-ref '(1 2) 0)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
+
+#[test]
+fn test_vector_list_conversion() {
+    assert_eval_repr("(vector->list '#(dah dah didah))", "'(dah dah didah)");
+    assert_eval_repr("(vector->list '#(dah dah didah) 1)", "'(dah didah)");
+    assert_eval_repr("(vector->list '#(dah dah didah) 1 2)", "'(dah)");
+    assert_eval_repr("(list->vector '(dididit dah))", "#(dididit dah)");
+    assert_eval_repr("(list->vector '())", "#()");
+    let error = assert_eval_err("(vector->list '#(1 2) 1 3)");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedValue { .. }));
+}
+
+#[test]
+fn test_vector_copy() {
+    assert_eval_repr("(vector-copy '#(1 8 2 8))", "#(1 8 2 8)");
+    assert_eval_repr("(vector-copy '#(1 8 2 8) 1 3)", "#(8 2)");
+    assert_eval_repr(
+        "(define a (vector 1 2)) (define b (vector-copy a)) (vector-set! b 0 0) a",
+        "#(1 2)",
+    );
+    assert_eval_repr(
+        "(define a (vector 1 2 3 4 5)) (define b (vector 10 20 30 40 50)) (vector-copy! b 1 a 0 2) b",
+        "#(10 1 2 40 50)",
+    );
+    assert_eval_repr(
+        "(define a (vector 1 2 3 4 5)) (vector-copy! a 1 a 0 3) a",
+        "#(1 1 2 3 5)",
+    );
+    let error = assert_eval_err("(vector-copy! (vector 1 2) 1 (vector 1 2))");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedValue { .. }));
+}
+
+#[test]
+fn test_vector_append_fill() {
+    assert_eval_repr(
+        "(vector-append '#(a b c) (vector 'd 'e 'f))",
+        "#(a b c d e f)",
+    );
+    assert_eval_repr("(vector-append)", "#()");
+    assert_eval_repr(
+        "(define a (vector 1 2 3 4 5)) (vector-fill! a 'smash 2 4) a",
+        "#(1 2 smash smash 5)",
+    );
+    assert_eval_repr("(define a (vector 1 2 3)) (vector-fill! a 0) a", "#(0 0 0)");
+}
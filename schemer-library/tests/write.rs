@@ -0,0 +1,161 @@
+pub mod common;
+use common::{assert_eval_eq, assert_eval_err};
+use schemer_lang::error::ErrorKind;
+use schemer_lang::eval::Expression;
+
+fn assert_written(procedure: &str, setup: &str, value: &str, expected: &str) {
+    assert_eval_eq(
+        &format!(
+            "(import (scheme write)) {} \
+             (define out (open-output-string)) \
+             ({} {} out) \
+             (get-output-string out)",
+            setup, procedure, value
+        ),
+        Expression::String(expected.into()),
+    );
+}
+
+#[test]
+fn test_write_simple_data() {
+    assert_written(
+        "write",
+        "",
+        "'(1 \"two\" #\\3 four)",
+        "(1 \"two\" #\\3 four)",
+    );
+    assert_written("write", "", "'(a . b)", "(a . b)");
+    assert_written("write", "", "'(a b . c)", "(a b . c)");
+    assert_written("write", "", "'#(1 (2) #(3))", "#(1 (2) #(3))");
+    assert_written("write", "", "'()", "()");
+    assert_written("write", "", "#u8(1 2)", "#u8(1 2)");
+    assert_written("write", "", "''a", "'a");
+    assert_written("write", "", "#t", "#t");
+    assert_written("write-simple", "", "'(1 (2 3))", "(1 (2 3))");
+}
+
+#[test]
+fn test_write_named_chars() {
+    assert_written(
+        "write",
+        "",
+        "(list #\\space #\\newline #\\tab #\\null #\\delete #\\alarm #\\backspace #\\escape #\\return)",
+        "(#\\space #\\newline #\\tab #\\null #\\delete #\\alarm #\\backspace #\\escape #\\return)",
+    );
+    assert_written("write", "", "#\\x20", "#\\space");
+    assert_written("write", "", "#\\a", "#\\a");
+    assert_written("display", "", "#\\space", " ");
+}
+
+#[test]
+fn test_write_escaped_strings() {
+    assert_written(
+        "write",
+        "(define s (string #\\a #\\x22 #\\x5c #\\newline #\\tab #\\alarm #\\x1))",
+        "s",
+        "\"a\\\"\\\\\\n\\t\\a\\x1;\"",
+    );
+    assert_written("display", "(define s (string #\\a #\\x22))", "s", "a\"");
+}
+
+#[test]
+fn test_write_symbols() {
+    assert_written(
+        "write",
+        "",
+        "'(abc + - ... ->x .a <=? a.b)",
+        "(abc + - ... ->x .a <=? a.b)",
+    );
+    assert_written(
+        "write",
+        "",
+        "(string->symbol \"Hello World\")",
+        "|Hello World|",
+    );
+    assert_written("write", "", "(string->symbol \"\")", "||");
+    assert_written("write", "", "(string->symbol \"1+\")", "|1+|");
+    assert_written("write", "", "(string->symbol \"+i\")", "|+i|");
+    assert_written("write", "", "(string->symbol \".\")", "|.|");
+    assert_written("write", "", "(string->symbol \"a|b\")", "|a\\|b|");
+    assert_written("write", "", "'|a b|", "|a b|");
+    assert_written(
+        "display",
+        "",
+        "(string->symbol \"Hello World\")",
+        "Hello World",
+    );
+}
+
+#[test]
+fn test_display() {
+    assert_written("display", "", "'(\"a\" #\\b c 1.5)", "(a b c 1.5)");
+    assert_written("display", "", "\"hello\"", "hello");
+    assert_written("display", "", "#\\y", "y");
+    assert_written("display", "", "'#(\"a\")", "#(a)");
+}
+
+#[test]
+fn test_write_cycles() {
+    assert_written(
+        "write",
+        "(define x (list 1 2)) (set-cdr! (cdr x) x)",
+        "x",
+        "#0=(1 2 . #0#)",
+    );
+    assert_written(
+        "write",
+        "(define x (list 1 2)) (set-car! (cdr x) x)",
+        "x",
+        "#0=(1 #0#)",
+    );
+    assert_written(
+        "write",
+        "(define v (vector 1 2)) (vector-set! v 1 v)",
+        "v",
+        "#0=#(1 #0#)",
+    );
+    assert_written(
+        "display",
+        "(define x (list \"a\")) (set-cdr! x x)",
+        "x",
+        "#0=(a . #0#)",
+    );
+}
+
+#[test]
+fn test_write_shared() {
+    assert_written(
+        "write-shared",
+        "(define y (list 'a))",
+        "(list y y)",
+        "(#0=(a) #0#)",
+    );
+    assert_written("write", "(define y (list 'a))", "(list y y)", "((a) (a))");
+    assert_written(
+        "write-shared",
+        "(define y (list 'a)) (define z (vector 1))",
+        "(list y z y z)",
+        "(#0=(a) #1=#(1) #0# #1#)",
+    );
+    assert_written(
+        "write-shared",
+        "(define x (list 1 2)) (set-cdr! (cdr x) x)",
+        "x",
+        "#0=(1 2 . #0#)",
+    );
+    assert_written("write-shared", "", "'(1 2)", "(1 2)");
+}
+
+#[test]
+fn test_write_to_current_port() {
+    assert_eval_eq(
+        "(import (scheme write)) (write 'a)",
+        Expression::Unspecified,
+    );
+    assert_eval_eq(
+        "(import (scheme write)) (display \"a\")",
+        Expression::Unspecified,
+    );
+    let error = assert_eval_err("(import (scheme write)) (write 'a (open-input-string \"\"))");
+    assert!(matches!(error.kind(), ErrorKind::UnexpectedType { .. }));
+}
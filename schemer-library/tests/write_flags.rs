@@ -0,0 +1,43 @@
+pub mod common;
+use common::assert_eval_eq;
+use schemer_lang::eval::Expression;
+use schemer_lang::parameters::{
+    set_global_flag, WRITE_BOOLEAN_LONG_FORM, WRITE_CONS_LONG_FORM, WRITE_QUOTE_LONG_FORM,
+};
+
+fn assert_written(value: &str, expected: &str) {
+    assert_eval_eq(
+        &format!(
+            "(import (scheme write)) \
+             (define out (open-output-string)) \
+             (write {} out) \
+             (get-output-string out)",
+            value
+        ),
+        Expression::String(expected.into()),
+    );
+}
+
+// the flags are global, so they are only changed within this one test.
+#[test]
+fn test_write_long_forms() {
+    assert_written("'(#t 'a (b c))", "(#t 'a (b c))");
+
+    assert_eq!(set_global_flag(WRITE_BOOLEAN_LONG_FORM, true), Some(false));
+    assert_written("'(#t #f)", "(#true #false)");
+    let _ = set_global_flag(WRITE_BOOLEAN_LONG_FORM, false);
+
+    let _ = set_global_flag(WRITE_CONS_LONG_FORM, true);
+    assert_written("'(a (b) . c)", "(a . ((b . ()) . c))");
+    let _ = set_global_flag(WRITE_CONS_LONG_FORM, false);
+
+    let _ = set_global_flag(WRITE_QUOTE_LONG_FORM, true);
+    assert_written(
+        "'(a 'b `(c ,d))",
+        "(a (quote b) (quasiquote (c (unquote d))))",
+    );
+    let _ = set_global_flag(WRITE_QUOTE_LONG_FORM, false);
+
+    assert_eq!(set_global_flag("no-such-flag", true), None);
+    assert_written("'(#t 'a (b c))", "(#t 'a (b c))");
+}
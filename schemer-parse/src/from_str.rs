@@ -83,6 +83,32 @@ pub fn string_to_number(s: &str) -> Result<Number, Error> {
     parse_number_str(s)
 }
 
+///
+/// Parse a number where `radix` is the default radix, used only if the string does not
+/// include its own radix prefix.
+///
+pub fn string_to_number_radix(s: &str, radix: u32) -> Result<Number, Error> {
+    let radix_prefix = match radix {
+        2 => "#b",
+        8 => "#o",
+        10 => "#d",
+        16 => "#x",
+        _ => {
+            return Err(ErrorKind::UnexpectedValue {
+                type_name: "radix".to_string(),
+                expected: "2, 8, 10, or 16".to_string(),
+                actual: radix.to_string(),
+            }
+            .into())
+        }
+    };
+    if radix == 10 || has_radix_prefix(s) {
+        parse_number_str(s)
+    } else {
+        parse_number_str(&format!("{}{}", radix_prefix, s))
+    }
+}
+
 pub fn string_to_symbol(_s: &str) -> Result<Identifier, Error> {
     todo!()
 }
@@ -99,6 +125,15 @@ pub fn string_to_list(_s: &str) -> Result<Identifier, Error> {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+fn has_radix_prefix(s: &str) -> bool {
+    // The prefix is at most two markers, one for exactness and one for radix, in either order.
+    s.as_bytes()
+        .chunks(2)
+        .take(2)
+        .take_while(|marker| marker.len() == 2 && marker[0] == b'#')
+        .any(|marker| matches!(marker[1].to_ascii_lowercase(), b'b' | b'o' | b'd' | b'x'))
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -37,3 +37,5 @@ extern crate pest_derive;
 pub mod from_str;
 
 pub mod parser;
+
+pub mod reader;
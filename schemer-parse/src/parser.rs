@@ -9,22 +9,28 @@ More detailed description, with
 
 use crate::from_str::{string_to_boolean, string_to_char};
 use num::complex::Complex;
-use num::traits::Zero;
+use num::traits::{Num, One, Zero};
 use pest::iterators::Pair;
 use pest::Parser;
 use schemer_lang::error::{Error, ErrorKind};
 use schemer_lang::parameters::{get_global_flag, DEBUG_SHOW_TOKEN_TREE};
-use schemer_lang::read::datum::{Abbreviation, Datum};
+use schemer_lang::read::datum::{Abbreviation, Datum, Label};
+use schemer_lang::read::syntax_str::{
+    SYNTAX_DIRECTIVE_FOLD_CASE, SYNTAX_HASH_CHAR, SYNTAX_MATH_EQUALITY_CHAR,
+    SYNTAX_VERTICAL_LINE_CHAR,
+};
 use schemer_lang::read::tokens::Token;
-use schemer_lang::types::numbers::conv::{
-    exact_to_inexact, inexact_complex_to_exact_complex, integer_to_inexact_real,
-    rational_to_inexact_real,
+use schemer_lang::types::char_folding::fold_string;
+use schemer_lang::types::numbers::conv::{exact_to_inexact, inexact_to_exact};
+use schemer_lang::types::numbers::{
+    TYPE_NAME_EXACT_REAL, TYPE_NAME_INTEGER, TYPE_NAME_NUMBER, TYPE_NAME_RATIONAL,
 };
-use schemer_lang::types::numbers::{TYPE_NAME_EXACT_REAL, TYPE_NAME_INTEGER};
+use schemer_lang::types::strings::TYPE_NAME_BYTE;
 use schemer_lang::types::{
-    lists::vector_to_list, ExactReal, Identifier, InexactComplex, InexactReal, InfNan, Integer,
-    Number, Pair as DatumPair, Rational, Ref, SchemeString, Vector,
+    lists::vector_to_list, ByteVector, Char, ExactComplex, ExactReal, Identifier, InexactComplex,
+    InexactReal, InfNan, Integer, Number, Pair as DatumPair, Rational, Ref, SchemeString, Vector,
 };
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fmt::Debug;
 use std::str::FromStr;
@@ -52,6 +58,10 @@ where
 
 const SIGN_NEGATIVE: &str = "-";
 
+const TYPE_NAME_LABEL: &str = "label";
+
+type Labels = HashMap<Label, Ref<Datum>>;
+
 macro_rules! debug_token_tree {
     ($parsed:expr) => {
         if get_global_flag(DEBUG_SHOW_TOKEN_TREE).unwrap_or_default() {
@@ -109,11 +119,32 @@ pub fn parse_datum_str(source: &str) -> Result<Datum, Error> {
     parse_datum(pair)
 }
 
+///
+/// Parse a single datum from the start of `source`, returning any input that follows it. The
+/// datum is returned as a reference so that a datum whose label is referenced within itself, as
+/// in `#0=(a . #0#)`, is the same datum as the one it contains.
+///
+pub fn parse_datum_prefix_str(source: &str) -> Result<Parsed<'_, Ref<Datum>>, Error> {
+    let mut parsed = SimpleSyntax::parse(Rule::datum, source)
+        .map_err(|e| Error::chain(Box::new(e), ErrorKind::Parser))?;
+    let matched_str = parsed.as_str();
+    debug_token_tree!(parsed);
+    let pair = parsed.next().unwrap();
+    Ok(make_parsed(parse_datum_ref(pair)?, source, matched_str))
+}
+
 pub fn parse_number_str(source: &str) -> Result<Number, Error> {
     let mut parsed = SimpleSyntax::parse(Rule::number, source)
         .map_err(|e| Error::chain(Box::new(e), ErrorKind::Parser))?;
     debug_token_tree!(parsed);
     let pair = parsed.next().unwrap();
+    if pair.as_str().len() < source.len() {
+        return Err(ErrorKind::ParseValue {
+            kind: TYPE_NAME_NUMBER.to_string(),
+            value: source.to_string(),
+        }
+        .into());
+    }
     parse_number(pair)
 }
 
@@ -178,9 +209,11 @@ fn parse_tokens(input_pair: Pair<'_, Rule>) -> Result<Vec<Token>, Error> {
     let mut tokens: Vec<Token> = Default::default();
     match input_pair.as_rule() {
         Rule::tokens => {
+            let mut fold_case = false;
             for inner_pair in input_pair.into_inner() {
                 match inner_pair.as_rule() {
-                    Rule::token => tokens.push(parse_token(inner_pair)?),
+                    Rule::directive => fold_case = is_fold_case(&inner_pair),
+                    Rule::token => tokens.push(parse_token(inner_pair, fold_case)?),
                     Rule::EOI => {}
                     _ => unexpected_input!(inner_pair),
                 }
@@ -191,7 +224,7 @@ fn parse_tokens(input_pair: Pair<'_, Rule>) -> Result<Vec<Token>, Error> {
     Ok(tokens)
 }
 
-fn parse_token(input_pair: Pair<'_, Rule>) -> Result<Token, Error> {
+fn parse_token(input_pair: Pair<'_, Rule>, fold_case: bool) -> Result<Token, Error> {
     match input_pair.as_rule() {
         Rule::token => {
             let span = input_pair.as_str();
@@ -207,13 +240,13 @@ fn parse_token(input_pair: Pair<'_, Rule>) -> Result<Token, Error> {
                     Rule::comma => Ok(Token::Unquote),
                     Rule::comma_at => Ok(Token::UnquoteSplicing),
                     Rule::period => Ok(Token::Dot),
-                    Rule::identifier => {
-                        let identifier = Identifier::from_str_unchecked(inner_pair.as_str());
-                        Ok(Token::Identifier(identifier))
-                    }
+                    Rule::identifier => Ok(Token::Identifier(parse_identifier(
+                        inner_pair.as_str(),
+                        fold_case,
+                    ))),
                     Rule::boolean => Ok(string_to_boolean(span)?.into()),
                     Rule::number => Ok(parse_number(inner_pair)?.simplify().into()),
-                    Rule::character => Ok(string_to_char(span)?.into()),
+                    Rule::character => Ok(parse_character(span, fold_case)?.into()),
                     Rule::string => Ok(SchemeString::from_str(span)?.into()),
                     _ => unexpected_input!(inner_pair),
                 }
@@ -231,75 +264,71 @@ fn parse_number(input_pair: Pair<'_, Rule>) -> Result<Number, Error> {
     let mut negative = false;
 
     for inner_pair in input_pair.into_inner() {
-        match inner_pair.as_rule() {
+        let number: Number = match inner_pair.as_rule() {
             Rule::exactness => {
-                exact = if inner_pair.as_str() == "#e" {
-                    Some(true)
-                } else {
-                    Some(false)
-                }
+                exact = Some(inner_pair.as_str().eq_ignore_ascii_case("#e"));
+                continue;
+            }
+            Rule::radix_2 => {
+                radix = 2;
+                continue;
+            }
+            Rule::radix_8 => {
+                radix = 8;
+                continue;
+            }
+            Rule::radix_10 => {
+                radix = 10;
+                continue;
+            }
+            Rule::radix_16 => {
+                radix = 16;
+                continue;
+            }
+            Rule::sign => {
+                negative = inner_pair.as_str() == SIGN_NEGATIVE;
+                continue;
             }
-            Rule::radix_2 => radix = 2,
-            Rule::radix_8 => radix = 8,
-            Rule::radix_10 => radix = 10,
-            Rule::radix_16 => radix = 16,
-            Rule::sign => negative = inner_pair.as_str() == SIGN_NEGATIVE,
             Rule::polar_2 | Rule::polar_8 | Rule::polar_10 | Rule::polar_16 => {
-                let number = parse_polar_complex_number(inner_pair, radix)?;
-                return Ok(if let Some(true) = exact {
-                    Number::from(inexact_complex_to_exact_complex(number)?)
-                } else {
-                    Number::from(number)
-                });
+                parse_polar_complex_number(inner_pair, radix)?
             }
             Rule::cartesian_2 | Rule::cartesian_8 | Rule::cartesian_10 | Rule::cartesian_16 => {
-                let number = parse_cartesian_complex_number(inner_pair, radix)?;
-                return Ok(if let Some(true) = exact {
-                    Number::from(inexact_complex_to_exact_complex(number)?)
-                } else {
-                    Number::from(number)
-                });
+                parse_cartesian_complex_number(inner_pair, radix)?
             }
-            Rule::decimal_10 => return Ok(parse_decimal_number(inner_pair, negative)?.into()),
+            Rule::decimal_10 => parse_decimal_number(inner_pair, negative)?.into(),
             Rule::infnan => {
-                return if let Some(true) = exact {
-                    Err(ErrorKind::ParseValue {
+                if let Some(true) = exact {
+                    return Err(ErrorKind::ParseValue {
                         kind: "exact".to_string(),
                         value: inner_pair.as_str().to_string(),
                     }
-                    .into())
-                } else {
-                    Ok(InexactReal::from(InfNan::from_str(inner_pair.as_str())?).into())
+                    .into());
                 }
+                InexactReal::from(InfNan::from_str(inner_pair.as_str())?).into()
             }
             Rule::uratio_2 | Rule::uratio_8 | Rule::uratio_10 | Rule::uratio_16 => {
-                let number = parse_rational_number(inner_pair, radix, negative)?;
-                return Ok(if let Some(false) = exact {
-                    Number::from(rational_to_inexact_real(number)?)
-                } else {
-                    Number::from(number)
-                });
+                parse_rational_number(inner_pair, radix, negative)?.into()
             }
             Rule::uinteger_2 | Rule::uinteger_8 | Rule::uinteger_10 | Rule::uinteger_16 => {
-                let number = parse_integer_number(inner_pair, radix, negative)?;
-                return Ok(if let Some(false) = exact {
-                    Number::from(integer_to_inexact_real(number)?)
-                } else {
-                    Number::from(number)
-                });
+                parse_integer_number(inner_pair, radix, negative)?.into()
             }
             _ => {
                 unexpected_input!(inner_pair)
             }
-        }
+        };
+        return match exact {
+            Some(true) => inexact_to_exact(number),
+            Some(false) => exact_to_inexact(number),
+            None => Ok(number),
+        };
     }
     unreachable!()
 }
 
-fn parse_polar_complex_number(
-    input_pair: Pair<'_, Rule>,
-    radix: u32,
-) -> Result<InexactComplex, Error> {
+///
+/// A polar number is inexact; one with a zero angle is simply its magnitude, as a real.
+///
+fn parse_polar_complex_number(input_pair: Pair<'_, Rule>, radix: u32) -> Result<Number, Error> {
     let mut inner_pairs = input_pair.into_inner();
     let next_pair = inner_pairs.next().unwrap();
     let (negative, next_pair) = if next_pair.as_rule() == Rule::sign {
@@ -326,17 +355,16 @@ fn parse_polar_complex_number(
     };
     let t = parse_real_number(next_pair, radix, negative)?;
 
-    Ok(Complex::from_polar(
-        InexactReal::try_from(exact_to_inexact(r)?)?,
-        InexactReal::try_from(exact_to_inexact(t)?)?,
-    )
-    .into())
+    let r = InexactReal::try_from(exact_to_inexact(r)?)?;
+    let t = InexactReal::try_from(exact_to_inexact(t)?)?;
+    if t.is_zero() {
+        Ok(r.into())
+    } else {
+        Ok(InexactComplex::from(Complex::from_polar(r, t)).into())
+    }
 }
 
-fn parse_cartesian_complex_number(
-    input_pair: Pair<'_, Rule>,
-    radix: u32,
-) -> Result<InexactComplex, Error> {
+fn parse_cartesian_complex_number(input_pair: Pair<'_, Rule>, radix: u32) -> Result<Number, Error> {
     let mut real: Number = Integer::zero().into();
     let mut negative = false;
     let mut imag: Number = Integer::zero().into();
@@ -344,9 +372,9 @@ fn parse_cartesian_complex_number(
     let mut state = 0;
     for inner_pair in input_pair.into_inner() {
         //
-        //  [([+-] {real} | infnan)] ([+-] {real} | infnan) "i"
-        //   ↑     ↑        ↑        ↑     ↑        ↑        ↑
-        //   0→1   0|1→2    0→2      2→3   2|3→4    2→4      2|4→5
+        //  [([+-] {real} | infnan)] ([+-] [{real}] | infnan) "i"
+        //   ↑     ↑        ↑         ↑     ↑         ↑        ↑
+        //   0→1   0|1→2    0→2       2→3   2|3→4     2→4      1|2|3|4→5
         //
         match (state, inner_pair.as_rule()) {
             (0, Rule::sign) => {
@@ -399,6 +427,15 @@ fn parse_cartesian_complex_number(
                     .map(Number::from)?;
                 state = 4;
             }
+            (1, Rule::i) => {
+                // A lone "+i" or "-i", the unit is the imaginary part.
+                real = unit_integer(negative).into();
+                state = 2;
+            }
+            (3, Rule::i) => {
+                imag = unit_integer(negative).into();
+                state = 4;
+            }
             (2 | 4, Rule::i) => {
                 negative = false;
             }
@@ -406,18 +443,25 @@ fn parse_cartesian_complex_number(
         }
     }
 
-    // TODO: Allow rational and integer values.
+    let (real, imag) = if state == 2 {
+        (Integer::zero().into(), real)
+    } else {
+        (real, imag)
+    };
 
-    if state == 2 {
-        Ok(InexactComplex::new(
-            InexactReal::zero(),
-            InexactReal::try_from(real)?,
-        ))
+    if real.is_exact() && imag.is_exact() {
+        Ok(ExactComplex::new(ExactReal::try_from(real)?, ExactReal::try_from(imag)?).into())
     } else {
-        Ok(InexactComplex::new(
-            InexactReal::try_from(real)?,
-            InexactReal::try_from(imag)?,
-        ))
+        Ok(InexactComplex::new(InexactReal::try_from(real)?, InexactReal::try_from(imag)?).into())
+    }
+}
+
+#[inline]
+fn unit_integer(negative: bool) -> Integer {
+    if negative {
+        -Integer::one()
+    } else {
+        Integer::one()
     }
 }
 
@@ -446,6 +490,7 @@ fn parse_rational_number(
     radix: u32,
     negative: bool,
 ) -> Result<Rational, Error> {
+    let source = input_pair.as_str();
     let mut inner_pairs = input_pair.into_inner();
     let next_pair = inner_pairs.next().unwrap();
     let n = parse_integer_number(next_pair, radix, negative)?;
@@ -455,6 +500,13 @@ fn parse_rational_number(
 
     let next_pair = inner_pairs.next().unwrap();
     let d = parse_integer_number(next_pair, radix, false)?;
+    if d.is_zero() {
+        return Err(ErrorKind::ParseValue {
+            kind: TYPE_NAME_RATIONAL.to_string(),
+            value: source.to_string(),
+        }
+        .into());
+    }
 
     Ok(Rational::new(n, d))
 }
@@ -530,10 +582,12 @@ fn parse_data(input_pair: Pair<'_, Rule>) -> Result<Vec<Datum>, Error> {
     let mut data: Vec<Datum> = Default::default();
     match input_pair.as_rule() {
         Rule::data => {
+            let mut fold_case = false;
             for inner_pair in input_pair.into_inner() {
                 match inner_pair.as_rule() {
+                    Rule::directive => fold_case = is_fold_case(&inner_pair),
                     Rule::datum => {
-                        if let Some(datum) = parse_maybe_datum(inner_pair)? {
+                        if let Some(datum) = parse_maybe_datum(inner_pair, &mut fold_case)? {
                             data.push(datum)
                         }
                     }
@@ -547,61 +601,178 @@ fn parse_data(input_pair: Pair<'_, Rule>) -> Result<Vec<Datum>, Error> {
     Ok(data)
 }
 
-fn parse_maybe_datum(input_pair: Pair<'_, Rule>) -> Result<Option<Datum>, Error> {
+fn parse_maybe_datum(
+    input_pair: Pair<'_, Rule>,
+    fold_case: &mut bool,
+) -> Result<Option<Datum>, Error> {
     match input_pair.as_rule() {
-        Rule::datum => Ok(Some(parse_datum_inner(input_pair)?)),
+        Rule::datum => Ok(Some(unwrap_datum(resolve_labels(parse_datum_inner(
+            input_pair, fold_case,
+        )?)?))),
         Rule::EOI => Ok(None),
         _ => unexpected_input!(input_pair),
     }
 }
 
 fn parse_datum(input_pair: Pair<'_, Rule>) -> Result<Datum, Error> {
+    Ok(unwrap_datum(parse_datum_ref(input_pair)?))
+}
+
+fn parse_datum_ref(input_pair: Pair<'_, Rule>) -> Result<Ref<Datum>, Error> {
     match input_pair.as_rule() {
-        Rule::datum => parse_datum_inner(input_pair),
+        Rule::datum => resolve_labels(parse_datum_inner(input_pair, &mut false)?),
         _ => unexpected_input!(input_pair),
     }
 }
 
-fn parse_datum_inner(input_pair: Pair<'_, Rule>) -> Result<Datum, Error> {
-    let mut inner_pairs = input_pair.into_inner();
+///
+/// Parse a datum, `fold_case` is updated by any directive within it so that the directive also
+/// applies to the data that follow.
+///
+fn parse_datum_inner(input_pair: Pair<'_, Rule>, fold_case: &mut bool) -> Result<Datum, Error> {
+    let mut inner_pairs = input_pair
+        .into_inner()
+        .filter(|inner_pair| !update_fold_case(inner_pair, fold_case))
+        .collect::<Vec<_>>()
+        .into_iter();
     let input_pair = inner_pairs.next().unwrap();
     let datum: Datum = match input_pair.as_rule() {
-        Rule::symbol => {
-            let symbol = Identifier::from_str_unchecked(input_pair.as_str());
-            symbol.into()
-        }
+        Rule::symbol => parse_identifier(input_pair.as_str(), *fold_case).into(),
         Rule::boolean => string_to_boolean(input_pair.as_str())?.into(),
         Rule::number => parse_number(input_pair)?.simplify().into(),
-        Rule::character => string_to_char(input_pair.as_str())?.into(),
+        Rule::character => parse_character(input_pair.as_str(), *fold_case)?.into(),
         Rule::string => SchemeString::from_str(input_pair.as_str())?.into(),
-        Rule::pair => parse_pair(input_pair)?.into(),
-        Rule::list => parse_list(input_pair)?.into(),
-        Rule::vector => parse_vector(input_pair)?.into(),
-        Rule::abbreviation => parse_abbreviation(input_pair)?.into(),
+        Rule::byte_vector => parse_byte_vector(input_pair, fold_case)?.into(),
+        Rule::pair => parse_pair(input_pair, fold_case)?.into(),
+        Rule::list => parse_list(input_pair, fold_case)?.into(),
+        Rule::vector => parse_vector(input_pair, fold_case)?.into(),
+        Rule::abbreviation => parse_abbreviation(input_pair, fold_case)?.into(),
+        Rule::label => {
+            let label = parse_label(input_pair)?;
+            match inner_pairs.next() {
+                Some(inner_pair) => {
+                    Datum::Labeled(label, Ref::new(parse_datum_inner(inner_pair, fold_case)?))
+                }
+                None => Datum::LabelRef(label),
+            }
+        }
         _ => unexpected_input!(input_pair),
     };
     assert!(inner_pairs.next().is_none());
     Ok(datum)
 }
 
-fn parse_list(input_pair: Pair<'_, Rule>) -> Result<Datum, Error> {
+fn parse_label(input_pair: Pair<'_, Rule>) -> Result<Label, Error> {
+    let label = input_pair.as_str();
+    Label::from_str(&label[1..]).map_err(|e| {
+        Error::chain(
+            Box::new(e),
+            ErrorKind::ParseValue {
+                kind: TYPE_NAME_LABEL.to_string(),
+                value: label.to_string(),
+            },
+        )
+    })
+}
+
+///
+/// Replace each labeled datum, `#n=datum`, with the datum itself and each reference, `#n#`, with
+/// that same datum; so that `#0=(a . #0#)` is read as a circular list.
+///
+fn resolve_labels(datum: Datum) -> Result<Ref<Datum>, Error> {
+    resolve_datum(&Ref::new(datum), &mut Labels::default())
+}
+
+fn resolve_datum(datum: &Ref<Datum>, labels: &mut Labels) -> Result<Ref<Datum>, Error> {
+    match &**datum {
+        Datum::Labeled(label, labeled) => {
+            if labels.contains_key(label) {
+                return Err(label_error(label, SYNTAX_MATH_EQUALITY_CHAR));
+            }
+            if labeled.is_pair() || labeled.is_vector() {
+                // a list or vector is updated in place, and so may refer to itself.
+                let _ = labels.insert(*label, labeled.clone());
+                resolve_datum(labeled, labels)
+            } else {
+                let resolved = resolve_datum(labeled, labels)?;
+                let _ = labels.insert(*label, resolved.clone());
+                Ok(resolved)
+            }
+        }
+        Datum::LabelRef(label) => labels
+            .get(label)
+            .cloned()
+            .ok_or_else(|| label_error(label, SYNTAX_HASH_CHAR)),
+        Datum::List(_) => {
+            let mut rest = datum.clone();
+            while let Datum::List(pair) = &*rest {
+                let car = resolve_datum(&pair.car(), labels)?;
+                pair.set_car(car);
+                let cdr = pair.cdr();
+                if cdr.is_pair() {
+                    rest = cdr;
+                } else {
+                    pair.set_cdr(resolve_datum(&cdr, labels)?);
+                    break;
+                }
+            }
+            Ok(datum.clone())
+        }
+        Datum::Vector(vector) => {
+            let elements = vector.as_vec().clone();
+            for (index, element) in elements.iter().enumerate() {
+                let resolved = resolve_datum(element, labels)?;
+                vector.as_vec_mut()[index] = resolved;
+            }
+            Ok(datum.clone())
+        }
+        Datum::Abbreviation(abbreviation, quoted) => Ok(Ref::new(Datum::Abbreviation(
+            abbreviation.clone(),
+            resolve_datum(quoted, labels)?,
+        ))),
+        _ => Ok(datum.clone()),
+    }
+}
+
+fn label_error(label: &Label, suffix: char) -> Error {
+    ErrorKind::ParseValue {
+        kind: TYPE_NAME_LABEL.to_string(),
+        value: format!("{}{}{}", SYNTAX_HASH_CHAR, label, suffix),
+    }
+    .into()
+}
+
+///
+/// The datum behind `datum`, which is copied only if it is shared; as it will be only when it
+/// refers to itself through a label.
+///
+fn unwrap_datum(datum: Ref<Datum>) -> Datum {
+    Ref::try_unwrap(datum).unwrap_or_else(|datum| (*datum).clone())
+}
+
+fn parse_list(input_pair: Pair<'_, Rule>, fold_case: &mut bool) -> Result<Datum, Error> {
     let mut list_data: Vec<Datum> = Vec::default();
     for inner_pair in input_pair.into_inner() {
         match inner_pair.as_rule() {
-            Rule::datum => list_data.push(parse_datum_inner(inner_pair)?),
+            Rule::directive => *fold_case = is_fold_case(&inner_pair),
+            Rule::datum => list_data.push(parse_datum_inner(inner_pair, fold_case)?),
             _ => unexpected_input!(inner_pair),
         }
     }
-    Ok(Datum::List(vector_to_list(Vector::from(list_data))))
+    if list_data.is_empty() {
+        Ok(Datum::Null)
+    } else {
+        Ok(Datum::List(vector_to_list(Vector::from(list_data))))
+    }
 }
 
-fn parse_pair(input_pair: Pair<'_, Rule>) -> Result<DatumPair, Error> {
+fn parse_pair(input_pair: Pair<'_, Rule>, fold_case: &mut bool) -> Result<DatumPair, Error> {
     let mut data = Vec::new();
     for next_pair in input_pair.into_inner() {
-        if next_pair.as_rule() == Rule::datum {
-            data.push(parse_datum_inner(next_pair)?)
-        } else {
-            unexpected_input!(next_pair);
+        match next_pair.as_rule() {
+            Rule::directive => *fold_case = is_fold_case(&next_pair),
+            Rule::datum => data.push(parse_datum_inner(next_pair, fold_case)?),
+            _ => unexpected_input!(next_pair),
         }
     }
 
@@ -615,19 +786,45 @@ fn parse_pair(input_pair: Pair<'_, Rule>) -> Result<DatumPair, Error> {
     Ok(head)
 }
 
-fn parse_vector(input_pair: Pair<'_, Rule>) -> Result<Datum, Error> {
+fn parse_vector(input_pair: Pair<'_, Rule>, fold_case: &mut bool) -> Result<Datum, Error> {
     let mut vector: Vec<Ref<Datum>> = Vec::default();
     for inner_pair in input_pair.into_inner() {
         match inner_pair.as_rule() {
-            Rule::datum => vector.push(Ref::new(parse_datum_inner(inner_pair)?.into())),
+            Rule::directive => *fold_case = is_fold_case(&inner_pair),
+            Rule::datum => vector.push(Ref::new(parse_datum_inner(inner_pair, fold_case)?.into())),
             _ => unexpected_input!(inner_pair),
         }
     }
     Ok(Datum::Vector(Vector::from(vector)))
 }
 
-fn parse_abbreviation(input_pair: Pair<'_, Rule>) -> Result<Datum, Error> {
-    let mut inner_pairs = input_pair.into_inner();
+fn parse_byte_vector(
+    input_pair: Pair<'_, Rule>,
+    fold_case: &mut bool,
+) -> Result<ByteVector, Error> {
+    let mut bytes: Vec<u8> = Vec::default();
+    for inner_pair in input_pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::directive => *fold_case = is_fold_case(&inner_pair),
+            Rule::left_byte_vec | Rule::right_paren => {}
+            Rule::byte => bytes.push(u8::from_str(inner_pair.as_str()).map_err(|_| {
+                Error::from(ErrorKind::ParseValue {
+                    kind: TYPE_NAME_BYTE.to_string(),
+                    value: inner_pair.as_str().to_string(),
+                })
+            })?),
+            _ => unexpected_input!(inner_pair),
+        }
+    }
+    Ok(ByteVector::from(bytes))
+}
+
+fn parse_abbreviation(input_pair: Pair<'_, Rule>, fold_case: &mut bool) -> Result<Datum, Error> {
+    let mut inner_pairs = input_pair
+        .into_inner()
+        .filter(|inner_pair| !update_fold_case(inner_pair, fold_case))
+        .collect::<Vec<_>>()
+        .into_iter();
     let input_pair = inner_pairs.next().unwrap();
     let abbreviation = match input_pair.as_rule() {
         Rule::abbrev_prefix => Abbreviation::from_str(input_pair.as_str())?,
@@ -635,12 +832,53 @@ fn parse_abbreviation(input_pair: Pair<'_, Rule>) -> Result<Datum, Error> {
     };
     let input_pair = inner_pairs.next().unwrap();
     let datum = match input_pair.as_rule() {
-        Rule::datum => parse_datum_inner(input_pair)?,
+        Rule::datum => parse_datum_inner(input_pair, fold_case)?,
         _ => unexpected_input!(input_pair),
     };
     Ok(Datum::Abbreviation(abbreviation, Ref::new(datum)))
 }
 
+#[inline]
+fn is_fold_case(input_pair: &Pair<'_, Rule>) -> bool {
+    input_pair.as_str() == SYNTAX_DIRECTIVE_FOLD_CASE
+}
+
+///
+/// If `input_pair` is a directive set `fold_case` from it, returning `true`, else `false`.
+///
+fn update_fold_case(input_pair: &Pair<'_, Rule>, fold_case: &mut bool) -> bool {
+    if input_pair.as_rule() == Rule::directive {
+        *fold_case = is_fold_case(input_pair);
+        true
+    } else {
+        false
+    }
+}
+
+///
+/// An identifier, case folded following a `#!fold-case` directive unless it is enclosed in
+/// vertical lines.
+///
+fn parse_identifier(s: &str, fold_case: bool) -> Identifier {
+    if fold_case && !s.starts_with(SYNTAX_VERTICAL_LINE_CHAR) {
+        Identifier::from_str_unchecked(&fold_string(s))
+    } else {
+        Identifier::from_str_unchecked(s)
+    }
+}
+
+///
+/// A character, whose name is case folded following a `#!fold-case` directive; a character
+/// given as itself, such as `#\\A`, is never folded.
+///
+fn parse_character(s: &str, fold_case: bool) -> Result<Char, Error> {
+    if fold_case && s.chars().count() > 3 {
+        string_to_char(&fold_string(s))
+    } else {
+        string_to_char(s)
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Unit Tests
 // ------------------------------------------------------------------------------------------------
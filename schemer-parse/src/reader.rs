@@ -0,0 +1,392 @@
+/*!
+Reads one datum at a time from a source of characters, such as an input port.
+
+The parser works on a complete string, so the reader first scans the characters of a single
+datum from the source, following nesting, strings, and comments, and then parses the scanned
+text. A simple datum ends at the first delimiter, which is only peeked at, and a compound datum
+ends at its closing parenthesis; either way the characters that follow the datum are left in the
+source for the next read.
+
+Whitespace, comments, datum comments (`#;`), and directives before a datum are skipped. The
+`#!fold-case` and `#!no-fold-case` directives, which may also appear within a datum, set
+whether the source folds the case of the identifiers and character names read from it after the
+directive, as the parser does for a complete string.
+
+# Example
+
+```rust
+use schemer_parse::reader::read_datum;
+use schemer_lang::eval::Port;
+use schemer_lang::types::SchemeRepr;
+
+let mut port = Port::new_input_string("  (a b) ; comment\n#!fold-case ABC");
+let datum = read_datum(&mut port).unwrap().unwrap();
+assert_eq!(datum.to_repr_string(), "(a b)");
+assert_eq!(port.peek_char().unwrap(), Some(' '));
+let datum = read_datum(&mut port).unwrap().unwrap();
+assert_eq!(datum.to_repr_string(), "abc");
+```
+
+ */
+
+use crate::parser::parse_datum_prefix_str;
+use schemer_lang::error::{Error, ErrorKind};
+use schemer_lang::eval::Port;
+use schemer_lang::read::datum::Datum;
+use schemer_lang::read::syntax_str::{SYNTAX_DIRECTIVE_FOLD_CASE, SYNTAX_DIRECTIVE_NO_FOLD_CASE};
+use schemer_lang::types::char_folding::fold_string;
+use schemer_lang::types::Ref;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A source of characters with a single character of lookahead, which also records whether the
+/// identifiers read from it are case folded.
+///
+pub trait CharSource {
+    ///
+    /// Return the next character without consuming it, or `None` at the end of input.
+    ///
+    fn peek_char(&mut self) -> Result<Option<char>, Error>;
+
+    ///
+    /// Consume and return the next character, or `None` at the end of input.
+    ///
+    fn read_char(&mut self) -> Result<Option<char>, Error>;
+
+    ///
+    /// Whether identifiers read from this source are case folded.
+    ///
+    fn is_fold_case(&self) -> bool;
+
+    fn set_fold_case(&mut self, fold_case: bool);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Scans the text of a datum; `pending` holds a `#` that has been consumed from the source while
+/// deciding whether it starts a comment, or a datum.
+///
+struct Reader<'a, S>
+where
+    S: CharSource,
+{
+    source: &'a mut S,
+    pending: Option<char>,
+}
+
+const TYPE_NAME_DATUM: &str = "datum";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Read the next datum from `source`, or `None` if there are no more data before the end of
+/// input. An error is returned if the input ends within a datum, or if the datum is malformed.
+///
+pub fn read_datum<S>(source: &mut S) -> Result<Option<Ref<Datum>>, Error>
+where
+    S: CharSource,
+{
+    let mut reader = Reader {
+        source,
+        pending: None,
+    };
+    if !reader.skip_atmosphere()? {
+        return Ok(None);
+    }
+    let mut text = String::new();
+    reader.scan_datum(&mut text)?;
+    let parsed = parse_datum_prefix_str(&text)?;
+    if parsed.has_more_input() {
+        Err(ErrorKind::ParseValue {
+            kind: TYPE_NAME_DATUM.to_string(),
+            value: text,
+        }
+        .into())
+    } else {
+        Ok(Some(parsed.into_parsed()))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl CharSource for Port {
+    fn peek_char(&mut self) -> Result<Option<char>, Error> {
+        Port::peek_char(self)
+    }
+
+    fn read_char(&mut self) -> Result<Option<char>, Error> {
+        Port::read_char(self)
+    }
+
+    fn is_fold_case(&self) -> bool {
+        Port::is_fold_case(self)
+    }
+
+    fn set_fold_case(&mut self, fold_case: bool) {
+        Port::set_fold_case(self, fold_case)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<S> Reader<'_, S>
+where
+    S: CharSource,
+{
+    fn peek(&mut self) -> Result<Option<char>, Error> {
+        match self.pending {
+            Some(c) => Ok(Some(c)),
+            None => self.source.peek_char(),
+        }
+    }
+
+    fn next(&mut self) -> Result<Option<char>, Error> {
+        match self.pending.take() {
+            Some(c) => Ok(Some(c)),
+            None => self.source.read_char(),
+        }
+    }
+
+    fn next_in(&mut self, text: &str) -> Result<char, Error> {
+        match self.next()? {
+            Some(c) => Ok(c),
+            None => Err(unexpected_input(text)),
+        }
+    }
+
+    ///
+    /// Skip whitespace, comments, datum comments, and directives, returning `false` if the end
+    /// of input is reached.
+    ///
+    fn skip_atmosphere(&mut self) -> Result<bool, Error> {
+        loop {
+            match self.peek()? {
+                None => return Ok(false),
+                Some(c) if c.is_whitespace() => {
+                    let _ = self.next()?;
+                }
+                Some(';') => {
+                    while let Some(c) = self.next()? {
+                        if c == '\n' {
+                            break;
+                        }
+                    }
+                }
+                Some('#') if self.pending.is_none() => {
+                    let _ = self.next()?;
+                    match self.peek()? {
+                        Some('|') => {
+                            let _ = self.next()?;
+                            self.skip_nested_comment()?;
+                        }
+                        Some(';') => {
+                            let _ = self.next()?;
+                            if !self.skip_atmosphere()? {
+                                return Err(unexpected_input("#;"));
+                            }
+                            self.scan_datum(&mut String::new())?;
+                        }
+                        Some('!') => {
+                            let mut directive = String::from('#');
+                            self.scan_atom(&mut directive)?;
+                            if directive == SYNTAX_DIRECTIVE_FOLD_CASE {
+                                self.source.set_fold_case(true);
+                            } else if directive == SYNTAX_DIRECTIVE_NO_FOLD_CASE {
+                                self.source.set_fold_case(false);
+                            }
+                        }
+                        _ => {
+                            self.pending = Some('#');
+                            return Ok(true);
+                        }
+                    }
+                }
+                Some(_) => return Ok(true),
+            }
+        }
+    }
+
+    fn skip_nested_comment(&mut self) -> Result<(), Error> {
+        let mut depth = 1;
+        while depth > 0 {
+            match (self.next_in("#|")?, self.peek()?) {
+                ('|', Some('#')) => {
+                    let _ = self.next()?;
+                    depth -= 1;
+                }
+                ('#', Some('|')) => {
+                    let _ = self.next()?;
+                    depth += 1;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn scan_datum(&mut self, text: &mut String) -> Result<(), Error> {
+        let c = self.next_in(text)?;
+        text.push(c);
+        match c {
+            '(' => self.scan_list(text),
+            ')' => Err(unexpected_input(text)),
+            '\'' | '`' | ',' => {
+                if c == ',' && self.peek()? == Some('@') {
+                    text.push(self.next_in(text)?);
+                }
+                self.scan_prefixed(text)
+            }
+            '"' | '|' => self.scan_quoted(c, text),
+            '#' => {
+                let start = text.len() - 1;
+                if self.peek()? == Some('\\') {
+                    text.push(self.next_in(text)?);
+                    let c = self.next_in(text)?;
+                    text.push(c);
+                }
+                self.scan_hash_atom(start, text)?;
+                if self.source.is_fold_case() && is_character_name(&text[start..]) {
+                    let folded = fold_string(&text[start..]);
+                    text.replace_range(start.., &folded);
+                }
+                let atom = &text[start..];
+                if (atom == "#" || atom == "#u8") && self.peek()? == Some('(') {
+                    text.push(self.next_in(text)?);
+                    self.scan_list(text)
+                } else if is_label(atom) {
+                    self.scan_prefixed(text)
+                } else {
+                    Ok(())
+                }
+            }
+            _ => {
+                let start = text.len() - 1;
+                self.scan_atom(text)?;
+                if self.source.is_fold_case() {
+                    let folded = fold_string(&text[start..]);
+                    text.replace_range(start.., &folded);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    ///
+    /// Scan the elements of a list, or vector, the opening parenthesis is already in `text`.
+    ///
+    fn scan_list(&mut self, text: &mut String) -> Result<(), Error> {
+        let mut first = true;
+        loop {
+            if !self.skip_atmosphere()? {
+                return Err(unexpected_input(text));
+            }
+            if self.peek()? == Some(')') {
+                text.push(self.next_in(text)?);
+                return Ok(());
+            }
+            if !first {
+                text.push(' ');
+            }
+            first = false;
+            self.scan_datum(text)?;
+        }
+    }
+
+    ///
+    /// Scan the datum that follows an abbreviation prefix, or a label.
+    ///
+    fn scan_prefixed(&mut self, text: &mut String) -> Result<(), Error> {
+        if !self.skip_atmosphere()? {
+            return Err(unexpected_input(text));
+        }
+        self.scan_datum(text)
+    }
+
+    ///
+    /// Scan a string, or a `|` delimited identifier, the opening `quote` is already in `text`.
+    ///
+    fn scan_quoted(&mut self, quote: char, text: &mut String) -> Result<(), Error> {
+        loop {
+            let c = self.next_in(text)?;
+            text.push(c);
+            if c == '\\' {
+                let c = self.next_in(text)?;
+                text.push(c);
+            } else if c == quote {
+                return Ok(());
+            }
+        }
+    }
+
+    ///
+    /// Scan the remainder of an atom that starts with `#` at `start`, as `scan_atom` does except
+    /// that a label, `#n=`, ends at the `=` as the labeled datum may follow it immediately.
+    ///
+    fn scan_hash_atom(&mut self, start: usize, text: &mut String) -> Result<(), Error> {
+        while let Some(c) = self.peek()? {
+            if is_delimiter(c) || is_label(&text[start..]) {
+                break;
+            }
+            text.push(c);
+            let _ = self.next()?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Scan the remainder of a simple datum, up to but not including the next delimiter.
+    ///
+    fn scan_atom(&mut self, text: &mut String) -> Result<(), Error> {
+        while let Some(c) = self.peek()? {
+            if is_delimiter(c) {
+                break;
+            }
+            text.push(c);
+            let _ = self.next()?;
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '(' | ')' | '"' | ';' | '|')
+}
+
+fn is_label(atom: &str) -> bool {
+    atom.len() > 2
+        && atom.ends_with('=')
+        && atom[1..atom.len() - 1].chars().all(|c| c.is_ascii_digit())
+}
+
+///
+/// Whether `atom` is a character given by name, or hex value, rather than as itself.
+///
+fn is_character_name(atom: &str) -> bool {
+    atom.starts_with("#\\") && atom.chars().count() > 3
+}
+
+fn unexpected_input(text: &str) -> Error {
+    ErrorKind::ParserState {
+        input: text.to_string(),
+        state: None,
+    }
+    .into()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
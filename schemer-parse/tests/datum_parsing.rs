@@ -1,6 +1,7 @@
 use schemer_lang::read::datum::Datum;
-use schemer_lang::types::{Identifier, InexactReal, InfNan, Integer, Number, SchemeRepr};
-use schemer_parse::parser::parse_datum_str;
+use schemer_lang::types::lists::list_to_vec;
+use schemer_lang::types::{Identifier, InexactReal, InfNan, Integer, Number, Ref, SchemeRepr};
+use schemer_parse::parser::{parse_data_str, parse_datum_str};
 use std::str::FromStr;
 
 fn assert_parsed_ok(src: &str) {
@@ -47,6 +48,20 @@ fn test_string() {
     assert_parsed_eq("\"hello\"", Datum::from("hello").into());
 }
 
+#[test]
+fn test_string_escapes() {
+    assert_parsed_eq(
+        r#""a\n\t\r\a\b\\\"z""#,
+        Datum::from("a\n\t\r\u{7}\u{8}\\\"z"),
+    );
+    assert_parsed_eq(r#""\x41;\x3bb;""#, Datum::from("Aλ"));
+    assert_parsed_eq("\"abc \\  \n   def\"", Datum::from("abc def"));
+    assert_parsed_eq("\"abc\\\r\n\tdef\"", Datum::from("abcdef"));
+    assert_parsed_eq("\"a\nb\"", Datum::from("a\nb"));
+    assert!(parse_datum_str(r#""\q""#).is_err());
+    assert!(parse_datum_str(r#""\x110000;""#).is_err());
+}
+
 #[test]
 fn test_identifiers() {
     assert_parsed_eq("+", Identifier::from_str("+").unwrap().into());
@@ -84,6 +99,20 @@ fn test_num_integer_10() {
     assert_parsed_eq("+3", Number::from(Integer::from(3)).into());
 }
 
+#[test]
+fn test_num_big_integer_10() {
+    assert_parsed_eq(
+        "123456789012345678901234567890",
+        Number::from(Integer::from_str("123456789012345678901234567890").unwrap()).into(),
+    );
+    assert_parsed_eq(
+        "-123456789012345678901234567890",
+        Number::from(Integer::from_str("-123456789012345678901234567890").unwrap()).into(),
+    );
+    assert_parsed_ok("#xFFFFFFFFFFFFFFFFFFFF");
+    assert_parsed_ok("1/123456789012345678901234567890");
+}
+
 #[test]
 fn test_num_rational_10() {
     assert_parsed_ok("1/3");
@@ -110,6 +139,15 @@ fn test_num_rational_16() {
     assert_parsed_ok("#xA0/B0B");
 }
 
+#[test]
+fn test_num_rational_zero_denominator() {
+    assert!(parse_datum_str("1/0").is_err());
+    assert!(parse_datum_str("-3/0").is_err());
+    assert!(parse_datum_str("#x1/00").is_err());
+    assert!(parse_datum_str("1/0+2i").is_err());
+    assert_parsed_ok("0/1");
+}
+
 #[test]
 fn test_num_decimal_10() {
     assert_parsed_ok("3.");
@@ -186,6 +224,25 @@ fn test_num_cartesian_10() {
     assert_parsed_ok("1+2i");
     assert_parsed_ok("1/2+2/3i");
     assert_parsed_ok("#xA0/B0B+1i");
+    assert_parsed_ok("1-i");
+    assert_parsed_ok("#b1+i");
+}
+
+#[test]
+fn test_num_exactness_prefix() {
+    let exact = |src: &str| match parse_datum_str(src) {
+        Ok(Datum::Number(v)) => v.is_exact(),
+        result => panic!("{:#?}", result),
+    };
+    assert!(exact("1.5"));
+    assert!(!exact("#i1.5"));
+    assert!(!exact("#I1/2"));
+    assert!(exact("#E1.5"));
+    assert!(exact("1+2i"));
+    assert!(!exact("#i1+2i"));
+    assert!(!exact("1+inf.0i"));
+    assert!(!exact("1@0"));
+    assert!(exact("#e1@0"));
 }
 
 #[test]
@@ -224,3 +281,95 @@ fn test_char_parsing_bug() {
 fn test_char_escaped() {
     assert_parsed_ok("#\\x2764");
 }
+
+#[test]
+fn test_explicit_sign_followed_by_number() {
+    // the sign is an identifier, not the prefix of a number across whitespace.
+    assert_eq!(
+        parse_datum_str("(+ 1 1)").unwrap().to_repr_string(),
+        "(+ 1 1)"
+    );
+    assert_eq!(
+        parse_datum_str("(- 1 1)").unwrap().to_repr_string(),
+        "(- 1 1)"
+    );
+    assert_eq!(
+        parse_datum_str("(-1 +1)").unwrap().to_repr_string(),
+        "(-1 1)"
+    );
+}
+
+#[test]
+fn test_abbreviations() {
+    assert_eq!(
+        parse_datum_str("`(a ,b ,@c 'd)").unwrap().to_repr_string(),
+        "`(a ,b ,@c 'd)"
+    );
+}
+
+#[test]
+fn test_fold_case_directives() {
+    let data = parse_data_str("Hello #!fold-case Hello |Hello| #!no-fold-case Hello").unwrap();
+    assert_eq!(
+        data.iter()
+            .map(|datum| datum.to_repr_string())
+            .collect::<Vec<String>>(),
+        vec!["Hello", "hello", "|Hello|", "Hello"]
+    );
+    assert_eq!(
+        parse_data_str("#!fold-case (DEFINE (Foo X) 'ABC)").unwrap()[0].to_repr_string(),
+        "(define (foo x) 'abc)"
+    );
+}
+
+#[test]
+fn test_fold_case_directives_within_data() {
+    let data =
+        parse_data_str("(quote (A #!fold-case B)) C #(D #!no-fold-case E) '#!fold-case F").unwrap();
+    assert_eq!(
+        data.iter()
+            .map(|datum| datum.to_repr_string())
+            .collect::<Vec<String>>(),
+        vec!["(quote (A b))", "c", "#(d E)", "'f"]
+    );
+}
+
+#[test]
+fn test_fold_case_character_names() {
+    let data = parse_data_str("#!fold-case #\\SPACE #\\Newline #\\A #\\x41").unwrap();
+    assert_eq!(
+        data.iter()
+            .map(|datum| datum.to_repr_string())
+            .collect::<Vec<String>>(),
+        vec!["#\\space", "#\\newline", "#\\A", "#\\A"]
+    );
+    assert!(parse_data_str("#\\SPACE").is_err());
+}
+
+#[test]
+fn test_labels() {
+    let datum = parse_datum_str("(#0=(a) #0#)").unwrap();
+    let list = list_to_vec(datum.as_pair().unwrap().clone());
+    assert!(Ref::ptr_eq(&list[0], &list[1]));
+    let datum = parse_datum_str("#0=(a b . #0#)").unwrap();
+    let cdr = datum.as_pair().unwrap().cdr();
+    let cddr = cdr.as_pair().unwrap().cdr();
+    assert!(Ref::ptr_eq(
+        &cddr,
+        &cddr.as_pair().unwrap().cdr().as_pair().unwrap().cdr()
+    ));
+    assert_parsed_eq("#0=a", Datum::from(Identifier::from_str_unchecked("a")));
+    assert!(parse_datum_str("#0#").is_err());
+    assert!(parse_datum_str("(#0=a #0=b)").is_err());
+    assert!(parse_datum_str("(#0# #0=a)").is_err());
+}
+
+#[test]
+fn test_byte_vector() {
+    assert_parsed_eq(
+        "#u8(0 7 42 199 255)",
+        Datum::from(vec![0u8, 7, 42, 199, 255]),
+    );
+    assert_parsed_eq("#u8()", Datum::from(Vec::<u8>::new()));
+    assert!(parse_datum_str("#u8(256)").is_err());
+}
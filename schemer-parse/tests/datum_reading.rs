@@ -0,0 +1,103 @@
+use schemer_lang::eval::Port;
+use schemer_lang::types::SchemeRepr;
+use schemer_parse::reader::read_datum;
+
+fn assert_read_all(src: &str, expected: &[&str]) {
+    println!("<< {:?}", src);
+    let mut source = Port::new_input_string(src);
+    let mut data: Vec<String> = Default::default();
+    while let Some(datum) = read_datum(&mut source).unwrap() {
+        println!(">> {}", datum.to_repr_string());
+        data.push(datum.to_repr_string());
+    }
+    assert_eq!(data, expected);
+}
+
+fn assert_read_rest(src: &str, expected: &str, rest: &str) {
+    let mut source = Port::new_input_string(src);
+    let datum = read_datum(&mut source).unwrap().unwrap();
+    assert_eq!(datum.to_repr_string(), expected);
+    assert_eq!(
+        source.read_string(src.len()).unwrap().unwrap_or_default(),
+        rest
+    );
+}
+
+fn assert_read_err(src: &str) {
+    let mut source = Port::new_input_string(src);
+    let result = read_datum(&mut source);
+    println!("{:?} => {:?}", src, result);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_read_simple_data() {
+    assert_read_all(
+        "1 -2/3 #t #false \"a b\" #\\a #\\Z abc |a b|",
+        &[
+            "1", "-2/3", "#t", "#f", "\"a b\"", "#\\a", "#\\Z", "abc", "|a b|",
+        ],
+    );
+}
+
+#[test]
+fn test_read_compound_data() {
+    assert_read_all(
+        "(a (b \"c)\") . d) #(1 #(2)) #u8(1 2) '(a) `(a ,b ,@c) (#\\( #\\))",
+        &[
+            "(a (b \"c)\") . d)",
+            "#(1 #(2))",
+            "#u8(1 2)",
+            "'(a)",
+            "`(a ,b ,@c)",
+            "(#\\( #\\))",
+        ],
+    );
+}
+
+#[test]
+fn test_read_skips_atmosphere() {
+    assert_read_all(
+        "; line\n #| nested #| comment |# |# a #;(b c) d #!fold-case (e ; f\n #;g h)",
+        &["a", "d", "(e h)"],
+    );
+    assert_read_all("  ; nothing but a comment\n", &[]);
+}
+
+#[test]
+fn test_read_leaves_rest() {
+    assert_read_rest("abc def", "abc", " def");
+    assert_read_rest("abc(def)", "abc", "(def)");
+    assert_read_rest("(a b)c", "(a b)", "c");
+    assert_read_rest("\"a\"b", "\"a\"", "b");
+    assert_read_rest("'a ;c", "'a", " ;c");
+}
+
+#[test]
+fn test_read_errors() {
+    assert_read_err(")");
+    assert_read_err("(a b");
+    assert_read_err("\"abc");
+    assert_read_err("#| abc");
+    assert_read_err("'");
+    assert_read_err("#(1 . 2)");
+}
+
+#[test]
+fn test_read_labels() {
+    assert_read_all("(#0=(a) #0#) #1=#(b) #2= c", &["((a) (a))", "#(b)", "c"]);
+    assert_read_err("(#0# #0=a)");
+}
+
+#[test]
+fn test_read_fold_case() {
+    assert_read_all(
+        "ABC #!fold-case (DEF |GHI| #\\A \"JKL\") #!no-fold-case MNO",
+        &["ABC", "(def |GHI| #\\A \"JKL\")", "MNO"],
+    );
+    assert_read_all("#!fold-case STRASSE", &["strasse"]);
+    assert_read_all(
+        "(A #!fold-case B) C #\\SPACE #\\A #!no-fold-case #\\x41",
+        &["(A b)", "c", "#\\space", "#\\A", "#\\A"],
+    );
+}
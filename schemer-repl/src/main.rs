@@ -459,7 +459,9 @@ fn eval_datum_str(datum_str: &str, env: &mut MutableRef<Environment>, silent: bo
                 match datum.eval(env) {
                     Ok(result) => {
                         if !silent {
-                            println!("{}", result.to_repr_string());
+                            for value in result.into_values() {
+                                println!("{}", value.to_repr_string());
+                            }
                         }
                     }
                     Err(err) => {